@@ -0,0 +1,159 @@
+//! Implementations of the [approx] comparison traits for bounds and intervals.
+//!
+//! Requires the `approx` feature. Bound values are compared with the given
+//! tolerances, but the kinds of the bounds (open, closed or unbounded) must
+//! match exactly.
+use crate::{Interval, bounds::{Bound, Closed, NoBound, Open, OpenOrClosed}};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+macro_rules! impl_approx {
+    ($bound:ident) => {
+        impl<V: AbsDiffEq> AbsDiffEq for $bound<V> {
+            type Epsilon = V::Epsilon;
+
+            fn default_epsilon() -> V::Epsilon { V::default_epsilon() }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: V::Epsilon) -> bool {
+                self.0.abs_diff_eq(&other.0, epsilon)
+            }
+        }
+
+        impl<V: RelativeEq> RelativeEq for $bound<V> {
+            fn default_max_relative() -> V::Epsilon { V::default_max_relative() }
+
+            fn relative_eq(&self, other: &Self, epsilon: V::Epsilon, max_relative: V::Epsilon) -> bool {
+                self.0.relative_eq(&other.0, epsilon, max_relative)
+            }
+        }
+
+        impl<V: UlpsEq> UlpsEq for $bound<V> {
+            fn default_max_ulps() -> u32 { V::default_max_ulps() }
+
+            fn ulps_eq(&self, other: &Self, epsilon: V::Epsilon, max_ulps: u32) -> bool {
+                self.0.ulps_eq(&other.0, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+impl_approx!(Open);
+impl_approx!(Closed);
+
+impl<V: AbsDiffEq> AbsDiffEq for OpenOrClosed<V> {
+    type Epsilon = V::Epsilon;
+
+    fn default_epsilon() -> V::Epsilon { V::default_epsilon() }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: V::Epsilon) -> bool {
+        match (self, other) {
+            (OpenOrClosed::Open(x), OpenOrClosed::Open(y))
+            | (OpenOrClosed::Closed(x), OpenOrClosed::Closed(y)) => x.abs_diff_eq(y, epsilon),
+            _ => false,
+        }
+    }
+}
+
+impl<V: RelativeEq> RelativeEq for OpenOrClosed<V> {
+    fn default_max_relative() -> V::Epsilon { V::default_max_relative() }
+
+    fn relative_eq(&self, other: &Self, epsilon: V::Epsilon, max_relative: V::Epsilon) -> bool {
+        match (self, other) {
+            (OpenOrClosed::Open(x), OpenOrClosed::Open(y))
+            | (OpenOrClosed::Closed(x), OpenOrClosed::Closed(y)) => x.relative_eq(y, epsilon, max_relative),
+            _ => false,
+        }
+    }
+}
+
+impl<V: UlpsEq> UlpsEq for OpenOrClosed<V> {
+    fn default_max_ulps() -> u32 { V::default_max_ulps() }
+
+    fn ulps_eq(&self, other: &Self, epsilon: V::Epsilon, max_ulps: u32) -> bool {
+        match (self, other) {
+            (OpenOrClosed::Open(x), OpenOrClosed::Open(y))
+            | (OpenOrClosed::Closed(x), OpenOrClosed::Closed(y)) => x.ulps_eq(y, epsilon, max_ulps),
+            _ => false,
+        }
+    }
+}
+
+impl<V: AbsDiffEq> AbsDiffEq for NoBound<V> {
+    type Epsilon = V::Epsilon;
+
+    fn default_epsilon() -> V::Epsilon { V::default_epsilon() }
+
+    fn abs_diff_eq(&self, _: &Self, _: V::Epsilon) -> bool { true }
+}
+
+impl<V: RelativeEq> RelativeEq for NoBound<V> {
+    fn default_max_relative() -> V::Epsilon { V::default_max_relative() }
+
+    fn relative_eq(&self, _: &Self, _: V::Epsilon, _: V::Epsilon) -> bool { true }
+}
+
+impl<V: UlpsEq> UlpsEq for NoBound<V> {
+    fn default_max_ulps() -> u32 { V::default_max_ulps() }
+
+    fn ulps_eq(&self, _: &Self, _: V::Epsilon, _: u32) -> bool { true }
+}
+
+/// Both bounds must be approximately equal, with the default tolerances
+/// taken from the left bound.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// # fn main() {
+/// let x = Interval::closed_unchecked(0.1 + 0.2, 1.0);
+///
+/// assert_ne!(x, Interval::closed_unchecked(0.3, 1.0));
+/// assert_relative_eq!(x, Interval::closed_unchecked(0.3, 1.0));
+/// # }
+/// ```
+impl<L, R> AbsDiffEq for Interval<L, R>
+where
+    L: Bound + AbsDiffEq,
+    R: Bound<Value = L::Value> + AbsDiffEq<Epsilon = L::Epsilon>,
+
+    L::Epsilon: Clone,
+{
+    type Epsilon = L::Epsilon;
+
+    fn default_epsilon() -> L::Epsilon { L::default_epsilon() }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: L::Epsilon) -> bool {
+        self.left.abs_diff_eq(&other.left, epsilon.clone()) && self.right.abs_diff_eq(&other.right, epsilon)
+    }
+}
+
+impl<L, R> RelativeEq for Interval<L, R>
+where
+    L: Bound + RelativeEq,
+    R: Bound<Value = L::Value> + RelativeEq<Epsilon = L::Epsilon>,
+
+    L::Epsilon: Clone,
+{
+    fn default_max_relative() -> L::Epsilon { L::default_max_relative() }
+
+    fn relative_eq(&self, other: &Self, epsilon: L::Epsilon, max_relative: L::Epsilon) -> bool {
+        self.left.relative_eq(&other.left, epsilon.clone(), max_relative.clone())
+            && self.right.relative_eq(&other.right, epsilon, max_relative)
+    }
+}
+
+impl<L, R> UlpsEq for Interval<L, R>
+where
+    L: Bound + UlpsEq,
+    R: Bound<Value = L::Value> + UlpsEq<Epsilon = L::Epsilon>,
+
+    L::Epsilon: Clone,
+{
+    fn default_max_ulps() -> u32 { L::default_max_ulps() }
+
+    fn ulps_eq(&self, other: &Self, epsilon: L::Epsilon, max_ulps: u32) -> bool {
+        self.left.ulps_eq(&other.left, epsilon.clone(), max_ulps)
+            && self.right.ulps_eq(&other.right, epsilon, max_ulps)
+    }
+}
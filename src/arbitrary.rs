@@ -0,0 +1,99 @@
+//! Implementations of [quickcheck::Arbitrary] for bounds and intervals.
+//!
+//! Requires the `quickcheck` feature.
+use crate::{Interval, bounds::{self, Bound, Closed, NoBound, Open, OpenOrClosed}};
+use quickcheck::{Arbitrary, Gen};
+
+impl<V: Arbitrary> Arbitrary for Open<V> {
+    fn arbitrary(g: &mut Gen) -> Self { Open(V::arbitrary(g)) }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> { Box::new(self.0.shrink().map(Open)) }
+}
+
+impl<V: Arbitrary> Arbitrary for Closed<V> {
+    fn arbitrary(g: &mut Gen) -> Self { Closed(V::arbitrary(g)) }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> { Box::new(self.0.shrink().map(Closed)) }
+}
+
+/// Open bounds shrink to closed bounds before their values are shrunk.
+impl<V: Arbitrary> Arbitrary for OpenOrClosed<V> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        if bool::arbitrary(g) {
+            OpenOrClosed::Open(V::arbitrary(g))
+        } else {
+            OpenOrClosed::Closed(V::arbitrary(g))
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            OpenOrClosed::Open(v) => Box::new(
+                std::iter::once(OpenOrClosed::Closed(v.clone())).chain(v.shrink().map(OpenOrClosed::Open))
+            ),
+            OpenOrClosed::Closed(v) => Box::new(v.shrink().map(OpenOrClosed::Closed)),
+        }
+    }
+}
+
+impl<V: Clone + 'static> Arbitrary for NoBound<V> {
+    fn arbitrary(_: &mut Gen) -> Self { NoBound::new() }
+}
+
+// Returns the interval if its bounds are comparable and pass validation.
+fn valid<L, R>(left: L, right: R) -> Option<Interval<L, R>>
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+
+    bounds::Validator: bounds::ValidateBounds<L, R>,
+{
+    let comparable = |v: Option<&L::Value>| v.is_none_or(|v| v.partial_cmp(v).is_some());
+
+    if !comparable(left.value()) || !comparable(right.value()) { return None; }
+
+    Interval::new(left, right).ok()
+}
+
+/// Generates only valid intervals, with comparable (e.g. non-NaN) bounds.
+///
+/// Intervals shrink towards degenerate intervals, by moving the right bound
+/// onto the left, and otherwise by shrinking either bound.
+impl<L, R> Arbitrary for Interval<L, R>
+where
+    L: Bound + Arbitrary,
+    R: Bound<Value = L::Value> + Arbitrary,
+
+    L::Value: Clone,
+    bounds::Validator: bounds::ValidateBounds<L, R>,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        loop {
+            let (left, right) = (L::arbitrary(g), R::arbitrary(g));
+
+            if let Some(x) = valid(left.clone(), right.clone()) { return x; }
+
+            // Decreasing bounds are tried again with their values swapped.
+            if let (Some(l), Some(r)) = (left.value().cloned(), right.value().cloned()) {
+                if let Some(x) = valid(left.map_value(|_| r), right.map_value(|_| l)) { return x; }
+            }
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let mut shrunk = Vec::new();
+
+        if let (Some(l), Some(r)) = (self.left.value(), self.right.value()) {
+            if l != r {
+                let l = l.clone();
+
+                shrunk.extend(valid(self.left.clone(), self.right.clone().map_value(|_| l)));
+            }
+        }
+
+        shrunk.extend(self.left.shrink().filter_map(|l| valid(l, self.right.clone())));
+        shrunk.extend(self.right.shrink().filter_map(|r| valid(self.left.clone(), r)));
+
+        Box::new(shrunk.into_iter())
+    }
+}
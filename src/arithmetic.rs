@@ -0,0 +1,1040 @@
+//! Module containing arithmetic operations on intervals.
+use crate::{Interval, IntervalResult, bounds::{self, Bound, NoBound, OpenOrClosed, ProperBound}, dynamic::IntervalEnum};
+use std::{cmp::Ordering, ops::{Add, Div, Mul, Neg, Sub}};
+use num_traits::{
+    Bounded, CheckedAdd, CheckedMul, CheckedSub, Float, Num, SaturatingAdd, SaturatingMul, SaturatingSub,
+    Zero,
+};
+
+/// Trait for intervals whose endpoints can be mapped through a monotone
+/// function.
+///
+/// If the function is decreasing then the images of the endpoints swap
+/// sides, carrying their openness with them. The output type is the
+/// narrowest interval type that can represent both orientations.
+pub trait Transform {
+    /// Underlying type associated with the interval.
+    type Value;
+
+    /// Interval type produced by the transformation.
+    type Output;
+
+    /// Map each endpoint of the interval through `f`.
+    ///
+    /// __Note__: `f` is assumed to be monotone; if `decreasing` is true then
+    /// it must be non-increasing, otherwise non-decreasing. No validation is
+    /// performed on the result.
+    fn transform<F>(self, f: F, decreasing: bool) -> Self::Output
+    where
+        F: Fn(Self::Value) -> Self::Value;
+}
+
+macro_rules! impl_transform_symmetric {
+    ($v:ident; $b:ident) => {
+        impl<$v: PartialOrd> Transform for Interval<bounds::$b<$v>, bounds::$b<$v>> {
+            type Value = $v;
+            type Output = Self;
+
+            fn transform<F: Fn($v) -> $v>(self, f: F, decreasing: bool) -> Self {
+                let left = f(self.left.0);
+                let right = f(self.right.0);
+
+                if decreasing {
+                    Interval::new_unchecked(bounds::$b(right), bounds::$b(left))
+                } else {
+                    Interval::new_unchecked(bounds::$b(left), bounds::$b(right))
+                }
+            }
+        }
+    };
+}
+
+impl_transform_symmetric!(V; Open);
+impl_transform_symmetric!(V; Closed);
+
+macro_rules! impl_transform_mixed {
+    ($v:ident; $l:ty, $r:ty) => {
+        impl<$v: PartialOrd> Transform for Interval<$l, $r> {
+            type Value = $v;
+            type Output = Interval<OpenOrClosed<$v>, OpenOrClosed<$v>>;
+
+            fn transform<F: Fn($v) -> $v>(self, f: F, decreasing: bool) -> Self::Output {
+                let left = map_bound(self.left.into(), &f);
+                let right = map_bound(self.right.into(), &f);
+
+                if decreasing {
+                    Interval::new_unchecked(right, left)
+                } else {
+                    Interval::new_unchecked(left, right)
+                }
+            }
+        }
+    };
+}
+
+impl_transform_mixed!(V; bounds::Open<V>, bounds::Closed<V>);
+impl_transform_mixed!(V; bounds::Closed<V>, bounds::Open<V>);
+
+impl_transform_mixed!(V; bounds::Open<V>, OpenOrClosed<V>);
+impl_transform_mixed!(V; bounds::Closed<V>, OpenOrClosed<V>);
+impl_transform_mixed!(V; OpenOrClosed<V>, bounds::Open<V>);
+impl_transform_mixed!(V; OpenOrClosed<V>, bounds::Closed<V>);
+impl_transform_mixed!(V; OpenOrClosed<V>, OpenOrClosed<V>);
+
+impl<V: PartialOrd> Transform for crate::Unbounded<V> {
+    type Value = V;
+    type Output = Self;
+
+    fn transform<F: Fn(V) -> V>(self, _: F, _: bool) -> Self { self }
+}
+
+// A decreasing map moves the unbounded side of a half-bounded interval to the
+// other side, so the shape is only known at runtime.
+macro_rules! impl_transform_half {
+    ($v:ident; $l:ty, $r:ty; $side:ident; $same:ident, $flipped:ident) => {
+        impl<$v: PartialOrd> Transform for Interval<$l, $r> {
+            type Value = $v;
+            type Output = IntervalEnum<$v>;
+
+            fn transform<F: Fn($v) -> $v>(self, f: F, decreasing: bool) -> IntervalEnum<$v> {
+                let x = f(self.$side.0);
+
+                if decreasing {
+                    Interval::$flipped(x).into()
+                } else {
+                    Interval::$same(x).into()
+                }
+            }
+        }
+    };
+}
+
+impl_transform_half!(V; bounds::Open<V>, NoBound<V>; left; left_open, right_open);
+impl_transform_half!(V; bounds::Closed<V>, NoBound<V>; left; left_closed, right_closed);
+impl_transform_half!(V; NoBound<V>, bounds::Open<V>; right; right_open, left_open);
+impl_transform_half!(V; NoBound<V>, bounds::Closed<V>; right; right_closed, left_closed);
+
+fn map_bound<V, F: Fn(V) -> V>(bound: OpenOrClosed<V>, f: F) -> OpenOrClosed<V> {
+    match bound {
+        OpenOrClosed::Open(x) => OpenOrClosed::Open(f(x)),
+        OpenOrClosed::Closed(x) => OpenOrClosed::Closed(f(x)),
+    }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+
+    L::Value: Num + Clone,
+{
+    /// Dilate the interval by a factor `k` about the point `anchor`.
+    ///
+    /// Each endpoint `x` is mapped to `anchor + k * (x - anchor)`. The
+    /// openness of each bound is preserved, and a negative `k` swaps the
+    /// bounds between the two sides. A factor of zero collapses the interval
+    /// onto the anchor; for open bounds this yields an empty interval.
+    ///
+    /// Only the finite bounds are scaled. Since the side on which the
+    /// unbounded limit of a half-bounded interval lies depends on the sign of
+    /// `k`, such intervals yield an [IntervalEnum].
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, bounds};
+    /// let x = Interval::closed_unchecked(0.0, 2.0);
+    ///
+    /// assert_eq!(x.scale_about(1.0, 2.0), Interval::closed_unchecked(-1.0, 3.0));
+    /// assert_eq!(x.scale_about(0.0, -1.0), Interval::closed_unchecked(-2.0, 0.0));
+    ///
+    /// let y = Interval::lcro_unchecked(0.0, 2.0).scale_about(0.0, -1.0);
+    ///
+    /// assert_eq!(y.left, bounds::Open(-2.0));
+    /// assert_eq!(y.right, bounds::Closed(0.0));
+    ///
+    /// let z = Interval::left_closed(1.0);
+    ///
+    /// assert_eq!(z.scale_about(0.0, 2.0), Interval::left_closed(2.0).into());
+    /// assert_eq!(z.scale_about(0.0, -2.0), Interval::right_closed(-2.0).into());
+    /// ```
+    pub fn scale_about(self, anchor: L::Value, k: L::Value) -> <Self as Transform>::Output
+    where
+        Self: Transform<Value = L::Value>,
+    {
+        let decreasing = k < L::Value::zero();
+
+        self.transform(
+            |x| anchor.clone() + k.clone() * (x - anchor.clone()),
+            decreasing,
+        )
+    }
+
+    /// Scale the interval by a factor `k` about zero.
+    ///
+    /// A negative `k` swaps the bounds between the two sides, carrying their
+    /// openness with them, so that `[0, 1)` scaled by `-2` is `(-2, 0]`; see
+    /// [Interval::scale_about]. For primitive value types this is also
+    /// available as `interval * k`.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, bounds};
+    /// assert_eq!(Interval::closed_unchecked(1.0, 2.0).scale(3.0), Interval::closed_unchecked(3.0, 6.0));
+    ///
+    /// let x = Interval::lcro_unchecked(0.0f64, 1.0) * -2.0;
+    ///
+    /// assert_eq!(x.left, bounds::OpenOrClosed::Open(-2.0));
+    /// assert_eq!(x.right, bounds::OpenOrClosed::Closed(0.0));
+    /// ```
+    pub fn scale(self, k: L::Value) -> <Self as Transform>::Output
+    where
+        Self: Transform<Value = L::Value>,
+    {
+        let decreasing = k < L::Value::zero();
+
+        self.transform(|x| x * k.clone(), decreasing)
+    }
+
+    /// Scale the interval by a factor `1 / k` about zero, dividing each
+    /// endpoint by `k`.
+    ///
+    /// As with [Interval::scale], a negative `k` swaps the bounds. For
+    /// primitive value types this is also available as `interval / k`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero, including for floating-point values.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::open_unchecked(-4, 8).scale_down(-4), Interval::open_unchecked(-2, 1));
+    /// ```
+    pub fn scale_down(self, k: L::Value) -> <Self as Transform>::Output
+    where
+        Self: Transform<Value = L::Value>,
+    {
+        assert!(!k.is_zero(), "Division by zero.");
+
+        let decreasing = k < L::Value::zero();
+
+        self.transform(|x| x / k.clone(), decreasing)
+    }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+
+    L::Value: Clone,
+{
+    /// Translate the interval by `d`.
+    ///
+    /// The bound types are preserved and unbounded sides are left unchanged;
+    /// since translation preserves order, no validation is performed. For
+    /// primitive value types this is also available as `interval + d`.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::closed_unchecked(0.0, 1.0).translate(2.5), Interval::closed_unchecked(2.5, 3.5));
+    /// assert_eq!(Interval::left_open(0.0).translate(2.5), Interval::left_open(2.5));
+    /// ```
+    pub fn translate(self, d: L::Value) -> Self
+    where
+        L::Value: Add<Output = L::Value>,
+    {
+        let left = self.left.map_value(|x| x + d.clone());
+        let right = self.right.map_value(|x| x + d);
+
+        Interval::new_unchecked(left, right)
+    }
+
+    /// Translate the interval by `-d`.
+    ///
+    /// This is the counterpart of [Interval::translate] for value types with
+    /// subtraction but no negation, e.g. timestamps less a duration. For
+    /// primitive value types this is also available as `interval - d`.
+    pub fn translate_down(self, d: L::Value) -> Self
+    where
+        L::Value: Sub<Output = L::Value>,
+    {
+        let left = self.left.map_value(|x| x - d.clone());
+        let right = self.right.map_value(|x| x - d);
+
+        Interval::new_unchecked(left, right)
+    }
+}
+
+/// Reason an interval could not be padded by a margin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PadError {
+    /// The margin is negative (or incomparable with zero).
+    Negative,
+
+    /// A padded endpoint is not representable in the value type.
+    Overflow,
+
+    /// The margin leaves no values in the interval.
+    Empty,
+}
+
+impl std::fmt::Display for PadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PadError::Negative => write!(f, "The margin must be non-negative."),
+            PadError::Overflow => write!(f, "The padded interval is not representable."),
+            PadError::Empty => write!(f, "The padded interval is empty."),
+        }
+    }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+
+    L::Value: Num + Bounded + Clone,
+{
+    /// Widen the interval by `pad` on each side.
+    ///
+    /// The bound types are preserved and unbounded sides are left unchanged.
+    /// Negative margins are rejected (as by [Interval::contract]), as are margins
+    /// that would take an endpoint beyond the numeric bounds of the value
+    /// type, e.g. below zero for unsigned integers; see
+    /// [Interval::saturating_expand] for a variant that clips instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, arithmetic::PadError};
+    /// assert_eq!(Interval::closed_unchecked(0.0, 1.0).expand(0.5), Ok(Interval::closed_unchecked(-0.5, 1.5)));
+    /// assert_eq!(Interval::left_closed(3).expand(1), Ok(Interval::left_closed(2)));
+    ///
+    /// assert_eq!(Interval::closed_unchecked(0.0, 1.0).expand(-0.5), Err(PadError::Negative));
+    /// assert_eq!(Interval::closed_unchecked(1u8, 2).expand(2), Err(PadError::Overflow));
+    /// ```
+    pub fn expand(self, pad: L::Value) -> std::result::Result<Self, PadError> {
+        match pad.partial_cmp(&L::Value::zero()) {
+            Some(Ordering::Greater) | Some(Ordering::Equal) => {},
+            _ => return Err(PadError::Negative),
+        }
+
+        let (min, max) = (L::Value::min_value() + pad.clone(), L::Value::max_value() - pad.clone());
+
+        let left = checked_map(self.left, |x| if *x < min { None } else { Some(x.clone() - pad.clone()) });
+        let right = checked_map(self.right, |x| if *x > max { None } else { Some(x.clone() + pad.clone()) });
+
+        match (left, right) {
+            (Some(left), Some(right)) => Ok(Interval::new_unchecked(left, right)),
+            _ => Err(PadError::Overflow),
+        }
+    }
+
+    /// Narrow the interval by `margin` on each side.
+    ///
+    /// The bound types are preserved and unbounded sides are left unchanged.
+    /// Negative margins yield [PadError::Negative] (as for
+    /// [Interval::expand]), while a margin greater than half the width yields
+    /// [PadError::Empty], as does one that would take an endpoint beyond the
+    /// numeric bounds of the value type. A margin of exactly half the width
+    /// yields a degenerate interval when both bounds are closed.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, arithmetic::PadError};
+    /// assert_eq!(Interval::closed_unchecked(0.0, 2.0).contract(0.5), Ok(Interval::closed_unchecked(0.5, 1.5)));
+    /// assert_eq!(Interval::closed_unchecked(0u8, 2).contract(1), Ok(Interval::degenerate(1)));
+    /// assert_eq!(Interval::left_open(3).contract(1), Ok(Interval::left_open(4)));
+    ///
+    /// assert_eq!(Interval::closed_unchecked(0u8, 2).contract(2), Err(PadError::Empty));
+    /// assert_eq!(Interval::closed_unchecked(0.0, 2.0).contract(-0.5), Err(PadError::Negative));
+    /// ```
+    pub fn contract(self, margin: L::Value) -> std::result::Result<Self, PadError>
+    where
+        bounds::Validator: bounds::ValidateBounds<L, R>,
+    {
+        match margin.partial_cmp(&L::Value::zero()) {
+            Some(Ordering::Greater) | Some(Ordering::Equal) => {},
+            _ => return Err(PadError::Negative),
+        }
+
+        let (max, min) = (L::Value::max_value() - margin.clone(), L::Value::min_value() + margin.clone());
+
+        // An endpoint beyond the bounds of the type lies past the other one.
+        let mut overflow = false;
+
+        let left = self.left.map_value(|x| match x > max {
+            true => { overflow = true; L::Value::max_value() },
+            false => x + margin.clone(),
+        });
+        let right = self.right.map_value(|x| match x < min {
+            true => { overflow = true; L::Value::min_value() },
+            false => x - margin.clone(),
+        });
+
+        if overflow { return Err(PadError::Empty); }
+
+        Interval::new(left, right).map_err(|_| PadError::Empty)
+    }
+}
+
+/// Reflects the interval about zero, mapping `[a, b)` to `(-b, -a]`.
+///
+/// The bounds swap sides, so the output type is the input type with its
+/// bound types reversed; e.g. a [LeftClosed](crate::LeftClosed) interval maps
+/// to a [RightClosed](crate::RightClosed) one.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// assert_eq!(-Interval::lcro_unchecked(0, 1), Interval::lorc_unchecked(-1, 0));
+/// assert_eq!(-Interval::left_closed(2.0), Interval::right_closed(-2.0));
+/// ```
+impl<L, R> Neg for Interval<L, R>
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+
+    L::Value: Neg<Output = L::Value>,
+{
+    type Output = Interval<R, L>;
+
+    fn neg(self) -> Interval<R, L> {
+        Interval::new_unchecked(self.right.map_value(Neg::neg), self.left.map_value(Neg::neg))
+    }
+}
+
+/// Minkowski sum of two intervals, `[a, b] + [c, d] = [a + c, b + d]`.
+///
+/// Each bound of the result is unbounded if either contributing bound is,
+/// and otherwise open if either is open; see [bounds::Combine].
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// let x = Interval::closed_unchecked(0.0, 1.0) + Interval::lcro_unchecked(10.0, 20.0);
+///
+/// assert_eq!(x, Interval::lcro_unchecked(10.0, 21.0));
+/// assert_eq!(Interval::left_open(1) + Interval::closed_unchecked(1, 2), Interval::left_open(2));
+/// ```
+impl<L, R, LL, RR> Add<Interval<LL, RR>> for Interval<L, R>
+where
+    L: bounds::Combine<LL>,
+    R: bounds::Combine<RR, Value = L::Value>,
+
+    LL: Bound<Value = L::Value>,
+    RR: Bound<Value = L::Value>,
+
+    L::Value: Add<Output = L::Value>,
+{
+    type Output = Interval<L::Output, R::Output>;
+
+    fn add(self, other: Interval<LL, RR>) -> Self::Output {
+        Interval::new_unchecked(
+            self.left.combine(other.left, |a, c| a + c),
+            self.right.combine(other.right, |b, d| b + d),
+        )
+    }
+}
+
+/// Minkowski difference of two intervals, `[a, b] - [c, d] = [a - d, b - c]`.
+///
+/// Bounds combine as for the Minkowski sum, such that `x - y` equals
+/// `x + (-y)`. The value type must implement `Neg`, which excludes unsigned
+/// integers, whose differences could underflow:
+///
+/// ```compile_fail
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// let _ = Interval::closed_unchecked(0u32, 1) - Interval::closed_unchecked(2u32, 3);
+/// ```
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// let x = Interval::closed_unchecked(0.0, 1.0) - Interval::lcro_unchecked(10.0, 20.0);
+///
+/// assert_eq!(x, Interval::lorc_unchecked(-20.0, -9.0));
+/// assert_eq!(Interval::left_open(1) - Interval::closed_unchecked(1, 2), Interval::left_open(-1));
+/// ```
+impl<L, R, LL, RR> Sub<Interval<LL, RR>> for Interval<L, R>
+where
+    L: bounds::Combine<RR>,
+    R: bounds::Combine<LL, Value = L::Value>,
+
+    LL: Bound<Value = L::Value>,
+    RR: Bound<Value = L::Value>,
+
+    L::Value: Sub<Output = L::Value> + Neg<Output = L::Value>,
+{
+    type Output = Interval<L::Output, R::Output>;
+
+    fn sub(self, other: Interval<LL, RR>) -> Self::Output {
+        Interval::new_unchecked(
+            self.left.combine(other.right, |a, d| a - d),
+            self.right.combine(other.left, |b, c| b - c),
+        )
+    }
+}
+
+// Returns the product of two proper bounds' values, and whether it is attained
+// within the intervals; a closed zero factor attains zero whatever the other.
+fn product<V: PartialOrd + Clone + Mul<Output = V> + Zero>(
+    x: &OpenOrClosed<V>,
+    y: &OpenOrClosed<V>,
+) -> (V, bool) {
+    let closed = |b: &OpenOrClosed<V>| matches!(b, OpenOrClosed::Closed(_));
+    let (xv, yv) = (x.proper_value(), y.proper_value());
+
+    let attained = (closed(x) && closed(y))
+        || (closed(x) && xv.is_zero())
+        || (closed(y) && yv.is_zero());
+
+    (xv.clone() * yv.clone(), attained)
+}
+
+// Returns the least (or greatest) of the values, attained if any instance of
+// it is attained.
+fn extremum<V: PartialOrd + Clone>(values: &[(V, bool)], greater: bool) -> (V, bool) {
+    let mut best = values[0].clone();
+
+    for (v, attained) in values[1..].iter().cloned() {
+        match v.partial_cmp(&best.0) {
+            Some(Ordering::Equal) => best.1 |= attained,
+            Some(Ordering::Greater) if greater => best = (v, attained),
+            Some(Ordering::Less) if !greater => best = (v, attained),
+            _ => {},
+        }
+    }
+
+    best
+}
+
+/// Product of two bounded intervals, following interval arithmetic.
+///
+/// The bounds of the result are the least and greatest of the four products
+/// of endpoints. Each is closed if it is attained, i.e. if some product
+/// achieving it involves only closed endpoints, or a closed endpoint at zero.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::{Interval, bounds::OpenOrClosed};
+/// let x = Interval::closed_unchecked(-1.0, 2.0) * Interval::lcro_unchecked(-3.0, 1.0);
+///
+/// assert_eq!(x.left, OpenOrClosed::Closed(-6.0));
+/// assert_eq!(x.right, OpenOrClosed::Closed(3.0));
+///
+/// let y = Interval::closed_unchecked(-1.0, 2.0) * Interval::lorc_unchecked(-3.0, 1.0);
+///
+/// assert_eq!(y.left, OpenOrClosed::Open(-6.0));
+/// assert_eq!(y.right, OpenOrClosed::Open(3.0));
+///
+/// let zero = Interval::degenerate(0.0) * Interval::open_unchecked(1.0, 2.0);
+///
+/// assert_eq!(zero, Interval::new_unchecked(OpenOrClosed::Closed(0.0), OpenOrClosed::Closed(0.0)));
+/// ```
+impl<L, R, LL, RR> Mul<Interval<LL, RR>> for Interval<L, R>
+where
+    L: ProperBound + Into<OpenOrClosed<L::Value>>,
+    R: ProperBound<Value = L::Value> + Into<OpenOrClosed<L::Value>>,
+
+    LL: ProperBound<Value = L::Value> + Into<OpenOrClosed<L::Value>>,
+    RR: ProperBound<Value = L::Value> + Into<OpenOrClosed<L::Value>>,
+
+    L::Value: Clone + Mul<Output = L::Value> + Zero,
+{
+    type Output = Interval<OpenOrClosed<L::Value>, OpenOrClosed<L::Value>>;
+
+    fn mul(self, other: Interval<LL, RR>) -> Self::Output {
+        let (a, b) = (self.left.into(), self.right.into());
+        let (c, d) = (other.left.into(), other.right.into());
+
+        let products = [product(&a, &c), product(&a, &d), product(&b, &c), product(&b, &d)];
+
+        Interval::new_unchecked(end_bound(extremum(&products, false)), end_bound(extremum(&products, true)))
+    }
+}
+
+/// Quotient of two bounded intervals; see the `Div<Interval>` implementation.
+///
+/// Dividing by an interval with zero as an endpoint yields a ray, and
+/// dividing by one with zero in its interior may yield two disjoint rays.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum Quotient<V: PartialOrd> {
+    /// The quotient is a bounded interval.
+    Bounded(Interval<OpenOrClosed<V>, OpenOrClosed<V>>),
+
+    /// The quotient is bounded below, but not above.
+    LeftBounded(Interval<OpenOrClosed<V>, bounds::NoBound<V>>),
+
+    /// The quotient is bounded above, but not below.
+    RightBounded(Interval<bounds::NoBound<V>, OpenOrClosed<V>>),
+
+    /// The quotient is the union of two disjoint rays, the lower one first.
+    Split(Interval<bounds::NoBound<V>, OpenOrClosed<V>>, Interval<OpenOrClosed<V>, bounds::NoBound<V>>),
+
+    /// The quotient is the whole real line.
+    Unbounded,
+
+    /// The divisor holds no non-zero values, so the quotient is empty.
+    Empty,
+}
+
+// An endpoint on the extended real line, and whether it is attained.
+type End<V> = (V, bool);
+
+// Product of two endpoints, where zero annihilates even an infinite factor.
+fn end_product<V: Float>(x: End<V>, y: End<V>) -> End<V> {
+    if x.0.is_zero() || y.0.is_zero() {
+        (V::zero(), (x.1 && x.0.is_zero()) || (y.1 && y.0.is_zero()))
+    } else {
+        (x.0 * y.0, x.1 && y.1)
+    }
+}
+
+// Quotient of `[a, b]` by a divisor `[c, d]` of constant sign, which may only
+// touch zero at an open endpoint.
+fn definite_quotient<V: Float>(a: End<V>, b: End<V>, c: End<V>, d: End<V>) -> (End<V>, End<V>) {
+    let recip = |e: End<V>, at_zero: V| if e.0.is_zero() { (at_zero, false) } else { (e.0.recip(), e.1) };
+    let (lo, hi) = (recip(d, V::neg_infinity()), recip(c, V::infinity()));
+
+    let products = [end_product(a, lo), end_product(a, hi), end_product(b, lo), end_product(b, hi)];
+
+    (extremum(&products, false), extremum(&products, true))
+}
+
+fn end_bound<V>((v, closed): End<V>) -> OpenOrClosed<V> {
+    if closed { OpenOrClosed::Closed(v) } else { OpenOrClosed::Open(v) }
+}
+
+fn quotient_of<V: Float>((lo, hi): (End<V>, End<V>)) -> Quotient<V> {
+    match (lo.0 == V::neg_infinity(), hi.0 == V::infinity()) {
+        (true, true) => Quotient::Unbounded,
+        (false, true) => Quotient::LeftBounded(Interval::new_unchecked(end_bound(lo), bounds::NoBound::new())),
+        (true, false) => Quotient::RightBounded(Interval::new_unchecked(bounds::NoBound::new(), end_bound(hi))),
+        (false, false) => Quotient::Bounded(Interval::new_unchecked(end_bound(lo), end_bound(hi))),
+    }
+}
+
+/// Quotient of two bounded intervals, following interval arithmetic.
+///
+/// If the divisor excludes zero then this is the product with its
+/// reciprocal, which is unbounded if zero is an (open) endpoint of the
+/// divisor. Otherwise zero is dropped from the divisor before dividing: a
+/// closed zero endpoint again yields a ray, zero in the interior of the
+/// divisor yields up to two disjoint rays, and a divisor of `[0, 0]` yields
+/// [Quotient::Empty]. Openness is tracked as for `Mul<Interval>`.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::{Interval, arithmetic::Quotient};
+/// let x = Interval::closed_unchecked(1.0, 2.0);
+///
+/// match x / Interval::lorc_unchecked(0.0, 2.0) {
+///     Quotient::LeftBounded(z) => assert_eq!(z.to_string(), "[0.5, \u{221E})"),
+///     _ => unreachable!(),
+/// }
+///
+/// match x / Interval::closed_unchecked(-1.0, 1.0) {
+///     Quotient::Split(a, b) => assert_eq!((a.to_string(), b.to_string()), ("(-\u{221E}, -1]".into(), "[1, \u{221E})".into())),
+///     _ => unreachable!(),
+/// }
+///
+/// assert_eq!(x / Interval::degenerate(0.0), Quotient::Empty);
+/// ```
+impl<L, R, LL, RR> Div<Interval<LL, RR>> for Interval<L, R>
+where
+    L: ProperBound + Into<OpenOrClosed<L::Value>>,
+    R: ProperBound<Value = L::Value> + Into<OpenOrClosed<L::Value>>,
+
+    LL: ProperBound<Value = L::Value> + Into<OpenOrClosed<L::Value>>,
+    RR: ProperBound<Value = L::Value> + Into<OpenOrClosed<L::Value>>,
+
+    L::Value: Float,
+{
+    type Output = Quotient<L::Value>;
+
+    fn div(self, other: Interval<LL, RR>) -> Quotient<L::Value> {
+        let end = |b: OpenOrClosed<L::Value>| (*b.proper_value(), b.is_closed());
+
+        let (a, b) = (end(self.left.into()), end(self.right.into()));
+        let (c, d) = (end(other.left.into()), end(other.right.into()));
+
+        let zero = (L::Value::zero(), false);
+
+        if c.0.is_zero() && d.0.is_zero() { return Quotient::Empty; }
+
+        if c.0 > L::Value::zero() || d.0 < L::Value::zero() || !c.1 && c.0.is_zero() || !d.1 && d.0.is_zero() {
+            return quotient_of(definite_quotient(a, b, c, d));
+        }
+
+        if c.0.is_zero() { return quotient_of(definite_quotient(a, b, zero, d)); }
+        if d.0.is_zero() { return quotient_of(definite_quotient(a, b, c, zero)); }
+
+        // Zero lies strictly inside the divisor, so divide by each side of it.
+        let below = definite_quotient(a, b, c, zero);
+        let above = definite_quotient(a, b, zero, d);
+
+        let (first, second) = if below.0.0 <= above.0.0 { (below, above) } else { (above, below) };
+
+        let connected = first.1.0 > second.0.0 || first.1.0 == second.0.0 && (first.1.1 || second.0.1);
+
+        if connected {
+            let lo = extremum(&[first.0, second.0], false);
+            let hi = extremum(&[first.1, second.1], true);
+
+            quotient_of((lo, hi))
+        } else {
+            Quotient::Split(
+                Interval::new_unchecked(bounds::NoBound::new(), end_bound(first.1)),
+                Interval::new_unchecked(end_bound(second.0), bounds::NoBound::new()),
+            )
+        }
+    }
+}
+
+// Scalar operators are implemented per primitive type, rather than for any
+// value type, such that they don't overlap with the operators between two
+// intervals. Note that literals may then need a suffix, e.g. `x + 1i64`.
+macro_rules! impl_scalar_ops {
+    ($($v:ty),+) => {$(
+        impl<L, R> Add<$v> for Interval<L, R>
+        where
+            L: Bound<Value = $v>,
+            R: Bound<Value = $v>,
+        {
+            type Output = Self;
+
+            fn add(self, d: $v) -> Self { self.translate(d) }
+        }
+
+        impl<L, R> Sub<$v> for Interval<L, R>
+        where
+            L: Bound<Value = $v>,
+            R: Bound<Value = $v>,
+        {
+            type Output = Self;
+
+            fn sub(self, d: $v) -> Self { self.translate_down(d) }
+        }
+
+        impl<L, R> Mul<$v> for Interval<L, R>
+        where
+            L: Bound<Value = $v>,
+            R: Bound<Value = $v>,
+
+            Self: Transform<Value = $v>,
+        {
+            type Output = <Self as Transform>::Output;
+
+            fn mul(self, k: $v) -> Self::Output { self.scale(k) }
+        }
+
+        impl<L, R> Div<$v> for Interval<L, R>
+        where
+            L: Bound<Value = $v>,
+            R: Bound<Value = $v>,
+
+            Self: Transform<Value = $v>,
+        {
+            type Output = <Self as Transform>::Output;
+
+            fn div(self, k: $v) -> Self::Output { self.scale_down(k) }
+        }
+    )+};
+}
+
+impl_scalar_ops!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+// Map the value of a bound (if any) through the fallible function `f`.
+fn checked_map<B, F>(bound: B, f: F) -> Option<B>
+where
+    B: Bound,
+    F: FnOnce(&B::Value) -> Option<B::Value>,
+{
+    match bound.value().map(f) {
+        Some(Some(v)) => Some(bound.map_value(|_| v)),
+        Some(None) => None,
+        None => Some(bound),
+    }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+{
+    /// Translate the interval by `d`, or `None` on overflow.
+    ///
+    /// Unbounded sides are left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::closed_unchecked(0i8, 10).checked_translate(5), Some(Interval::closed_unchecked(5, 15)));
+    /// assert_eq!(Interval::closed_unchecked(0i8, 125).checked_translate(5), None);
+    /// assert_eq!(Interval::left_open(0i8).checked_translate(-5), Some(Interval::left_open(-5)));
+    /// ```
+    pub fn checked_translate(self, d: L::Value) -> Option<Self>
+    where
+        L::Value: CheckedAdd,
+    {
+        let left = checked_map(self.left, |x| x.checked_add(&d))?;
+        let right = checked_map(self.right, |x| x.checked_add(&d))?;
+
+        Some(Interval::new_unchecked(left, right))
+    }
+
+    /// Translate the interval by `-d`, or `None` on overflow.
+    ///
+    /// This is the counterpart of [Interval::checked_translate] for unsigned
+    /// value types, in which negative offsets can't be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::closed_unchecked(5u8, 10).checked_translate_down(5), Some(Interval::closed_unchecked(0, 5)));
+    /// assert_eq!(Interval::closed_unchecked(5u8, 10).checked_translate_down(6), None);
+    /// ```
+    pub fn checked_translate_down(self, d: L::Value) -> Option<Self>
+    where
+        L::Value: CheckedSub,
+    {
+        let left = checked_map(self.left, |x| x.checked_sub(&d))?;
+        let right = checked_map(self.right, |x| x.checked_sub(&d))?;
+
+        Some(Interval::new_unchecked(left, right))
+    }
+
+    /// Scale the interval by a factor `k` about zero, or `None` on overflow.
+    ///
+    /// Unlike [Interval::scale_about], the bounds are not swapped for negative
+    /// `k`, so the result is re-validated: decreasing bounds (or a zero `k`
+    /// with open bounds) yield an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(-10i8, 20);
+    ///
+    /// assert_eq!(x.checked_scale(2).unwrap().unwrap(), Interval::closed_unchecked(-20, 40));
+    /// assert!(x.checked_scale(-1).unwrap().is_err());
+    /// assert!(x.checked_scale(10).is_none());
+    /// ```
+    pub fn checked_scale(self, k: L::Value) -> Option<IntervalResult<L, R>>
+    where
+        L::Value: CheckedMul,
+        bounds::Validator: bounds::ValidateBounds<L, R>,
+    {
+        let left = checked_map(self.left, |x| x.checked_mul(&k))?;
+        let right = checked_map(self.right, |x| x.checked_mul(&k))?;
+
+        Some(Interval::new(left, right))
+    }
+
+    /// Translate the interval by `d`, saturating at the numeric bounds of the
+    /// value type.
+    ///
+    /// Saturation is monotone, so the bounds never cross, though they may
+    /// coincide at the extremes of the type.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::closed_unchecked(120i8, 126).saturating_translate(5), Interval::closed_unchecked(125, 127));
+    /// assert_eq!(Interval::closed_unchecked(126i8, 127).saturating_translate(5), Interval::degenerate(127));
+    /// ```
+    pub fn saturating_translate(self, d: L::Value) -> Self
+    where
+        L::Value: SaturatingAdd,
+    {
+        self.map_left(|x| x.saturating_add(&d)).map_right(|x| x.saturating_add(&d))
+    }
+
+    /// Translate the interval by `-d`, saturating at the numeric bounds of
+    /// the value type; see [Interval::saturating_translate].
+    pub fn saturating_translate_down(self, d: L::Value) -> Self
+    where
+        L::Value: SaturatingSub,
+    {
+        self.map_left(|x| x.saturating_sub(&d)).map_right(|x| x.saturating_sub(&d))
+    }
+
+    /// Scale the interval by a factor `k` about zero, saturating at the
+    /// numeric bounds of the value type.
+    ///
+    /// As for [Interval::checked_scale], the result is re-validated.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(-10i8, 20);
+    ///
+    /// assert_eq!(x.saturating_scale(10).unwrap(), Interval::closed_unchecked(-100, 127));
+    /// assert!(x.saturating_scale(-1).is_err());
+    /// ```
+    pub fn saturating_scale(self, k: L::Value) -> IntervalResult<L, R>
+    where
+        L::Value: SaturatingMul,
+        bounds::Validator: bounds::ValidateBounds<L, R>,
+    {
+        let left = self.left.map_value(|x| x.saturating_mul(&k));
+        let right = self.right.map_value(|x| x.saturating_mul(&k));
+
+        Interval::new(left, right)
+    }
+
+    /// Widen the interval by `eps` on each side, saturating at the numeric
+    /// bounds of the value type; see [Interval::saturating_pad].
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let window = Interval::lcro_unchecked(2usize, 6);
+    ///
+    /// assert_eq!(window.saturating_expand(3), Ok(Interval::lcro_unchecked(0, 9)));
+    /// ```
+    pub fn saturating_expand(self, eps: L::Value) -> std::result::Result<Self, PadError>
+    where
+        L::Value: SaturatingAdd + SaturatingSub + Zero + Clone,
+    {
+        self.saturating_pad(eps.clone(), eps)
+    }
+
+    /// Widen the interval by `l` on the left and `r` on the right, saturating
+    /// at the numeric bounds of the value type.
+    ///
+    /// Each endpoint moves outwards, or stays put once it reaches `V::MIN` or
+    /// `V::MAX`, so the bounds of the result are never decreasing. Negative
+    /// amounts yield [PadError::Negative], as for [Interval::expand].
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, arithmetic::PadError};
+    /// let x = Interval::closed_unchecked(-120i8, 120);
+    ///
+    /// assert_eq!(x.saturating_pad(5, 10), Ok(Interval::closed_unchecked(-125, 127)));
+    /// assert_eq!(x.saturating_pad(10, 0), Ok(Interval::closed_unchecked(-128, 120)));
+    /// assert_eq!(x.saturating_pad(-1, 0), Err(PadError::Negative));
+    /// ```
+    pub fn saturating_pad(self, l: L::Value, r: L::Value) -> std::result::Result<Self, PadError>
+    where
+        L::Value: SaturatingAdd + SaturatingSub + Zero,
+    {
+        let zero = L::Value::zero();
+
+        if !(l >= zero && r >= zero) { return Err(PadError::Negative); }
+
+        Ok(self.map_left(|x| x.saturating_sub(&l)).map_right(|x| x.saturating_add(&r)))
+    }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: Bound + Clone + Into<OpenOrClosed<L::Value>>,
+    R: Bound<Value = L::Value> + Clone + Into<OpenOrClosed<L::Value>>,
+
+    L::Value: Num + Clone,
+{
+    /// Translate `inner` so that it lies within the interval, shrinking it
+    /// only if it does not fit.
+    ///
+    /// Endpoints of `inner` keep their openness unless they are pinned to a
+    /// bound of `self`, in which case they take that bound. An endpoint that
+    /// coincides with a bound of `self` is only pinned if it would otherwise
+    /// fall outside, e.g. a closed endpoint meeting an open bound.
+    ///
+    /// If `inner` is too wide to fit then its left endpoint is pinned first
+    /// and the excess is cut away on the right; the result is then `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, bounds::OpenOrClosed};
+    /// let viewport = Interval::closed_unchecked(0.0, 10.0);
+    ///
+    /// let x = viewport.clamp_interval(Interval::lcro_unchecked(8.0, 12.0));
+    ///
+    /// assert_eq!(x.left, OpenOrClosed::Closed(6.0));
+    /// assert_eq!(x.right, OpenOrClosed::Closed(10.0));
+    ///
+    /// let x = viewport.clamp_interval(Interval::lcro_unchecked(-5.0, 20.0));
+    ///
+    /// assert_eq!(x, viewport);
+    /// ```
+    pub fn clamp_interval<LL, RR>(
+        &self,
+        inner: Interval<LL, RR>,
+    ) -> Interval<OpenOrClosed<L::Value>, OpenOrClosed<L::Value>>
+    where
+        LL: Bound<Value = L::Value> + Into<OpenOrClosed<L::Value>>,
+        RR: Bound<Value = L::Value> + Into<OpenOrClosed<L::Value>>,
+    {
+        let outer_left: OpenOrClosed<L::Value> = self.left.clone().into();
+        let outer_right: OpenOrClosed<L::Value> = self.right.clone().into();
+
+        let inner_left = inner.left.into();
+        let inner_right = inner.right.into();
+
+        match (exceeds_left(&outer_left, &inner_left), exceeds_right(&outer_right, &inner_right)) {
+            (false, false) => Interval::new_unchecked(inner_left, inner_right),
+            (true, false) => {
+                let delta = outer_left.proper_value().clone() - inner_left.proper_value().clone();
+                let inner_right = inner_right.map_value(|x| x + delta);
+
+                if exceeds_right(&outer_right, &inner_right) {
+                    Interval::new_unchecked(outer_left, outer_right)
+                } else {
+                    Interval::new_unchecked(outer_left, inner_right)
+                }
+            },
+            (false, true) => {
+                let delta = inner_right.proper_value().clone() - outer_right.proper_value().clone();
+                let inner_left = inner_left.map_value(|x| x - delta);
+
+                if exceeds_left(&outer_left, &inner_left) {
+                    Interval::new_unchecked(outer_left, outer_right)
+                } else {
+                    Interval::new_unchecked(inner_left, outer_right)
+                }
+            },
+            (true, true) => Interval::new_unchecked(outer_left, outer_right),
+        }
+    }
+}
+
+fn exceeds_left<V: PartialOrd>(outer: &OpenOrClosed<V>, inner: &OpenOrClosed<V>) -> bool {
+    let (o, i) = (outer.proper_value(), inner.proper_value());
+
+    i < o || (i == o && outer.is_open() && inner.is_closed())
+}
+
+fn exceeds_right<V: PartialOrd>(outer: &OpenOrClosed<V>, inner: &OpenOrClosed<V>) -> bool {
+    let (o, i) = (outer.proper_value(), inner.proper_value());
+
+    i > o || (i == o && outer.is_open() && inner.is_closed())
+}
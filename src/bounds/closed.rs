@@ -1,7 +1,7 @@
 use super::*;
 
 /// Type representing a closed bound.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(
     feature = "serde",
     derive(Serialize, Deserialize),
@@ -15,28 +15,54 @@ impl<V> crate::private::Sealed for Closed<V> {}
 impl<V: PartialOrd> Bound for Closed<V> {
     type Value = V;
     type WithLimit = Closed<V>;
+    type WithoutLimit = Open<V>;
+    type Ref<'a> = Closed<&'a V> where V: 'a;
+    type Mapped<U: PartialOrd> = Closed<U>;
 
     fn value(&self) -> Option<&Self::Value> { Some(&self.0) }
 
+    fn into_value(self) -> Option<Self::Value> { Some(self.0) }
+
     fn is_open(&self) -> bool { false }
 
     fn is_closed(&self) -> bool { true }
 
     fn with_limit_point(self) -> Self::WithLimit { self }
+
+    fn without_limit_point(self) -> Self::WithoutLimit { Open(self.0) }
+
+    fn map_value<F: FnOnce(V) -> V>(self, f: F) -> Self { Closed(f(self.0)) }
+
+    fn map<U: PartialOrd, F: FnOnce(V) -> U>(self, f: F) -> Closed<U> { Closed(f(self.0)) }
+
+    fn as_ref(&self) -> Self::Ref<'_> { Closed(&self.0) }
 }
 
 impl<V: PartialOrd> ProperBound for Closed<V> {
     fn proper_value(&self) -> &Self::Value { &self.0 }
 }
 
+impl<V: PartialOrd> FromStdBound for Closed<V> {
+    fn from_std_bound(bound: std::ops::Bound<V>) -> Result<Self, std::ops::Bound<V>> {
+        match bound {
+            std::ops::Bound::Included(v) => Ok(Closed(v)),
+            bound => Err(bound),
+        }
+    }
+}
+
 // Formatting:
 impl<V: PartialOrd + fmt::Display> BoundDisplay for Closed<V> {
     fn fmt_left(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "[{}", self.0)
+        f.write_str("[")?;
+
+        fmt::Display::fmt(&self.0, f)
     }
 
     fn fmt_right(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}]", self.0)
+        fmt::Display::fmt(&self.0, f)?;
+
+        f.write_str("]")
     }
 }
 
@@ -122,7 +148,7 @@ impl<V> std::cmp::PartialEq<NoBound<V>> for Closed<V> {
 impl<V: PartialEq> std::cmp::PartialEq<OpenOrClosed<V>> for Closed<V> {
     fn eq(&self, rhs: &OpenOrClosed<V>) -> bool {
         match rhs {
-            &OpenOrClosed::Closed(ref inner) => self.0.eq(&inner),
+            OpenOrClosed::Closed(inner) => self.0.eq(inner),
             _ => false,
         }
     }
@@ -0,0 +1,52 @@
+use super::*;
+
+macro_rules! impl_combine {
+    ($v:ident; $l:ty, $r:ty => $out:ty; |$x:ident, $open:ident| $code:expr) => {
+        impl<$v: PartialOrd> Combine<$r> for $l {
+            type Output = $out;
+
+            fn combine<F: FnOnce($v, $v) -> $v>(self, other: $r, f: F) -> $out {
+                let $open = self.is_open() || other.is_open();
+                let $x = f(proper(self), proper(other));
+
+                $code
+            }
+        }
+    };
+    ($v:ident; $l:ty, $r:ty => NoBound) => {
+        impl<$v: PartialOrd> Combine<$r> for $l {
+            type Output = NoBound<$v>;
+
+            fn combine<F: FnOnce($v, $v) -> $v>(self, _: $r, _: F) -> NoBound<$v> { NoBound::new() }
+        }
+    };
+}
+
+// Returns the value of a proper bound.
+fn proper<B: ProperBound>(bound: B) -> B::Value {
+    bound.into_value().expect("proper bounds have a value")
+}
+
+impl_combine!(V; NoBound<V>, NoBound<V> => NoBound);
+impl_combine!(V; NoBound<V>, Open<V> => NoBound);
+impl_combine!(V; NoBound<V>, Closed<V> => NoBound);
+impl_combine!(V; NoBound<V>, OpenOrClosed<V> => NoBound);
+impl_combine!(V; Open<V>, NoBound<V> => NoBound);
+impl_combine!(V; Closed<V>, NoBound<V> => NoBound);
+impl_combine!(V; OpenOrClosed<V>, NoBound<V> => NoBound);
+
+impl_combine!(V; Open<V>, Open<V> => Open<V>; |x, _open| Open(x));
+impl_combine!(V; Open<V>, Closed<V> => Open<V>; |x, _open| Open(x));
+impl_combine!(V; Open<V>, OpenOrClosed<V> => Open<V>; |x, _open| Open(x));
+impl_combine!(V; Closed<V>, Open<V> => Open<V>; |x, _open| Open(x));
+impl_combine!(V; OpenOrClosed<V>, Open<V> => Open<V>; |x, _open| Open(x));
+
+impl_combine!(V; Closed<V>, Closed<V> => Closed<V>; |x, _open| Closed(x));
+
+impl_combine!(V; Closed<V>, OpenOrClosed<V> => OpenOrClosed<V>; |x, open| mixed(x, open));
+impl_combine!(V; OpenOrClosed<V>, Closed<V> => OpenOrClosed<V>; |x, open| mixed(x, open));
+impl_combine!(V; OpenOrClosed<V>, OpenOrClosed<V> => OpenOrClosed<V>; |x, open| mixed(x, open));
+
+fn mixed<V>(x: V, open: bool) -> OpenOrClosed<V> {
+    if open { OpenOrClosed::Open(x) } else { OpenOrClosed::Closed(x) }
+}
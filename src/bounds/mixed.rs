@@ -1,7 +1,7 @@
 use super::*;
 
 /// Union type representing a bound that is either open or closed.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(
     feature = "serde",
     derive(Serialize, Deserialize),
@@ -36,6 +36,9 @@ impl<V> crate::private::Sealed for OpenOrClosed<V> {}
 impl<V: PartialOrd> Bound for OpenOrClosed<V> {
     type Value = V;
     type WithLimit = Closed<V>;
+    type WithoutLimit = Open<V>;
+    type Ref<'a> = OpenOrClosed<&'a V> where V: 'a;
+    type Mapped<U: PartialOrd> = OpenOrClosed<U>;
 
     fn value(&self) -> Option<&Self::Value> {
         match self {
@@ -43,6 +46,8 @@ impl<V: PartialOrd> Bound for OpenOrClosed<V> {
         }
     }
 
+    fn into_value(self) -> Option<Self::Value> { Some(self.unwrap()) }
+
     fn is_open(&self) -> bool {
         match self {
             OpenOrClosed::Open(_) => true,
@@ -62,6 +67,33 @@ impl<V: PartialOrd> Bound for OpenOrClosed<V> {
             OpenOrClosed::Open(v) | OpenOrClosed::Closed(v) => Closed(v),
         }
     }
+
+    fn without_limit_point(self) -> Self::WithoutLimit {
+        match self {
+            OpenOrClosed::Open(v) | OpenOrClosed::Closed(v) => Open(v),
+        }
+    }
+
+    fn map_value<F: FnOnce(V) -> V>(self, f: F) -> Self {
+        match self {
+            OpenOrClosed::Open(v) => OpenOrClosed::Open(f(v)),
+            OpenOrClosed::Closed(v) => OpenOrClosed::Closed(f(v)),
+        }
+    }
+
+    fn map<U: PartialOrd, F: FnOnce(V) -> U>(self, f: F) -> OpenOrClosed<U> {
+        match self {
+            OpenOrClosed::Open(v) => OpenOrClosed::Open(f(v)),
+            OpenOrClosed::Closed(v) => OpenOrClosed::Closed(f(v)),
+        }
+    }
+
+    fn as_ref(&self) -> Self::Ref<'_> {
+        match self {
+            OpenOrClosed::Open(ref v) => OpenOrClosed::Open(v),
+            OpenOrClosed::Closed(ref v) => OpenOrClosed::Closed(v),
+        }
+    }
 }
 
 impl<V: PartialOrd> ProperBound for OpenOrClosed<V> {
@@ -72,6 +104,16 @@ impl<V: PartialOrd> ProperBound for OpenOrClosed<V> {
     }
 }
 
+impl<V: PartialOrd> FromStdBound for OpenOrClosed<V> {
+    fn from_std_bound(bound: std::ops::Bound<V>) -> Result<Self, std::ops::Bound<V>> {
+        match bound {
+            std::ops::Bound::Excluded(v) => Ok(OpenOrClosed::Open(v)),
+            std::ops::Bound::Included(v) => Ok(OpenOrClosed::Closed(v)),
+            bound => Err(bound),
+        }
+    }
+}
+
 impl<V: PartialOrd + fmt::Display> BoundDisplay for OpenOrClosed<V> {
     fn fmt_left(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -141,15 +183,15 @@ impl<V: PartialOrd> Pinch<OpenOrClosed<V>> for OpenOrClosed<V> {
 
     fn pinch_left(self, other: OpenOrClosed<V>) -> OpenOrClosed<V> {
         match self {
-            OpenOrClosed::Open(x) => Open(x).pinch_left(other).into(),
-            OpenOrClosed::Closed(x) => Closed(x).pinch_left(other).into(),
+            OpenOrClosed::Open(x) => Open(x).pinch_left(other),
+            OpenOrClosed::Closed(x) => Closed(x).pinch_left(other),
         }
     }
 
     fn pinch_right(self, other: OpenOrClosed<V>) -> OpenOrClosed<V> {
         match self {
-            OpenOrClosed::Open(x) => Open(x).pinch_right(other).into(),
-            OpenOrClosed::Closed(x) => Closed(x).pinch_right(other).into(),
+            OpenOrClosed::Open(x) => Open(x).pinch_right(other),
+            OpenOrClosed::Closed(x) => Closed(x).pinch_right(other),
         }
     }
 }
@@ -161,15 +203,15 @@ impl<V: PartialOrd> Unroll<OpenOrClosed<V>> for OpenOrClosed<V> {
 
     fn unroll_left(self, other: OpenOrClosed<V>) -> OpenOrClosed<V> {
         match self {
-            OpenOrClosed::Open(x) => Open(x).unroll_left(other).into(),
-            OpenOrClosed::Closed(x) => Closed(x).unroll_left(other).into(),
+            OpenOrClosed::Open(x) => Open(x).unroll_left(other),
+            OpenOrClosed::Closed(x) => Closed(x).unroll_left(other),
         }
     }
 
     fn unroll_right(self, other: OpenOrClosed<V>) -> OpenOrClosed<V> {
         match self {
-            OpenOrClosed::Open(x) => Open(x).unroll_right(other).into(),
-            OpenOrClosed::Closed(x) => Closed(x).unroll_right(other).into(),
+            OpenOrClosed::Open(x) => Open(x).unroll_right(other),
+            OpenOrClosed::Closed(x) => Closed(x).unroll_right(other),
         }
     }
 }
@@ -223,7 +265,7 @@ impl_unroll!(V; Closed<V>);
 impl<V: PartialEq> std::cmp::PartialEq<Open<V>> for OpenOrClosed<V> {
     fn eq(&self, rhs: &Open<V>) -> bool {
         match self {
-            &OpenOrClosed::Open(ref inner) => inner.eq(&rhs.0),
+            OpenOrClosed::Open(inner) => inner.eq(&rhs.0),
             _ => false,
         }
     }
@@ -232,7 +274,7 @@ impl<V: PartialEq> std::cmp::PartialEq<Open<V>> for OpenOrClosed<V> {
 impl<V: PartialEq> std::cmp::PartialEq<Closed<V>> for OpenOrClosed<V> {
     fn eq(&self, rhs: &Closed<V>) -> bool {
         match self {
-            &OpenOrClosed::Closed(ref inner) => inner.eq(&rhs.0),
+            OpenOrClosed::Closed(inner) => inner.eq(&rhs.0),
             _ => false,
         }
     }
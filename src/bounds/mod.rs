@@ -1,5 +1,5 @@
 //! Module containing bound types.
-use std::fmt;
+use std::{cmp::Ordering, fmt};
 
 /// Trait for types that represent upper/lower bounds.
 pub trait Bound: crate::private::Sealed {
@@ -9,9 +9,18 @@ pub trait Bound: crate::private::Sealed {
     /// Corresponding bound given inclusion of limit point.
     type WithLimit: Bound<Value = Self::Value>;
 
+    /// Corresponding bound given exclusion of limit point.
+    type WithoutLimit: Bound<Value = Self::Value>;
+
+    /// Corresponding bound over a reference to the value.
+    type Ref<'a>: Bound<Value = &'a Self::Value> where Self: 'a;
+
     /// Returns the value of the bound if one exists.
     fn value(&self) -> Option<&Self::Value>;
 
+    /// Consumes the bound, returning its value if one exists.
+    fn into_value(self) -> Option<Self::Value>;
+
     /// Returns true if the bound is open.
     fn is_open(&self) -> bool;
 
@@ -20,6 +29,41 @@ pub trait Bound: crate::private::Sealed {
 
     /// Returns the corresponding bound with its limit point.
     fn with_limit_point(self) -> Self::WithLimit;
+
+    /// Returns the corresponding bound without its limit point.
+    fn without_limit_point(self) -> Self::WithoutLimit;
+
+    /// Corresponding bound over a different value type.
+    type Mapped<U: PartialOrd>: Bound<Value = U>;
+
+    /// Returns the bound with its value (if any) mapped through `f`.
+    fn map_value<F: FnOnce(Self::Value) -> Self::Value>(self, f: F) -> Self;
+
+    /// Returns the bound of the same kind with its value (if any) converted
+    /// through `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::bounds::{Bound, Closed, NoBound};
+    /// assert_eq!(Closed(2i32).map(f64::from), Closed(2.0));
+    /// assert_eq!(NoBound::<i32>::new().map(f64::from), NoBound::new());
+    /// ```
+    fn map<U: PartialOrd, F: FnOnce(Self::Value) -> U>(self, f: F) -> Self::Mapped<U>;
+
+    /// Returns a view of the bound that borrows its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::bounds::{Bound, Open};
+    /// let bound = Open(String::from("a"));
+    ///
+    /// assert_eq!(bound.as_ref(), Open(&bound.0));
+    /// ```
+    fn as_ref(&self) -> Self::Ref<'_>;
 }
 
 /// Trait for bounds that are open or closed.
@@ -27,11 +71,52 @@ pub trait ProperBound: Bound {
     fn proper_value(&self) -> &Self::Value;
 }
 
+/// Trait for bounds that can be constructed from a [std::ops::Bound].
+pub trait FromStdBound: Bound + Sized {
+    /// Construct the bound, handing back `bound` if it is of the wrong kind.
+    fn from_std_bound(bound: std::ops::Bound<Self::Value>) -> Result<Self, std::ops::Bound<Self::Value>>;
+}
+
 /// Trait for formatting bound upper/lower bound strings.
+///
+/// Under the alternate flag (`{:#}`), open and absent bounds are written with
+/// reversed brackets, as in `]0, 1[` and `]-∞, 1]`. The remaining formatter
+/// flags (precision, width, fill and alignment) apply to the bound value
+/// alone; infinities are written as-is.
 pub trait BoundDisplay: Bound {
     fn fmt_left(&self, f: &mut fmt::Formatter) -> fmt::Result;
 
     fn fmt_right(&self, f: &mut fmt::Formatter) -> fmt::Result;
+
+    /// Format the bound as a left bound in the given [Notation](crate::Notation).
+    fn fmt_left_with(&self, f: &mut fmt::Formatter, notation: &crate::Notation) -> fmt::Result
+    where
+        Self::Value: fmt::Display,
+    {
+        match self.value() {
+            None => write!(f, "{}-{}", notation.left_open, notation.infinity),
+            Some(v) => {
+                f.write_str(if self.is_open() { notation.left_open } else { notation.left_closed })?;
+
+                fmt::Display::fmt(v, f)
+            },
+        }
+    }
+
+    /// Format the bound as a right bound in the given [Notation](crate::Notation).
+    fn fmt_right_with(&self, f: &mut fmt::Formatter, notation: &crate::Notation) -> fmt::Result
+    where
+        Self::Value: fmt::Display,
+    {
+        match self.value() {
+            None => write!(f, "{}{}", notation.infinity, notation.right_open),
+            Some(v) => {
+                fmt::Display::fmt(v, f)?;
+
+                f.write_str(if self.is_open() { notation.right_open } else { notation.right_closed })
+            },
+        }
+    }
 }
 
 /// Trait for "pinching" bounds on the left and right.
@@ -113,6 +198,35 @@ pub trait Unroll<T>: Bound {
     fn unroll_right(self, other: T) -> Self::Right;
 }
 
+/// Trait for combining the values of two bounds through an arithmetic
+/// operation, as in interval arithmetic.
+///
+/// The combined bound is unbounded if either bound is, and otherwise open if
+/// either bound is open. The output type is the narrowest bound type that
+/// captures this statically.
+pub trait Combine<T>: Bound {
+    type Output: Bound<Value = Self::Value>;
+
+    /// Returns the bound whose value (if any) is `f` of the two values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::bounds::{self, Combine};
+    /// let a = bounds::Closed(1.0f64);
+    /// let b = bounds::Open(2.0f64);
+    ///
+    /// assert_eq!(a.combine(b, |x, y| x + y), bounds::Open(3.0));
+    /// assert_eq!(a.combine(bounds::NoBound::new(), |x, y| x + y), bounds::NoBound::new());
+    /// ```
+    fn combine<F>(self, other: T, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Value, Self::Value) -> Self::Value;
+}
+
+mod combine;
+
 mod no_bound;
 pub use self::no_bound::NoBound;
 
@@ -125,6 +239,71 @@ pub use self::closed::Closed;
 mod mixed;
 pub use self::mixed::OpenOrClosed;
 
+///////////////////////////////////////////////////////////////////
+// Ordering
+///////////////////////////////////////////////////////////////////
+/// Side of an interval on which a bound lies.
+///
+/// The side determines where a bound sits on the extended number line: a
+/// [NoBound] is negative infinity on the left and positive infinity on the
+/// right, and an open bound lies just inside its value on either side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+// Position of a bound on the extended line: the value (if any), and an offset
+// that is -1, 0 or +1 infinitesimal away from it.
+fn position<B: Bound>(bound: &B, side: Side) -> (Option<&B::Value>, i8) {
+    match (bound.value(), side) {
+        (None, Side::Left) => (None, -1),
+        (None, Side::Right) => (None, 1),
+        (Some(v), _) if bound.is_closed() => (Some(v), 0),
+        (Some(v), Side::Left) => (Some(v), 1),
+        (Some(v), Side::Right) => (Some(v), -1),
+    }
+}
+
+/// Compare the positions of two bounds, given the side on which each lies.
+///
+/// Returns `None` only if the bound values are incomparable (e.g. NaN).
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use std::cmp::Ordering;
+/// # use intervals::bounds::{cmp_bounds, Closed, NoBound, Open, Side};
+/// let unbounded: NoBound<f64> = NoBound::new();
+///
+/// assert_eq!(cmp_bounds(&unbounded, Side::Left, &Closed(0.0), Side::Left), Some(Ordering::Less));
+/// assert_eq!(cmp_bounds(&unbounded, Side::Right, &Closed(0.0), Side::Left), Some(Ordering::Greater));
+///
+/// // The interval (0, 1] starts just after [0, 1]...
+/// assert_eq!(cmp_bounds(&Open(0.0), Side::Left, &Closed(0.0), Side::Left), Some(Ordering::Greater));
+///
+/// // ...and [0, 0) ends just before [0, 0] starts.
+/// assert_eq!(cmp_bounds(&Open(0.0), Side::Right, &Closed(0.0), Side::Left), Some(Ordering::Less));
+/// ```
+pub fn cmp_bounds<A, B>(a: &A, a_side: Side, b: &B, b_side: Side) -> Option<Ordering>
+where
+    A: Bound,
+    B: Bound<Value = A::Value>,
+{
+    let (av, ao) = position(a, a_side);
+    let (bv, bo) = position(b, b_side);
+
+    match (av, bv) {
+        (Some(av), Some(bv)) => match av.partial_cmp(bv)? {
+            Ordering::Equal => Some(ao.cmp(&bo)),
+            ord => Some(ord),
+        },
+        (Some(_), None) => Some(0.cmp(&bo)),
+        (None, Some(_)) => Some(ao.cmp(&0)),
+        (None, None) => Some(ao.cmp(&bo)),
+    }
+}
+
 ///////////////////////////////////////////////////////////////////
 // Validation
 ///////////////////////////////////////////////////////////////////
@@ -136,8 +315,117 @@ where
     <Validator as ValidateBounds<L, R>>::validate(left, right)
 }
 
-/// Utility type for validation of bound pairs.
-pub struct Validator;
+/// Validate left-right bounds under the [ValidationPolicy] `P`.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::bounds::{validate, validate_with, AllowEmpty, Closed, Open, Strict};
+/// assert!(validate(Open(1.0), Open(1.0)).is_err());
+/// assert!(validate_with::<AllowEmpty, _, _>(Open(1.0), Open(1.0)).is_ok());
+///
+/// assert!(validate(Closed(1.0), Closed(1.0)).is_ok());
+/// assert!(validate_with::<Strict, _, _>(Closed(1.0), Closed(1.0)).is_err());
+/// ```
+pub fn validate_with<P, L: Bound, R: Bound>(left: L, right: R) -> ValidationResult<L, R>
+where
+    Validator<P>: ValidateBounds<L, R>,
+{
+    <Validator<P> as ValidateBounds<L, R>>::validate(left, right)
+}
+
+/// Utility type for validation of bound pairs under the [ValidationPolicy] `P`.
+pub struct Validator<P = AllowDegenerate>(std::marker::PhantomData<P>);
+
+/// Trait for policies that determine which pairs of bounds are valid.
+///
+/// Policies only ever see pairs of bounds of which both are proper (open or
+/// closed), or lone proper bounds paired with a [NoBound].
+pub trait ValidationPolicy {
+    /// Validate a pair of proper bounds.
+    fn validate_pair<L, R>(left: L, right: R) -> ValidationResult<L, R>
+    where
+        L: ProperBound,
+        R: ProperBound<Value = L::Value>;
+
+    /// Returns true if the value of a lone proper bound is valid.
+    ///
+    /// The default implementation accepts all values.
+    fn validate_value<V: PartialOrd>(_value: &V) -> bool { true }
+}
+
+/// Policy admitting degenerate closed intervals, but rejecting empty ones.
+///
+/// This is the default policy, as used by [validate] and
+/// [Interval::new](crate::Interval::new).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllowDegenerate;
+
+impl ValidationPolicy for AllowDegenerate {
+    fn validate_pair<L, R>(left: L, right: R) -> ValidationResult<L, R>
+    where
+        L: ProperBound,
+        R: ProperBound<Value = L::Value>,
+    {
+        match left.proper_value().partial_cmp(right.proper_value()) {
+            Some(Ordering::Greater) => Err(ValidationError::DecreasingBounds(left, right)),
+            Some(Ordering::Equal) if left.is_open() || right.is_open() => {
+                Err(ValidationError::DecreasingBounds(left, right))
+            },
+            _ => Ok((left, right)),
+        }
+    }
+}
+
+/// Policy admitting empty intervals, e.g. `(a, a)`, as well as degenerate ones.
+///
+/// Only strictly decreasing bounds are rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllowEmpty;
+
+impl ValidationPolicy for AllowEmpty {
+    fn validate_pair<L, R>(left: L, right: R) -> ValidationResult<L, R>
+    where
+        L: ProperBound,
+        R: ProperBound<Value = L::Value>,
+    {
+        match left.proper_value().partial_cmp(right.proper_value()) {
+            Some(Ordering::Greater) => Err(ValidationError::DecreasingBounds(left, right)),
+            _ => Ok((left, right)),
+        }
+    }
+}
+
+/// Policy admitting only intervals with comparable, strictly increasing bounds.
+///
+/// Empty and degenerate intervals are rejected, as are bound values that are
+/// not comparable with themselves (e.g. NaN), even when paired with a
+/// [NoBound].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Strict;
+
+impl ValidationPolicy for Strict {
+    fn validate_pair<L, R>(left: L, right: R) -> ValidationResult<L, R>
+    where
+        L: ProperBound,
+        R: ProperBound<Value = L::Value>,
+    {
+        if !Self::validate_value(left.proper_value()) || !Self::validate_value(right.proper_value()) {
+            return Err(ValidationError::IncomparableBounds(left, right));
+        }
+
+        match left.proper_value().partial_cmp(right.proper_value()) {
+            Some(Ordering::Less) => Ok((left, right)),
+            Some(Ordering::Equal) if left.is_closed() && right.is_closed() => {
+                Err(ValidationError::DegenerateBounds(left, right))
+            },
+            Some(_) => Err(ValidationError::DecreasingBounds(left, right)),
+            None => Err(ValidationError::IncomparableBounds(left, right)),
+        }
+    }
+
+    fn validate_value<V: PartialOrd>(value: &V) -> bool { value.partial_cmp(value).is_some() }
+}
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(
@@ -145,47 +433,123 @@ pub struct Validator;
     derive(Serialize, Deserialize),
     serde(crate = "serde_crate")
 )]
+#[non_exhaustive]
 pub enum ValidationError<L, R> {
-    DecreasingBounds(L, R)
+    DecreasingBounds(L, R),
+
+    /// The bounds coincide, and the [Strict] policy rejects degenerate intervals.
+    DegenerateBounds(L, R),
+
+    /// The bounds are incomparable (e.g. NaN), and the [Strict] policy rejects them.
+    IncomparableBounds(L, R),
+}
+
+impl<L, R> ValidationError<L, R> {
+    /// Map the left and right bounds of the error through `f` and `g`.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::bounds::{validate, Closed, Open, OpenOrClosed, ValidationError};
+    /// let err: ValidationError<OpenOrClosed<f64>, OpenOrClosed<f64>> = validate(Closed(1.0), Open(0.0))
+    ///     .unwrap_err()
+    ///     .map(OpenOrClosed::from, OpenOrClosed::from);
+    ///
+    /// assert_eq!(err.into_values(), (Some(1.0), Some(0.0)));
+    /// ```
+    pub fn map<L2, R2, F, G>(self, f: F, g: G) -> ValidationError<L2, R2>
+    where
+        F: FnOnce(L) -> L2,
+        G: FnOnce(R) -> R2,
+    {
+        match self {
+            ValidationError::DecreasingBounds(l, r) => ValidationError::DecreasingBounds(f(l), g(r)),
+            ValidationError::DegenerateBounds(l, r) => ValidationError::DegenerateBounds(f(l), g(r)),
+            ValidationError::IncomparableBounds(l, r) => ValidationError::IncomparableBounds(f(l), g(r)),
+        }
+    }
+}
+
+impl<L, R> ValidationError<L, R>
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+{
+    /// Map the values of both bounds of the error through `f`.
+    pub fn map_values<F>(self, f: F) -> Self
+    where
+        F: Fn(L::Value) -> L::Value,
+    {
+        self.map(|l| l.map_value(&f), |r| r.map_value(&f))
+    }
+
+    /// Consumes the error, returning the values of the left and right bounds.
+    pub fn into_values(self) -> (Option<L::Value>, Option<L::Value>) {
+        match self {
+            ValidationError::DecreasingBounds(l, r)
+                | ValidationError::DegenerateBounds(l, r)
+                | ValidationError::IncomparableBounds(l, r) => (l.into_value(), r.into_value()),
+        }
+    }
 }
 
 pub type ValidationResult<L, R> = Result<(L, R), ValidationError<L, R>>;
 
+/// Error returned when converting a pair of [std::ops::Bound]s into an interval.
+#[derive(Debug, Clone, Copy)]
+pub enum FromStdBoundsError<L: Bound, R: Bound<Value = L::Value>> {
+    /// The bounds are not of the kinds held by the interval type (e.g. an
+    /// [Unbounded](std::ops::Bound::Unbounded) side where a value is needed);
+    /// they are handed back unchanged.
+    Mismatch(std::ops::Bound<L::Value>, std::ops::Bound<L::Value>),
+
+    /// The bounds are of the right kinds, but failed validation.
+    Invalid(ValidationError<L, R>),
+}
+
 pub trait ValidateBounds<L: Bound, R: Bound> {
     fn validate(left: L, right: R) -> ValidationResult<L, R>;
 }
 
 macro_rules! impl_val {
-    ($v:ident; NoBound, $r:ty) => {
-        impl<V: PartialOrd> ValidateBounds<NoBound<$v>, $r> for Validator {
-            fn validate(l: NoBound<$v>, r: $r) -> ValidationResult<NoBound<$v>, $r> { Ok((l, r)) }
+    ($v:ident; NoBound, NoBound) => {
+        impl<$v: PartialOrd, P: ValidationPolicy> ValidateBounds<NoBound<$v>, NoBound<$v>> for Validator<P> {
+            fn validate(l: NoBound<$v>, r: NoBound<$v>) -> ValidationResult<NoBound<$v>, NoBound<$v>> {
+                Ok((l, r))
+            }
         }
     };
-    ($v:ident; $l:ty, NoBound) => {
-        impl<V: PartialOrd> ValidateBounds<$l, NoBound<$v>> for Validator {
-            fn validate(l: $l, r: NoBound<$v>) -> ValidationResult<$l, NoBound<$v>> { Ok((l, r)) }
+    ($v:ident; NoBound, $r:ty) => {
+        impl<$v: PartialOrd, P: ValidationPolicy> ValidateBounds<NoBound<$v>, $r> for Validator<P> {
+            fn validate(l: NoBound<$v>, r: $r) -> ValidationResult<NoBound<$v>, $r> {
+                if P::validate_value(r.proper_value()) {
+                    Ok((l, r))
+                } else {
+                    Err(ValidationError::IncomparableBounds(l, r))
+                }
+            }
         }
     };
-    ($v:ident; $l:ty, $r:ty) => {
-        impl<$v: PartialOrd> ValidateBounds<$l, $r> for Validator {
-            fn validate(l: $l, r: $r) -> ValidationResult<$l, $r> {
-                if l.proper_value() > r.proper_value() {
-                    Err(ValidationError::DecreasingBounds(l, r))
-                } else {
+    ($v:ident; $l:ty, NoBound) => {
+        impl<$v: PartialOrd, P: ValidationPolicy> ValidateBounds<$l, NoBound<$v>> for Validator<P> {
+            fn validate(l: $l, r: NoBound<$v>) -> ValidationResult<$l, NoBound<$v>> {
+                if P::validate_value(l.proper_value()) {
                     Ok((l, r))
+                } else {
+                    Err(ValidationError::IncomparableBounds(l, r))
                 }
             }
         }
     };
-}
-
-impl<V: PartialOrd> ValidateBounds<NoBound<V>, NoBound<V>> for Validator {
-    fn validate(l: NoBound<V>, r: NoBound<V>) -> ValidationResult<NoBound<V>, NoBound<V>> {
-        Ok((l, r))
-    }
+    ($v:ident; $l:ty, $r:ty) => {
+        impl<$v: PartialOrd, P: ValidationPolicy> ValidateBounds<$l, $r> for Validator<P> {
+            fn validate(l: $l, r: $r) -> ValidationResult<$l, $r> { P::validate_pair(l, r) }
+        }
+    };
 }
 
 // Unbounded cases:
+impl_val!(V; NoBound, NoBound);
 impl_val!(V; NoBound, Open<V>);
 impl_val!(V; NoBound, Closed<V>);
 impl_val!(V; NoBound, OpenOrClosed<V>);
@@ -197,115 +561,59 @@ impl_val!(V; Closed<V>, Closed<V>);
 impl_val!(V; OpenOrClosed<V>, NoBound);
 
 // Mixed cases:
-impl<V: PartialOrd> ValidateBounds<Closed<V>, Open<V>> for Validator {
-    fn validate(l: Closed<V>, r: Open<V>) -> ValidationResult<Closed<V>, Open<V>> {
-        if l.0 >= r.0 {
-            Err(ValidationError::DecreasingBounds(l, r))
-        } else {
-            Ok((l, r))
-        }
-    }
-}
-
-impl<V: PartialOrd> ValidateBounds<Open<V>, Closed<V>> for Validator {
-    fn validate(l: Open<V>, r: Closed<V>) -> ValidationResult<Open<V>, Closed<V>> {
-        if l.0 >= r.0 {
-            Err(ValidationError::DecreasingBounds(l, r))
-        } else {
-            Ok((l, r))
-        }
-    }
-}
-
-impl<V: PartialOrd> ValidateBounds<OpenOrClosed<V>, Closed<V>> for Validator {
-    fn validate(l: OpenOrClosed<V>, r: Closed<V>) -> ValidationResult<OpenOrClosed<V>, Closed<V>> {
-        let is_invalid = match &l {
-            OpenOrClosed::Open(x) => x >= &r.0,
-            OpenOrClosed::Closed(x) => x > &r.0,
-        };
-
-        if is_invalid {
-            Err(ValidationError::DecreasingBounds(l, r))
-        } else {
-            Ok((l, r))
-        }
-    }
-}
-
-impl<V: PartialOrd> ValidateBounds<Closed<V>, OpenOrClosed<V>> for Validator {
-    fn validate(l: Closed<V>, r: OpenOrClosed<V>) -> ValidationResult<Closed<V>, OpenOrClosed<V>> {
-        let is_invalid = match &r {
-            OpenOrClosed::Open(x) => &l.0 >= x,
-            OpenOrClosed::Closed(x) => &l.0 > x,
-        };
-
-        if is_invalid {
-            Err(ValidationError::DecreasingBounds(l, r))
-        } else {
-            Ok((l, r))
-        }
-    }
-}
+impl_val!(V; Closed<V>, Open<V>);
+impl_val!(V; Open<V>, Closed<V>);
+impl_val!(V; OpenOrClosed<V>, Closed<V>);
+impl_val!(V; Closed<V>, OpenOrClosed<V>);
 
 // All-Open cases:
-impl<V: PartialOrd> ValidateBounds<Open<V>, Open<V>> for Validator {
-    fn validate(l: Open<V>, r: Open<V>) -> ValidationResult<Open<V>, Open<V>> {
-        if l.0 >= r.0 {
-            Err(ValidationError::DecreasingBounds(l, r))
-        } else {
-            Ok((l, r))
-        }
-    }
-}
+impl_val!(V; Open<V>, Open<V>);
+impl_val!(V; OpenOrClosed<V>, Open<V>);
+impl_val!(V; Open<V>, OpenOrClosed<V>);
+impl_val!(V; OpenOrClosed<V>, OpenOrClosed<V>);
 
-impl<V: PartialOrd> ValidateBounds<OpenOrClosed<V>, Open<V>> for Validator {
-    fn validate(l: OpenOrClosed<V>, r: Open<V>) -> ValidationResult<OpenOrClosed<V>, Open<V>> {
-        let is_invalid = match &l {
-            OpenOrClosed::Open(x) | OpenOrClosed::Closed(x) => x >= &r.0,
-        };
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        if is_invalid {
-            Err(ValidationError::DecreasingBounds(l, r))
-        } else {
-            Ok((l, r))
+    #[test]
+    fn test_cmp_bounds_unbounded() {
+        let u = NoBound::<f64>::new();
+        let (l, r) = (Side::Left, Side::Right);
+
+        assert_eq!(cmp_bounds(&u, l, &u, l), Some(Ordering::Equal));
+        assert_eq!(cmp_bounds(&u, r, &u, r), Some(Ordering::Equal));
+        assert_eq!(cmp_bounds(&u, l, &u, r), Some(Ordering::Less));
+        assert_eq!(cmp_bounds(&u, r, &u, l), Some(Ordering::Greater));
+
+        for &v in &[f64::MIN, 0.0, f64::INFINITY] {
+            for &side in &[l, r] {
+                assert_eq!(cmp_bounds(&u, l, &Closed(v), side), Some(Ordering::Less));
+                assert_eq!(cmp_bounds(&u, l, &Open(v), side), Some(Ordering::Less));
+                assert_eq!(cmp_bounds(&u, r, &Closed(v), side), Some(Ordering::Greater));
+                assert_eq!(cmp_bounds(&u, r, &Open(v), side), Some(Ordering::Greater));
+
+                assert_eq!(cmp_bounds(&Closed(v), side, &u, l), Some(Ordering::Greater));
+                assert_eq!(cmp_bounds(&OpenOrClosed::Open(v), side, &u, r), Some(Ordering::Less));
+            }
         }
-    }
-}
 
-impl<V: PartialOrd> ValidateBounds<Open<V>, OpenOrClosed<V>> for Validator {
-    fn validate(l: Open<V>, r: OpenOrClosed<V>) -> ValidationResult<Open<V>, OpenOrClosed<V>> {
-        let is_invalid = match &r {
-            OpenOrClosed::Open(x) | OpenOrClosed::Closed(x) => &l.0 >= x,
-        };
-
-        if is_invalid {
-            Err(ValidationError::DecreasingBounds(l, r))
-        } else {
-            Ok((l, r))
-        }
+        // Unbounded sides are comparable even when the other value is not.
+        assert_eq!(cmp_bounds(&u, l, &Closed(f64::NAN), r), Some(Ordering::Less));
+        assert_eq!(cmp_bounds(&Closed(f64::NAN), l, &Closed(0.0), r), None);
     }
-}
 
-impl<V: PartialOrd> ValidateBounds<OpenOrClosed<V>, OpenOrClosed<V>> for Validator {
-    fn validate(l: OpenOrClosed<V>, r: OpenOrClosed<V>) -> ValidationResult<OpenOrClosed<V>, OpenOrClosed<V>> {
-        let is_invalid = match (&l, &r) {
-            (&OpenOrClosed::Open(ref x), &OpenOrClosed::Open(ref y))
-                | (&OpenOrClosed::Closed(ref x), &OpenOrClosed::Open(ref y))
-                | (&OpenOrClosed::Open(ref x), &OpenOrClosed::Closed(ref y)) => x >= y,
-            (&OpenOrClosed::Closed(ref x), &OpenOrClosed::Closed(ref y)) => x > y,
-        };
-
-        if is_invalid {
-            Err(ValidationError::DecreasingBounds(l, r))
-        } else {
-            Ok((l, r))
-        }
+    #[test]
+    fn test_cmp_bounds_openness() {
+        let (l, r) = (Side::Left, Side::Right);
+
+        assert_eq!(cmp_bounds(&Closed(0), l, &Closed(0), r), Some(Ordering::Equal));
+        assert_eq!(cmp_bounds(&Open(0), l, &Closed(0), l), Some(Ordering::Greater));
+        assert_eq!(cmp_bounds(&Open(0), r, &Closed(0), r), Some(Ordering::Less));
+        assert_eq!(cmp_bounds(&Open(0), r, &Open(0), l), Some(Ordering::Less));
+        assert_eq!(cmp_bounds(&Open(0), l, &Open(0), l), Some(Ordering::Equal));
+        assert_eq!(cmp_bounds(&Open(0), l, &Closed(1), r), Some(Ordering::Less));
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
     #[test]
     fn test_validate_unbounded() {
@@ -390,4 +698,99 @@ mod tests {
         assert!(validate(OpenOrClosed::Open(0.0f64), OpenOrClosed::Closed(0.0f64)).is_err());
         assert!(validate(OpenOrClosed::Open(0.0f64), OpenOrClosed::Closed(1.0f64)).is_ok());
     }
+
+    #[test]
+    fn test_validation_error_map() {
+        let err = validate(Closed(1), Open(0)).unwrap_err();
+        let err: ValidationError<OpenOrClosed<i32>, OpenOrClosed<i32>> = err.map(From::from, From::from);
+
+        match err {
+            ValidationError::DecreasingBounds(l, r) => {
+                assert_eq!(l, OpenOrClosed::Closed(1));
+                assert_eq!(r, OpenOrClosed::Open(0));
+            },
+            err => panic!("Unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_validation_error_values() {
+        let err = validate(Open(2.0), Closed(1.0)).unwrap_err();
+
+        assert_eq!(err.map_values(|x| x * 10.0).into_values(), (Some(20.0), Some(10.0)));
+        assert_eq!(err.into_values(), (Some(2.0), Some(1.0)));
+    }
+
+    #[test]
+    fn test_allow_degenerate() {
+        type P = AllowDegenerate;
+
+        assert!(validate_with::<P, _, _>(Closed(0.0f64), Closed(0.0f64)).is_ok());
+        assert!(validate_with::<P, _, _>(Closed(0.0f64), Open(0.0f64)).is_err());
+        assert!(validate_with::<P, _, _>(Open(0.0f64), Open(0.0f64)).is_err());
+        assert!(validate_with::<P, _, _>(Closed(1.0f64), Closed(0.0f64)).is_err());
+        assert!(validate_with::<P, _, _>(Closed(f64::NAN), Closed(0.0f64)).is_ok());
+        assert!(validate_with::<P, _, _>(Open(f64::NAN), NoBound::new()).is_ok());
+    }
+
+    #[test]
+    fn test_allow_empty() {
+        type P = AllowEmpty;
+
+        assert!(validate_with::<P, _, _>(Closed(0.0f64), Closed(0.0f64)).is_ok());
+        assert!(validate_with::<P, _, _>(Closed(0.0f64), Open(0.0f64)).is_ok());
+        assert!(validate_with::<P, _, _>(Open(0.0f64), OpenOrClosed::Open(0.0f64)).is_ok());
+        assert!(validate_with::<P, _, _>(Open(0.0f64), Open(1.0f64)).is_ok());
+        assert!(validate_with::<P, _, _>(Open(1.0f64), Open(0.0f64)).is_err());
+        assert!(validate_with::<P, _, _>(Closed(1.0f64), Closed(0.0f64)).is_err());
+    }
+
+    #[test]
+    fn test_strict() {
+        type P = Strict;
+
+        assert!(validate_with::<P, _, _>(Closed(0.0f64), Closed(1.0f64)).is_ok());
+        assert!(validate_with::<P, _, _>(Open(0.0f64), Open(1.0f64)).is_ok());
+        assert!(validate_with::<P, _, _>(Closed(0.0f64), NoBound::new()).is_ok());
+        assert!(validate_with::<P, _, _>(NoBound::<f64>::new(), NoBound::new()).is_ok());
+
+        assert!(matches!(
+            validate_with::<P, _, _>(Closed(0.0f64), Closed(0.0f64)),
+            Err(ValidationError::DegenerateBounds(_, _))
+        ));
+        assert!(matches!(
+            validate_with::<P, _, _>(OpenOrClosed::Closed(0.0f64), OpenOrClosed::Closed(0.0f64)),
+            Err(ValidationError::DegenerateBounds(_, _))
+        ));
+        assert!(matches!(
+            validate_with::<P, _, _>(Closed(0.0f64), Open(0.0f64)),
+            Err(ValidationError::DecreasingBounds(_, _))
+        ));
+        assert!(matches!(
+            validate_with::<P, _, _>(Closed(1.0f64), Closed(0.0f64)),
+            Err(ValidationError::DecreasingBounds(_, _))
+        ));
+        assert!(matches!(
+            validate_with::<P, _, _>(Closed(f64::NAN), Closed(0.0f64)),
+            Err(ValidationError::IncomparableBounds(_, _))
+        ));
+        assert!(matches!(
+            validate_with::<P, _, _>(NoBound::new(), Open(f64::NAN)),
+            Err(ValidationError::IncomparableBounds(_, _))
+        ));
+        assert!(matches!(
+            validate_with::<P, _, _>(OpenOrClosed::Open(f64::NAN), NoBound::new()),
+            Err(ValidationError::IncomparableBounds(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_default_policy() {
+        let pairs = [(0.0, 1.0), (0.0, 0.0), (1.0, 0.0), (f64::NAN, 0.0)];
+
+        for &(l, r) in pairs.iter() {
+            assert_eq!(validate(Closed(l), Open(r)).is_ok(), validate_with::<AllowDegenerate, _, _>(Closed(l), Open(r)).is_ok());
+            assert_eq!(validate(Closed(l), Closed(r)).is_ok(), validate_with::<AllowDegenerate, _, _>(Closed(l), Closed(r)).is_ok());
+        }
+    }
 }
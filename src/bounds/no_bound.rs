@@ -1,16 +1,38 @@
 use super::*;
 
 /// Type representing the absence of a bound.
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// The phantom value type is stored as `fn() -> V` so that `NoBound<V>` is
+/// covariant in `V` and is always `Send` and `Sync`.
+///
+/// # Examples
+/// ```
+/// # use intervals::bounds::NoBound;
+/// const UNBOUNDED: NoBound<f64> = NoBound::new();
+///
+/// assert_eq!(UNBOUNDED, NoBound::default());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(
     feature = "serde",
     derive(Serialize, Deserialize),
     serde(crate = "serde_crate")
 )]
-pub struct NoBound<V>(pub std::marker::PhantomData<V>);
+pub struct NoBound<V>(std::marker::PhantomData<fn() -> V>);
 
 impl<V> NoBound<V> {
-    pub fn new() -> Self { NoBound(std::marker::PhantomData) }
+    /// Construct a new (absent) bound.
+    pub const fn new() -> Self { NoBound(std::marker::PhantomData) }
+}
+
+impl<V> Default for NoBound<V> {
+    fn default() -> Self { NoBound::new() }
+}
+
+// Implemented by hand, as all absent bounds are equal whether or not `V` can
+// be hashed.
+impl<V> std::hash::Hash for NoBound<V> {
+    fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
 }
 
 impl<V> crate::private::Sealed for NoBound<V> {}
@@ -19,24 +41,46 @@ impl<V> crate::private::Sealed for NoBound<V> {}
 impl<V: PartialOrd> Bound for NoBound<V> {
     type Value = V;
     type WithLimit = NoBound<V>;
+    type WithoutLimit = NoBound<V>;
+    type Ref<'a> = NoBound<&'a V> where V: 'a;
+    type Mapped<U: PartialOrd> = NoBound<U>;
 
     fn value(&self) -> Option<&Self::Value> { None }
 
+    fn into_value(self) -> Option<Self::Value> { None }
+
     fn is_open(&self) -> bool { false }
 
     fn is_closed(&self) -> bool { false }
 
     fn with_limit_point(self) -> Self::WithLimit { self }
+
+    fn without_limit_point(self) -> Self::WithoutLimit { self }
+
+    fn map_value<F: FnOnce(V) -> V>(self, _: F) -> Self { self }
+
+    fn map<U: PartialOrd, F: FnOnce(V) -> U>(self, _: F) -> NoBound<U> { NoBound::new() }
+
+    fn as_ref(&self) -> Self::Ref<'_> { NoBound::new() }
+}
+
+impl<V: PartialOrd> FromStdBound for NoBound<V> {
+    fn from_std_bound(bound: std::ops::Bound<V>) -> Result<Self, std::ops::Bound<V>> {
+        match bound {
+            std::ops::Bound::Unbounded => Ok(NoBound::new()),
+            bound => Err(bound),
+        }
+    }
 }
 
 // Formatting:
 impl<V: PartialOrd> BoundDisplay for NoBound<V> {
     fn fmt_left(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "(\u{221E}")
+        if f.alternate() { write!(f, "]-\u{221E}") } else { write!(f, "(-\u{221E}") }
     }
 
     fn fmt_right(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "\u{221E})")
+        if f.alternate() { write!(f, "\u{221E}[") } else { write!(f, "\u{221E})") }
     }
 }
 
@@ -119,6 +163,22 @@ mod tests {
         assert_eq!(a.with_limit_point(), a);
     }
 
+    #[test]
+    fn test_construction() {
+        const A: NoBound<f64> = NoBound::new();
+
+        assert_eq!(A, NoBound::new());
+        assert_eq!(A, NoBound::default());
+    }
+
+    #[test]
+    fn test_auto_traits() {
+        fn is_send_sync<T: Send + Sync>(_: T) {}
+
+        is_send_sync(NoBound::<std::rc::Rc<f64>>::new());
+        is_send_sync(NoBound::<std::cell::Cell<f64>>::new());
+    }
+
     #[test]
     fn test_pinch() {
         let a = NoBound::new();
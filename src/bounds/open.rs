@@ -1,7 +1,7 @@
 use super::*;
 
 /// Type representing an open bound.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(
     feature = "serde",
     derive(Serialize, Deserialize),
@@ -15,28 +15,54 @@ impl<V> crate::private::Sealed for Open<V> {}
 impl<V: PartialOrd> Bound for Open<V> {
     type Value = V;
     type WithLimit = Closed<V>;
+    type WithoutLimit = Open<V>;
+    type Ref<'a> = Open<&'a V> where V: 'a;
+    type Mapped<U: PartialOrd> = Open<U>;
 
     fn value(&self) -> Option<&Self::Value> { Some(&self.0) }
 
+    fn into_value(self) -> Option<Self::Value> { Some(self.0) }
+
     fn is_open(&self) -> bool { true }
 
     fn is_closed(&self) -> bool { false }
 
     fn with_limit_point(self) -> Self::WithLimit { Closed(self.0) }
+
+    fn without_limit_point(self) -> Self::WithoutLimit { self }
+
+    fn map_value<F: FnOnce(V) -> V>(self, f: F) -> Self { Open(f(self.0)) }
+
+    fn map<U: PartialOrd, F: FnOnce(V) -> U>(self, f: F) -> Open<U> { Open(f(self.0)) }
+
+    fn as_ref(&self) -> Self::Ref<'_> { Open(&self.0) }
 }
 
 impl<V: PartialOrd> ProperBound for Open<V> {
     fn proper_value(&self) -> &Self::Value { &self.0 }
 }
 
+impl<V: PartialOrd> FromStdBound for Open<V> {
+    fn from_std_bound(bound: std::ops::Bound<V>) -> Result<Self, std::ops::Bound<V>> {
+        match bound {
+            std::ops::Bound::Excluded(v) => Ok(Open(v)),
+            bound => Err(bound),
+        }
+    }
+}
+
 // Formatting:
 impl<V: PartialOrd + fmt::Display> BoundDisplay for Open<V> {
     fn fmt_left(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}", self.0)
+        f.write_str(if f.alternate() { "]" } else { "(" })?;
+
+        fmt::Display::fmt(&self.0, f)
     }
 
     fn fmt_right(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{})", self.0)
+        fmt::Display::fmt(&self.0, f)?;
+
+        f.write_str(if f.alternate() { "[" } else { ")" })
     }
 }
 
@@ -122,7 +148,7 @@ impl<V> std::cmp::PartialEq<NoBound<V>> for Open<V> {
 impl<V: PartialEq> std::cmp::PartialEq<OpenOrClosed<V>> for Open<V> {
     fn eq(&self, rhs: &OpenOrClosed<V>) -> bool {
         match rhs {
-            &OpenOrClosed::Open(ref inner) => self.0.eq(&inner),
+            OpenOrClosed::Open(inner) => self.0.eq(inner),
             _ => false,
         }
     }
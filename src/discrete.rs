@@ -0,0 +1,418 @@
+//! Module containing support for intervals over discrete value types.
+//!
+//! A type is discrete if every value has a well-defined successor and
+//! predecessor, as captured by the [DiscreteValue] trait. Intervals over such
+//! types have least and greatest elements, even when their bounds are open,
+//! and can be iterated over element by element.
+//!
+//! # Examples
+//! ```
+//! # use intervals::Interval;
+//! let x = Interval::lorc_unchecked('a', 'e');
+//!
+//! assert_eq!(x.min_element(), Some('b'));
+//! assert_eq!(x.iter().collect::<String>(), "bcde");
+//! ```
+use crate::{Interval, bounds::{Bound, ProperBound}};
+use num_traits::ToPrimitive;
+
+/// Trait for types in which every value has a well-defined successor and
+/// predecessor.
+///
+/// Implementations must be consistent with `PartialOrd`: the successor of `x`
+/// is the least value greater than `x`, and the predecessor is the greatest
+/// value less than `x`.
+pub trait DiscreteValue: PartialOrd + Sized {
+    /// Returns the successor of the value, if one exists.
+    fn next_value(&self) -> Option<Self>;
+
+    /// Returns the predecessor of the value, if one exists.
+    fn prev_value(&self) -> Option<Self>;
+
+    /// Returns the number of successor steps from the value to `other`.
+    ///
+    /// This is `None` if `other` is less than the value, or if the count is
+    /// unknown or does not fit in a `usize`; the default implementation always
+    /// returns `None`.
+    fn steps_between(&self, other: &Self) -> Option<usize> {
+        let _ = other;
+
+        None
+    }
+}
+
+macro_rules! impl_discrete_int {
+    ($($t:ty as $u:ty),+) => {
+        $(
+            impl DiscreteValue for $t {
+                fn next_value(&self) -> Option<$t> { self.checked_add(1) }
+
+                fn prev_value(&self) -> Option<$t> { self.checked_sub(1) }
+
+                fn steps_between(&self, other: &$t) -> Option<usize> {
+                    if other < self { return None; }
+
+                    // The difference always fits in the unsigned counterpart.
+                    let steps = (*other as $u).wrapping_sub(*self as $u);
+
+                    std::convert::TryFrom::try_from(steps).ok()
+                }
+            }
+        )+
+    };
+}
+
+impl_discrete_int!(
+    u8 as u8, u16 as u16, u32 as u32, u64 as u64, u128 as u128, usize as usize,
+    i8 as u8, i16 as u16, i32 as u32, i64 as u64, i128 as u128, isize as usize
+);
+
+/// The `char` successor skips the surrogate range `U+D800..=U+DFFF`.
+impl DiscreteValue for char {
+    fn next_value(&self) -> Option<char> {
+        match *self {
+            '\u{D7FF}' => Some('\u{E000}'),
+            c => std::char::from_u32(c as u32 + 1),
+        }
+    }
+
+    fn prev_value(&self) -> Option<char> {
+        match *self {
+            '\u{E000}' => Some('\u{D7FF}'),
+            c => (c as u32).checked_sub(1).and_then(std::char::from_u32),
+        }
+    }
+
+    fn steps_between(&self, other: &char) -> Option<usize> {
+        let (a, b) = (*self as u32, *other as u32);
+        let gap = if a < 0xD800 && b > 0xDFFF { 0x800 } else { 0 };
+
+        b.checked_sub(a).map(|steps| (steps - gap) as usize)
+    }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+
+    L::Value: DiscreteValue + Clone,
+{
+    /// Returns the least element of the interval, if one exists.
+    ///
+    /// This is `None` if the interval is empty or unbounded on the left.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::closed_unchecked(1, 3).min_element(), Some(1));
+    /// assert_eq!(Interval::open_unchecked(1, 3).min_element(), Some(2));
+    /// assert_eq!(Interval::open_unchecked(1, 2).min_element(), None);
+    /// assert_eq!(Interval::right_closed(3).min_element(), None);
+    /// ```
+    pub fn min_element(&self) -> Option<L::Value> {
+        let left = self.left.value()?;
+        let min = if self.left.is_open() { left.next_value()? } else { left.clone() };
+
+        if within_right(&self.right, &min) { Some(min) } else { None }
+    }
+
+    /// Returns the greatest element of the interval, if one exists.
+    ///
+    /// This is `None` if the interval is empty or unbounded on the right.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::closed_unchecked(1, 3).max_element(), Some(3));
+    /// assert_eq!(Interval::open_unchecked(1, 3).max_element(), Some(2));
+    /// assert_eq!(Interval::open_unchecked(1, 2).max_element(), None);
+    /// assert_eq!(Interval::left_closed(3).max_element(), None);
+    /// ```
+    pub fn max_element(&self) -> Option<L::Value> {
+        let right = self.right.value()?;
+        let max = if self.right.is_open() { right.prev_value()? } else { right.clone() };
+
+        if within_left(&self.left, &max) { Some(max) } else { None }
+    }
+
+    /// Returns the number of integers in the interval.
+    ///
+    /// Open bounds are excluded from the count, and an empty interval counts
+    /// zero. This is `None` if the interval is unbounded, or if the count does
+    /// not fit in a `u128`, e.g. for the full range of `u128` or `i128`.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::lorc_unchecked(0, 3).count_integers(), Some(3));
+    /// assert_eq!(Interval::open_unchecked(0, 3).count_integers(), Some(2));
+    /// assert_eq!(Interval::degenerate(0).count_integers(), Some(1));
+    /// assert_eq!(Interval::open_unchecked(0, 1).count_integers(), Some(0));
+    /// assert_eq!(Interval::left_closed(0).count_integers(), None);
+    /// assert_eq!(Interval::closed_unchecked(0, u128::MAX).count_integers(), None);
+    /// ```
+    pub fn count_integers(&self) -> Option<u128>
+    where
+        L::Value: ToPrimitive,
+    {
+        if self.left.value().is_none() || self.right.value().is_none() { return None; }
+
+        let (min, max) = match (self.min_element(), self.max_element()) {
+            (Some(min), Some(max)) if min <= max => (min, max),
+            _ => return Some(0),
+        };
+
+        let steps = match (min.to_u128(), max.to_u128()) {
+            (Some(min), Some(max)) => max - min,
+
+            // The difference always fits in a u128, even if not in an i128.
+            _ => (max.to_i128()? as u128).wrapping_sub(min.to_i128()? as u128),
+        };
+
+        steps.checked_add(1)
+    }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: ProperBound,
+    R: ProperBound<Value = L::Value>,
+
+    L::Value: DiscreteValue + Clone,
+{
+    /// Returns an iterator over the elements of the interval in ascending
+    /// order.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::lcro_unchecked(0u8, 4);
+    ///
+    /// assert_eq!(x.iter().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    /// assert_eq!(Interval::closed_unchecked(250u8, 255).iter().count(), 6);
+    /// ```
+    pub fn iter(&self) -> Iter<L::Value> {
+        let range = match (self.min_element(), self.max_element()) {
+            (Some(min), Some(max)) if min <= max => Some((min, max)),
+            _ => None,
+        };
+
+        Iter { range }
+    }
+
+    /// Returns an iterator over the elements of the interval in descending
+    /// order.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::lorc_unchecked(0, 4);
+    ///
+    /// assert_eq!(x.iter_rev().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    /// assert!(x.iter_rev().eq((1..=4).rev()));
+    /// ```
+    pub fn iter_rev(&self) -> std::iter::Rev<Iter<L::Value>> { self.iter().rev() }
+}
+
+fn within_left<B: Bound>(bound: &B, val: &B::Value) -> bool {
+    match bound.value() {
+        Some(l) if bound.is_open() => val > l,
+        Some(l) => val >= l,
+        None => true,
+    }
+}
+
+fn within_right<B: Bound>(bound: &B, val: &B::Value) -> bool {
+    match bound.value() {
+        Some(r) if bound.is_open() => val < r,
+        Some(r) => val <= r,
+        None => true,
+    }
+}
+
+/// Iterator over the elements of a bounded interval of discrete values.
+///
+/// See [Interval::iter].
+#[derive(Debug, Clone)]
+pub struct Iter<V> {
+    // Remaining elements, inclusive of both ends.
+    range: Option<(V, V)>,
+}
+
+impl<V: DiscreteValue> Iterator for Iter<V> {
+    type Item = V;
+
+    fn next(&mut self) -> Option<V> {
+        let (front, back) = self.range.take()?;
+
+        if front < back {
+            self.range = front.next_value().map(|next| (next, back));
+        }
+
+        Some(front)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.range {
+            None => (0, Some(0)),
+            Some((ref front, ref back)) => match front.steps_between(back).map(|n| n.checked_add(1)) {
+                Some(Some(n)) => (n, Some(n)),
+                Some(None) => (usize::MAX, None),
+                None => (1, None),
+            },
+        }
+    }
+}
+
+impl<V: DiscreteValue> DoubleEndedIterator for Iter<V> {
+    fn next_back(&mut self) -> Option<V> {
+        let (front, back) = self.range.take()?;
+
+        if front < back {
+            self.range = back.prev_value().map(|prev| (front, prev));
+        }
+
+        Some(back)
+    }
+}
+
+impl<V: DiscreteValue> std::iter::FusedIterator for Iter<V> {}
+
+// As for `RangeInclusive`, only types whose every interval has a length that
+// fits in a `usize` on every platform are exact-sized; wider types only
+// provide an exact `size_hint`.
+impl ExactSizeIterator for Iter<u8> {}
+impl ExactSizeIterator for Iter<u16> {}
+impl ExactSizeIterator for Iter<i8> {}
+impl ExactSizeIterator for Iter<i16> {}
+
+/// Iterates over the elements of the interval in ascending order, as with
+/// [Interval::iter].
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// let mut total = 0;
+///
+/// for i in Interval::closed_unchecked(3, 7) {
+///     total += i;
+/// }
+///
+/// assert_eq!(total, (3..=7).sum());
+/// ```
+impl<L, R> IntoIterator for Interval<L, R>
+where
+    L: ProperBound,
+    R: ProperBound<Value = L::Value>,
+
+    L::Value: DiscreteValue + Clone,
+{
+    type Item = L::Value;
+    type IntoIter = Iter<L::Value>;
+
+    fn into_iter(self) -> Iter<L::Value> { self.iter() }
+}
+
+impl<L, R> IntoIterator for &Interval<L, R>
+where
+    L: ProperBound,
+    R: ProperBound<Value = L::Value>,
+
+    L::Value: DiscreteValue + Clone,
+{
+    type Item = L::Value;
+    type IntoIter = Iter<L::Value>;
+
+    fn into_iter(self) -> Iter<L::Value> { self.iter() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_int() {
+        assert_eq!(0u8.prev_value(), None);
+        assert_eq!(255u8.next_value(), None);
+        assert_eq!(i64::MIN.next_value(), Some(i64::MIN + 1));
+        assert_eq!((-1i32).next_value(), Some(0));
+    }
+
+    #[test]
+    fn test_char() {
+        assert_eq!('a'.next_value(), Some('b'));
+        assert_eq!('\u{D7FF}'.next_value(), Some('\u{E000}'));
+        assert_eq!('\u{E000}'.prev_value(), Some('\u{D7FF}'));
+        assert_eq!(char::MAX.next_value(), None);
+        assert_eq!('\0'.prev_value(), None);
+    }
+
+    #[test]
+    fn test_char_surrogate_gap() {
+        let x = Interval::closed_unchecked('\u{D7FE}', '\u{E001}');
+
+        assert_eq!(
+            x.iter().collect::<Vec<_>>(),
+            vec!['\u{D7FE}', '\u{D7FF}', '\u{E000}', '\u{E001}']
+        );
+
+        let x = Interval::open_unchecked('\u{D7FF}', '\u{E000}');
+
+        assert_eq!(x.min_element(), None);
+        assert_eq!(x.iter().next(), None);
+    }
+
+    #[test]
+    fn test_iter_extremes() {
+        let x = Interval::closed_unchecked(i8::MIN, i8::MAX);
+
+        assert_eq!(x.iter().count(), 256);
+        assert_eq!(x.iter().fold(None, |_, v| Some(v)), Some(i8::MAX));
+
+        let mut it = Interval::closed_unchecked(u8::MAX, u8::MAX).iter();
+
+        assert_eq!(it.next(), Some(u8::MAX));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_steps_between() {
+        assert_eq!(3u8.steps_between(&7), Some(4));
+        assert_eq!(7u8.steps_between(&3), None);
+        assert_eq!((-5i8).steps_between(&5), Some(10));
+        assert_eq!(i8::MIN.steps_between(&i8::MAX), Some(255));
+        assert_eq!(i64::MIN.steps_between(&i64::MAX), Some(usize::MAX));
+        assert_eq!(i128::MIN.steps_between(&i128::MAX), None);
+        assert_eq!('a'.steps_between(&'e'), Some(4));
+        assert_eq!('\u{D7FF}'.steps_between(&'\u{E000}'), Some(1));
+    }
+
+    #[test]
+    fn test_iter_back_extremes() {
+        let mut it = Interval::closed_unchecked(u8::MIN, u8::MIN).iter();
+
+        assert_eq!(it.next_back(), Some(u8::MIN));
+        assert_eq!(it.next_back(), None);
+        assert_eq!(it.next(), None);
+
+        let x = Interval::closed_unchecked(i8::MIN, i8::MAX);
+
+        assert_eq!(x.iter_rev().count(), 256);
+        assert_eq!(x.iter_rev().fold(None, |_, v| Some(v)), Some(i8::MIN));
+    }
+
+    #[test]
+    fn test_iter_empty() {
+        assert_eq!(Interval::open_unchecked(0, 1).iter().next(), None);
+        assert_eq!(Interval::lcro_unchecked(0, 0).iter().next(), None);
+        assert_eq!(Interval::closed_unchecked(1, 0).iter().next(), None);
+    }
+}
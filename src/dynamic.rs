@@ -0,0 +1,225 @@
+//! Module containing a runtime representation of intervals of any shape.
+use crate::{Interval, bounds::Side};
+use std::{cmp::Ordering, fmt, ops::Bound as StdBound};
+
+/// Interval whose shape is only known at runtime.
+///
+/// Each variant holds the statically-typed interval of the corresponding
+/// shape, such that intervals of different shapes can be stored together.
+/// Degenerate intervals are held as [Closed](IntervalEnum::Closed).
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::{Interval, dynamic::IntervalEnum};
+/// let xs: Vec<IntervalEnum<f64>> = vec![
+///     Interval::closed_unchecked(0.0, 1.0).into(),
+///     Interval::open_unchecked(0.0, 1.0).into(),
+///     Interval::right_closed(2.0).into(),
+/// ];
+///
+/// assert_eq!(xs.iter().filter(|x| x.contains(0.0)).count(), 2);
+/// assert_eq!(xs[2].to_string(), "(-\u{221E}, 2]");
+///
+/// let x = xs[0].intersect(xs[1]).unwrap();
+///
+/// assert_eq!(x, IntervalEnum::Open(Interval::open_unchecked(0.0, 1.0)));
+/// assert_eq!(x.union_closure(xs[2]), IntervalEnum::RightClosed(Interval::right_closed(2.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum IntervalEnum<V: PartialOrd> {
+    /// The interval `(-∞, ∞)`.
+    Unbounded(crate::Unbounded<V>),
+
+    /// An interval `(a, b)`.
+    Open(crate::Open<V>),
+
+    /// An interval `(a, ∞)`.
+    LeftOpen(crate::LeftOpen<V>),
+
+    /// An interval `(-∞, b)`.
+    RightOpen(crate::RightOpen<V>),
+
+    /// An interval `[a, b]`.
+    Closed(crate::Closed<V>),
+
+    /// An interval `[a, ∞)`.
+    LeftClosed(crate::LeftClosed<V>),
+
+    /// An interval `(-∞, b]`.
+    RightClosed(crate::RightClosed<V>),
+
+    /// An interval `[a, b)`.
+    LCRO(crate::LCRO<V>),
+
+    /// An interval `(a, b]`.
+    LORC(crate::LORC<V>),
+}
+
+macro_rules! forward {
+    ($self:expr, |$x:ident| $code:expr) => {
+        match $self {
+            IntervalEnum::Unbounded($x) => $code,
+            IntervalEnum::Open($x) => $code,
+            IntervalEnum::LeftOpen($x) => $code,
+            IntervalEnum::RightOpen($x) => $code,
+            IntervalEnum::Closed($x) => $code,
+            IntervalEnum::LeftClosed($x) => $code,
+            IntervalEnum::RightClosed($x) => $code,
+            IntervalEnum::LCRO($x) => $code,
+            IntervalEnum::LORC($x) => $code,
+        }
+    };
+}
+
+macro_rules! impl_from {
+    ($($variant:ident),*) => {
+        $(
+            impl<V: PartialOrd> From<crate::$variant<V>> for IntervalEnum<V> {
+                fn from(x: crate::$variant<V>) -> Self { IntervalEnum::$variant(x) }
+            }
+        )*
+    };
+}
+
+impl_from!(Unbounded, Open, LeftOpen, RightOpen, Closed, LeftClosed, RightClosed, LCRO, LORC);
+
+impl<V: PartialOrd> IntervalEnum<V> {
+    // Construct the interval of the shape given by a pair of std bounds.
+    fn from_std_bounds_unchecked(left: StdBound<V>, right: StdBound<V>) -> Self {
+        use std::ops::Bound::*;
+
+        match (left, right) {
+            (Unbounded, Unbounded) => Interval::unbounded().into(),
+            (Excluded(a), Excluded(b)) => Interval::open_unchecked(a, b).into(),
+            (Excluded(a), Unbounded) => Interval::left_open(a).into(),
+            (Unbounded, Excluded(b)) => Interval::right_open(b).into(),
+            (Included(a), Included(b)) => Interval::closed_unchecked(a, b).into(),
+            (Included(a), Unbounded) => Interval::left_closed(a).into(),
+            (Unbounded, Included(b)) => Interval::right_closed(b).into(),
+            (Included(a), Excluded(b)) => Interval::lcro_unchecked(a, b).into(),
+            (Excluded(a), Included(b)) => Interval::lorc_unchecked(a, b).into(),
+        }
+    }
+
+    /// Returns the bounds of the interval as a pair of std bounds.
+    pub fn to_std_bounds(&self) -> (StdBound<&V>, StdBound<&V>) { forward!(self, |x| x.to_std_bounds()) }
+
+    /// Consumes the interval, returning its bounds as a pair of std bounds.
+    pub fn into_std_bounds(self) -> (StdBound<V>, StdBound<V>) { forward!(self, |x| x.into_std_bounds()) }
+
+    /// Returns true if `val` lies within the interval.
+    pub fn contains(&self, val: V) -> bool { forward!(self, |x| x.contains(val)) }
+
+    /// Returns the intersection of the two intervals, or `None` if it is
+    /// empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, dynamic::IntervalEnum};
+    /// let x = IntervalEnum::from(Interval::left_closed(0));
+    ///
+    /// assert_eq!(x.intersect(Interval::right_open(5).into()), Some(Interval::lcro_unchecked(0, 5).into()));
+    /// assert_eq!(x.intersect(Interval::right_open(0).into()), None);
+    /// ```
+    pub fn intersect(self, other: IntervalEnum<V>) -> Option<IntervalEnum<V>> {
+        let ((l1, r1), (l2, r2)) = (self.into_std_bounds(), other.into_std_bounds());
+        let (left, right) = (tighter(l1, l2, Side::Left), tighter(r1, r2, Side::Right));
+
+        if is_empty_between(&left, &right) { return None; }
+
+        Some(IntervalEnum::from_std_bounds_unchecked(left, right))
+    }
+
+    /// Returns the smallest closed interval containing both intervals.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, dynamic::IntervalEnum};
+    /// let x = IntervalEnum::from(Interval::open_unchecked(0, 1));
+    ///
+    /// assert_eq!(x.union_closure(Interval::lcro_unchecked(2, 3).into()), Interval::closed_unchecked(0, 3).into());
+    /// assert_eq!(x.union_closure(Interval::left_open(2).into()), Interval::left_closed(0).into());
+    /// ```
+    pub fn union_closure(self, other: IntervalEnum<V>) -> IntervalEnum<V> {
+        let ((l1, r1), (l2, r2)) = (self.into_std_bounds(), other.into_std_bounds());
+        let (left, right) = (looser(l1, l2, Side::Left), looser(r1, r2, Side::Right));
+
+        IntervalEnum::from_std_bounds_unchecked(close(left), close(right))
+    }
+}
+
+impl<V: PartialOrd + fmt::Display> fmt::Display for IntervalEnum<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { forward!(self, |x| fmt::Display::fmt(x, f)) }
+}
+
+// Returns the value of a bound that is known to be included or excluded.
+fn proper_value<V>(bound: &StdBound<V>) -> &V {
+    match bound {
+        StdBound::Included(v) | StdBound::Excluded(v) => v,
+        StdBound::Unbounded => unreachable!("unbounded sides are handled by the caller"),
+    }
+}
+
+// Returns the bound on the given side that excludes more values. Ties in value
+// favour the excluded bound.
+fn tighter<V: PartialOrd>(a: StdBound<V>, b: StdBound<V>, side: Side) -> StdBound<V> {
+    let inward = match side {
+        Side::Left => Ordering::Greater,
+        Side::Right => Ordering::Less,
+    };
+
+    match (a, b) {
+        (StdBound::Unbounded, x) | (x, StdBound::Unbounded) => x,
+        (a, b) => match proper_value(&a).partial_cmp(proper_value(&b)) {
+            Some(ord) if ord == inward => a,
+            Some(ord) if ord == inward.reverse() => b,
+            _ if matches!(b, StdBound::Excluded(_)) => b,
+            _ => a,
+        },
+    }
+}
+
+// Returns the bound on the given side that includes more values. Ties in value
+// favour the included bound.
+fn looser<V: PartialOrd>(a: StdBound<V>, b: StdBound<V>, side: Side) -> StdBound<V> {
+    let outward = match side {
+        Side::Left => Ordering::Less,
+        Side::Right => Ordering::Greater,
+    };
+
+    match (a, b) {
+        (StdBound::Unbounded, _) | (_, StdBound::Unbounded) => StdBound::Unbounded,
+        (a, b) => match proper_value(&a).partial_cmp(proper_value(&b)) {
+            Some(ord) if ord == outward => a,
+            Some(ord) if ord == outward.reverse() => b,
+            _ if matches!(b, StdBound::Included(_)) => b,
+            _ => a,
+        },
+    }
+}
+
+fn close<V>(bound: StdBound<V>) -> StdBound<V> {
+    match bound {
+        StdBound::Excluded(v) => StdBound::Included(v),
+        bound => bound,
+    }
+}
+
+// Returns true if no value lies between the left and right bounds.
+fn is_empty_between<V: PartialOrd>(left: &StdBound<V>, right: &StdBound<V>) -> bool {
+    match (left, right) {
+        (StdBound::Unbounded, _) | (_, StdBound::Unbounded) => false,
+        (StdBound::Included(a), StdBound::Included(b)) => !matches!(
+            a.partial_cmp(b), Some(Ordering::Less) | Some(Ordering::Equal)
+        ),
+        _ => proper_value(left).partial_cmp(proper_value(right)) != Some(Ordering::Less),
+    }
+}
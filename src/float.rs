@@ -0,0 +1,68 @@
+//! Module containing support for intervals over floating-point types.
+use crate::{Interval, bounds::Bound};
+
+/// Trait for floating-point types with a monotone mapping onto the integers.
+///
+/// The mapping sends each representable, non-NaN value to its position
+/// relative to zero in the total order of such values, so consecutive floats
+/// map to consecutive integers. Both zeros map to `0`.
+pub trait FloatOrdinal: PartialOrd + Copy + crate::private::Sealed {
+    /// Returns the ordinal of the value, or `None` if it is NaN.
+    fn ordinal(self) -> Option<i64>;
+}
+
+macro_rules! impl_float_ordinal {
+    ($t:ty, $bits:ty) => {
+        impl crate::private::Sealed for $t {}
+
+        impl FloatOrdinal for $t {
+            fn ordinal(self) -> Option<i64> {
+                if self.is_nan() { return None; }
+
+                let bits = self.to_bits();
+                let magnitude = (bits & !(1 << (<$bits>::BITS - 1))) as i64;
+
+                Some(if self.is_sign_negative() { -magnitude } else { magnitude })
+            }
+        }
+    };
+}
+
+impl_float_ordinal!(f32, u32);
+impl_float_ordinal!(f64, u64);
+
+impl<L, R> Interval<L, R>
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+
+    L::Value: FloatOrdinal,
+{
+    /// Returns the number of representable values in the interval.
+    ///
+    /// Positive and negative zero are counted as a single value, and the
+    /// count is zero for empty intervals. This is `None` if either side is
+    /// unbounded or has a NaN value.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(1.0f64, 1.0 + 4.0 * f64::EPSILON);
+    ///
+    /// assert_eq!(x.count_ulps(), Some(5));
+    /// assert_eq!(Interval::open_unchecked(1.0f64, 1.0 + 4.0 * f64::EPSILON).count_ulps(), Some(3));
+    /// assert_eq!(Interval::degenerate(1.0f32).count_ulps(), Some(1));
+    /// assert_eq!(Interval::left_closed(1.0f64).count_ulps(), None);
+    /// ```
+    pub fn count_ulps(&self) -> Option<u64> {
+        let left = self.left.value()?.ordinal()? as i128;
+        let right = self.right.value()?.ordinal()? as i128;
+
+        let count = right - left + 1
+            - self.left.is_open() as i128
+            - self.right.is_open() as i128;
+
+        Some(count.max(0) as u64)
+    }
+}
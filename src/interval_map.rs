@@ -0,0 +1,228 @@
+//! Module containing a map keyed by disjoint intervals.
+use crate::{Interval, bounds::{Bound, OpenOrClosed, ProperBound}, ops};
+
+/// Type alias for the keys of an [IntervalMap].
+pub type Key<V> = Interval<OpenOrClosed<V>, OpenOrClosed<V>>;
+
+/// Map from pairwise disjoint, bounded intervals to values.
+///
+/// Entries are kept sorted by key, such that point lookups and overlap
+/// queries are binary searches (see [ops::find_containing] and
+/// [ops::find_overlapping]).
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::{Interval, interval_map::IntervalMap};
+/// let mut rates = IntervalMap::new();
+///
+/// rates.insert(Interval::lcro_unchecked(0, 10), 0.1).unwrap();
+/// rates.insert(Interval::lcro_unchecked(10, 50), 0.2).unwrap();
+///
+/// assert_eq!(rates.get(&10), Some(&0.2));
+/// assert_eq!(rates.get(&50), None);
+/// assert!(rates.insert(Interval::closed_unchecked(40, 60), 0.3).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", try_from = "RawIntervalMap<V, T>")
+)]
+pub struct IntervalMap<V: PartialOrd, T> {
+    keys: Vec<Key<V>>,
+    values: Vec<T>,
+}
+
+// Unvalidated form of an IntervalMap, as deserialized.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(crate = "serde_crate", rename = "IntervalMap")]
+struct RawIntervalMap<V: PartialOrd, T> {
+    keys: Vec<Key<V>>,
+    values: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<V: PartialOrd, T> std::convert::TryFrom<RawIntervalMap<V, T>> for IntervalMap<V, T> {
+    type Error = &'static str;
+
+    fn try_from(raw: RawIntervalMap<V, T>) -> Result<Self, &'static str> {
+        if raw.keys.len() != raw.values.len() {
+            return Err("the numbers of keys and values differ");
+        }
+
+        let keys = raw.keys.into_iter()
+            .map(|key| Interval::new(key.left, key.right))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| "a key is empty")?;
+
+        if !ops::is_sorted_disjoint(&keys) { return Err("the keys are not sorted and disjoint"); }
+
+        Ok(IntervalMap { keys, values: raw.values, })
+    }
+}
+
+impl<V: PartialOrd, T> IntervalMap<V, T> {
+    /// Construct an empty map.
+    pub fn new() -> Self { IntervalMap { keys: Vec::new(), values: Vec::new(), } }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize { self.keys.len() }
+
+    /// Returns true if the map has no entries.
+    pub fn is_empty(&self) -> bool { self.keys.is_empty() }
+
+    /// Returns the keys of the map, in order.
+    pub fn keys(&self) -> &[Key<V>] { &self.keys }
+
+    /// Returns an iterator over the entries of the map, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Key<V>, &T)> {
+        self.keys.iter().zip(self.values.iter())
+    }
+
+    /// Insert an entry into the map, returning its index.
+    ///
+    /// The entry is handed back if the key is empty or overlaps the key of an
+    /// existing entry.
+    pub fn insert<L, R>(&mut self, key: Interval<L, R>, value: T) -> Result<usize, (Key<V>, T)>
+    where
+        L: ProperBound<Value = V> + Into<OpenOrClosed<V>>,
+        R: ProperBound<Value = V> + Into<OpenOrClosed<V>>,
+    {
+        let key: Key<V> = Interval::new_unchecked(key.left.into(), key.right.into());
+
+        // The error only hands back the values, so note the openness of each
+        // side to rebuild the rejected key.
+        let (left_open, right_open) = (key.left.is_open(), key.right.is_open());
+        let reopen = |x: V, open: bool| if open { OpenOrClosed::Open(x) } else { OpenOrClosed::Closed(x) };
+
+        let key = match Interval::new(key.left, key.right) {
+            Ok(key) => key,
+            Err(e) => match e.into_values() {
+                (Some(l), Some(r)) => {
+                    let key = Interval::new_unchecked(reopen(l, left_open), reopen(r, right_open));
+
+                    return Err((key, value));
+                },
+                _ => unreachable!("proper bounds always hold a value"),
+            },
+        };
+
+        let range = ops::find_overlapping(&self.keys, &key);
+
+        if !range.is_empty() { return Err((key, value)); }
+
+        self.keys.insert(range.start, key);
+        self.values.insert(range.start, value);
+
+        Ok(range.start)
+    }
+
+    /// Returns the index of the entry whose key contains `x`, if any.
+    pub fn index_of(&self, x: &V) -> Option<usize> { ops::find_containing(&self.keys, x) }
+
+    /// Returns the value of the entry whose key contains `x`, if any.
+    pub fn get(&self, x: &V) -> Option<&T> { self.index_of(x).map(|k| &self.values[k]) }
+
+    /// Returns the value of the entry whose key contains `x` mutably, if any.
+    pub fn get_mut(&mut self, x: &V) -> Option<&mut T> {
+        self.index_of(x).map(move |k| &mut self.values[k])
+    }
+
+    /// Returns the entry whose key contains `x`, if any.
+    pub fn get_key_value(&self, x: &V) -> Option<(&Key<V>, &T)> {
+        self.index_of(x).map(|k| (&self.keys[k], &self.values[k]))
+    }
+
+    /// Returns an iterator over the entries whose keys overlap `query`.
+    pub fn range_overlapping<L, R>(&self, query: &Interval<L, R>) -> impl Iterator<Item = (&Key<V>, &T)>
+    where
+        L: crate::bounds::Bound<Value = V>,
+        R: crate::bounds::Bound<Value = V>,
+    {
+        let range = ops::find_overlapping(&self.keys, query);
+
+        self.keys[range.clone()].iter().zip(self.values[range].iter())
+    }
+
+    /// Returns an iterator over the entries whose keys overlap `query`, with
+    /// mutable access to their values.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, interval_map::IntervalMap};
+    /// let mut stock = IntervalMap::new();
+    ///
+    /// stock.insert(Interval::lcro_unchecked(0, 10), 5).unwrap();
+    /// stock.insert(Interval::lcro_unchecked(10, 20), 5).unwrap();
+    /// stock.insert(Interval::lcro_unchecked(20, 30), 5).unwrap();
+    ///
+    /// for (_, n) in stock.range_overlapping_mut(&Interval::closed_unchecked(5, 10)) {
+    ///     *n -= 1;
+    /// }
+    ///
+    /// assert_eq!(stock.iter().map(|(_, &n)| n).collect::<Vec<_>>(), vec![4, 4, 5]);
+    /// ```
+    pub fn range_overlapping_mut<L, R>(
+        &mut self,
+        query: &Interval<L, R>,
+    ) -> impl Iterator<Item = (&Key<V>, &mut T)>
+    where
+        L: crate::bounds::Bound<Value = V>,
+        R: crate::bounds::Bound<Value = V>,
+    {
+        let range = ops::find_overlapping(&self.keys, query);
+
+        self.keys[range.clone()].iter().zip(self.values[range].iter_mut())
+    }
+}
+
+impl<V: PartialOrd + Clone, T: Clone> IntervalMap<V, T> {
+    /// Split the entry whose key contains `x` at `x`, returning the index of
+    /// the entry that then starts at `x`.
+    ///
+    /// The key is cut into a piece that is open at `x` and a piece that is
+    /// closed at `x`, each holding a clone of the value; the outer bounds keep
+    /// their openness, so the map remains disjoint. If the entry already
+    /// starts at `x` (with a closed bound) then the map is left unchanged, and
+    /// if no entry contains `x` then `None` is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, interval_map::IntervalMap};
+    /// let mut free = IntervalMap::new();
+    ///
+    /// free.insert(Interval::closed_unchecked(9, 17), "free").unwrap();
+    ///
+    /// let k = free.split_entry_at(12).unwrap();
+    /// free.split_entry_at(13);
+    ///
+    /// *free.get_mut(&12).unwrap() = "booked";
+    ///
+    /// assert_eq!(k, 1);
+    /// assert_eq!(free.keys()[0], Interval::lcro_unchecked(9, 12));
+    /// assert_eq!(free.keys()[1], Interval::lcro_unchecked(12, 13));
+    /// assert_eq!(free.get(&12), Some(&"booked"));
+    /// assert_eq!(free.get(&13), Some(&"free"));
+    /// ```
+    pub fn split_entry_at(&mut self, x: V) -> Option<usize> {
+        let k = self.index_of(&x)?;
+
+        if self.keys[k].left == OpenOrClosed::Closed(x.clone()) { return Some(k); }
+
+        let right = std::mem::replace(&mut self.keys[k].right, OpenOrClosed::Open(x.clone()));
+        let value = self.values[k].clone();
+
+        self.keys.insert(k + 1, Interval::new_unchecked(OpenOrClosed::Closed(x), right));
+        self.values.insert(k + 1, value);
+
+        Some(k + 1)
+    }
+}
+
+impl<V: PartialOrd, T> Default for IntervalMap<V, T> {
+    fn default() -> Self { IntervalMap::new() }
+}
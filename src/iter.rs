@@ -0,0 +1,220 @@
+//! Module containing iterator adaptors for intervals.
+use crate::{Interval, Position, bounds::{Bound, ProperBound}};
+use num_traits::{Bounded, Num, NumCast, Zero};
+
+/// Extension trait for filtering iterators by interval containment.
+///
+/// Containment is tested by reference via [Interval::classify], so this
+/// works for every combination of bound types, including the mixed
+/// [OpenOrClosed](crate::bounds::OpenOrClosed) bounds.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::{Interval, iter::IntervalFilterExt};
+/// let timestamps = vec![1_000u64, 1_250, 1_500, 1_750, 2_000];
+/// let window = Interval::lcro_unchecked(1_200, 1_800);
+///
+/// let inside: Vec<u64> = timestamps.into_iter().filter_interval(&window).collect();
+///
+/// assert_eq!(inside, vec![1_250, 1_500, 1_750]);
+/// ```
+pub trait IntervalFilterExt: Iterator + Sized {
+    /// Returns an iterator over the items contained in `interval`.
+    fn filter_interval<L, R>(self, interval: &Interval<L, R>) -> FilterInterval<'_, Self, L, R>
+    where
+        L: Bound<Value = Self::Item>,
+        R: Bound<Value = Self::Item>,
+    {
+        FilterInterval { iter: self, interval, }
+    }
+
+    /// Splits the items into those inside and those outside of `interval`.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, iter::IntervalFilterExt};
+    /// let (inside, outside): (Vec<_>, Vec<_>) = vec![-1.0, 0.0, 0.5, 1.0, f64::NAN]
+    ///     .into_iter()
+    ///     .partition_interval(&Interval::lorc_unchecked(0.0, 1.0));
+    ///
+    /// assert_eq!(inside, vec![0.5, 1.0]);
+    /// assert_eq!(outside.len(), 3);
+    /// ```
+    fn partition_interval<B, L, R>(self, interval: &Interval<L, R>) -> (B, B)
+    where
+        B: Default + Extend<Self::Item>,
+        L: Bound<Value = Self::Item>,
+        R: Bound<Value = Self::Item>,
+    {
+        let mut inside = B::default();
+        let mut outside = B::default();
+
+        for x in self {
+            if interval.classify(&x) == Position::Inside {
+                inside.extend(Some(x));
+            } else {
+                outside.extend(Some(x));
+            }
+        }
+
+        (inside, outside)
+    }
+}
+
+impl<I: Iterator> IntervalFilterExt for I {}
+
+/// Iterator adaptor yielding only the items contained in an interval.
+///
+/// See [IntervalFilterExt::filter_interval].
+#[derive(Debug, Clone)]
+pub struct FilterInterval<'a, I, L, R>
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+{
+    iter: I,
+    interval: &'a Interval<L, R>,
+}
+
+impl<'a, I, L, R> Iterator for FilterInterval<'a, I, L, R>
+where
+    I: Iterator,
+    L: Bound<Value = I::Item>,
+    R: Bound<Value = I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let interval = self.interval;
+
+        self.iter.find(|x| interval.classify(x) == Position::Inside)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { (0, self.iter.size_hint().1) }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: ProperBound + Clone,
+    R: Bound<Value = L::Value> + Clone,
+
+    L::Value: Num + NumCast + Bounded + Clone,
+{
+    /// Returns an iterator over the points `left + k * step`, for `k = 0, 1,
+    /// 2, ...`, that lie in the interval.
+    ///
+    /// Each point is computed directly from `k` where `k * step` is
+    /// representable, rather than by repeated addition, so floating-point
+    /// error does not accumulate. The left
+    /// endpoint is skipped if it is open, and iteration stops at the first
+    /// point beyond the right bound, or that would overflow the value type;
+    /// the iterator is endless if the interval is unbounded on the right and
+    /// the values never overflow (e.g. floats). A `step` that is not positive
+    /// (including NaN) yields an empty iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(0.0, 1.0);
+    ///
+    /// assert_eq!(x.iter_step(0.25).collect::<Vec<_>>(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// assert_eq!(Interval::open_unchecked(0, 10).iter_step(5).collect::<Vec<_>>(), vec![5]);
+    /// assert_eq!(x.iter_step(-0.25).next(), None);
+    /// ```
+    pub fn iter_step(&self, step: L::Value) -> Steps<L, R> {
+        let k = match step.partial_cmp(&L::Value::zero()) {
+            Some(std::cmp::Ordering::Greater) => Some(0),
+            _ => None,
+        };
+
+        Steps { interval: self.clone(), step, k, last: None, }
+    }
+}
+
+/// Iterator over evenly spaced points of an interval.
+///
+/// See [Interval::iter_step].
+#[derive(Debug, Clone)]
+pub struct Steps<L, R>
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+{
+    interval: Interval<L, R>,
+    step: L::Value,
+
+    // Index of the next point, or `None` once exhausted.
+    k: Option<usize>,
+
+    // The previously computed point, if any.
+    last: Option<L::Value>,
+}
+
+impl<L, R> Steps<L, R>
+where
+    L: ProperBound,
+    R: Bound<Value = L::Value>,
+
+    L::Value: Num + NumCast + Bounded + Clone,
+{
+    // Returns `left + k * step`, or `None` if it would overflow.
+    //
+    // When `k * step` alone would overflow (e.g. from a negative `left`), the
+    // point is instead reached by adding `step` to the last one.
+    fn point(&self, k: usize) -> Option<L::Value> {
+        let max = L::Value::max_value();
+        let step = self.step.clone();
+
+        let base = match <L::Value as NumCast>::from(k) {
+            Some(k) if k <= max.clone() / step.clone() => Some((self.interval.left.proper_value(), k * step)),
+            _ => self.last.as_ref().map(|last| (last, step)),
+        };
+
+        let (base, offset) = base?;
+
+        if *base > max - offset.clone() { return None; }
+
+        Some(base.clone() + offset)
+    }
+}
+
+impl<L, R> Iterator for Steps<L, R>
+where
+    L: ProperBound,
+    R: Bound<Value = L::Value>,
+
+    L::Value: Num + NumCast + Bounded + Clone,
+{
+    type Item = L::Value;
+
+    fn next(&mut self) -> Option<L::Value> {
+        loop {
+            let k = self.k?;
+            let x = self.point(k);
+
+            self.k = k.checked_add(1);
+            self.last = x.clone();
+
+            match x.as_ref().map(|x| self.interval.classify(x)) {
+                Some(Position::Inside) => return x,
+                Some(Position::Below) | Some(Position::OnLeftBoundary) => {},
+                _ => {
+                    self.k = None;
+
+                    return None;
+                },
+            }
+        }
+    }
+}
+
+impl<L, R> std::iter::FusedIterator for Steps<L, R>
+where
+    L: ProperBound,
+    R: Bound<Value = L::Value>,
+
+    L::Value: Num + NumCast + Bounded + Clone,
+{}
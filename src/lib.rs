@@ -3,20 +3,58 @@
 //! `intervals` is oriented towards static-typing. The bounds are all
 //! unique types, all operations between instances are exhaustively
 //! implemented, and formatting is provided for ease-of-use.
+//!
+//! Each of the std range types converts into the interval type of the same
+//! shape:
+//!
+//! | Range    | Interval        | Conversion |
+//! |----------|-----------------|------------|
+//! | `a..b`   | [LCRO]          | `TryFrom`  |
+//! | `a..=b`  | [Closed]        | `TryFrom`  |
+//! | `a..`    | [LeftClosed]    | `From`     |
+//! | `..b`    | [RightOpen]     | `From`     |
+//! | `..=b`   | [RightClosed]   | `From`     |
+//! | `..`     | [Unbounded]     | `From`     |
 extern crate num_traits;
 
 #[cfg_attr(feature = "serde", macro_use)]
 #[cfg(feature = "serde")]
 extern crate serde_crate;
 
-use num_traits::{Zero, One, Unsigned};
+#[cfg(feature = "chrono")]
+extern crate chrono;
+
+#[cfg(feature = "rand")]
+extern crate rand;
+
+#[cfg(feature = "quickcheck")]
+extern crate quickcheck;
+
+#[cfg(feature = "approx")]
+extern crate approx;
+
+use num_traits::{Zero, One, NumCast, ToPrimitive, Unsigned, CheckedSub};
 
 mod private {
     pub trait Sealed {}
 }
 
+#[cfg(feature = "approx")]
+mod approx_eq;
+#[cfg(feature = "quickcheck")]
+mod arbitrary;
+pub mod arithmetic;
 pub mod bounds;
+pub mod discrete;
+pub mod dynamic;
+pub mod float;
+pub mod interval_map;
+pub mod iter;
+pub mod ops;
 pub mod partitions;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod total_order;
 
 pub type Result<T, L, R> = std::result::Result<T, bounds::ValidationError<L, R>>;
 pub type IntervalResult<L, R = L> = Result<Interval<L, R>, L, R>;
@@ -35,6 +73,26 @@ pub type IntervalResult<L, R = L> = Result<Interval<L, R>, L, R>;
 ///
 /// assert_eq!(x.intersect(y).unwrap(), Interval::degenerate(0.0));
 /// ```
+///
+/// Intervals can also be compared with the std range type of the same shape:
+/// `a..b` for [LCRO], `a..=b` for [Closed], `a..` for [LeftClosed], `..b` for
+/// [RightOpen], `..=b` for [RightClosed] and `..` for [Unbounded].
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// assert_eq!(Interval::lcro_unchecked(0, 10), 0..10);
+/// assert_eq!(Interval::closed_unchecked(0.0, 1.0), 0.0..=1.0);
+/// assert_eq!(..=5, Interval::right_closed(5));
+///
+/// assert_ne!(Interval::lcro_unchecked(0, 10), 0..11);
+/// ```
+///
+/// Intervals whose openness differs from the range do not compare at all:
+/// ```compile_fail
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// assert_ne!(Interval::open_unchecked(0, 10), 0..10);
+/// ```
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(
     feature = "serde",
@@ -91,231 +149,1806 @@ where
     }
 }
 
-impl<L, R> Interval<L, R>
+/// Intervals are equivalent, in the sense of [Eq], whenever their bounds are.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// use std::collections::HashMap;
+///
+/// let mut counts = HashMap::new();
+///
+/// *counts.entry(Interval::closed_unchecked(0, 9)).or_insert(0) += 1;
+/// *counts.entry(Interval::closed_unchecked(0, 9)).or_insert(0) += 1;
+///
+/// assert_eq!(counts[&Interval::closed_unchecked(0, 9)], 2);
+/// ```
+impl<L, R> Eq for Interval<L, R>
 where
-    L: bounds::Bound,
-    R: bounds::Bound<Value = L::Value>,
+    L: bounds::Bound + Eq,
+    R: bounds::Bound<Value = L::Value> + Eq,
+{}
 
-    bounds::Validator: bounds::ValidateBounds<L, R>,
+/// Hashes the left bound, then the right bound.
+///
+/// The hash is consistent with equality between intervals of the same type
+/// only: e.g. `[0, 1]` as a [Closed] interval and as an
+/// `Interval<OpenOrClosed<_>, OpenOrClosed<_>>` compare equal, but need not
+/// hash equally.
+impl<L, R> std::hash::Hash for Interval<L, R>
+where
+    L: bounds::Bound + std::hash::Hash,
+    R: bounds::Bound<Value = L::Value> + std::hash::Hash,
 {
-    /// Construct an interval with bound validation.
-    pub fn new(left: L, right: R) -> IntervalResult<L, R> {
-        bounds::validate(left, right).map(|(left, right)| Interval { left, right, })
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.left.hash(state);
+        self.right.hash(state);
     }
 }
 
-impl<L, R> Interval<L, R>
+/// Orders intervals lexicographically: by left bound, then by right bound.
+///
+/// This is **not** the subset order; e.g. `[0, 5]` precedes `[1, 2]` even
+/// though neither contains the other. Bounds are positioned as in
+/// [bounds::cmp_bounds], so an unbounded left side comes first, an open left
+/// bound comes just after a closed one at the same value, and an open right
+/// bound just before a closed one. Intervals with incomparable values (e.g.
+/// NaN) are unordered.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// let mut xs = vec![
+///     Interval::closed_unchecked(2, 3),
+///     Interval::closed_unchecked(0, 5),
+///     Interval::closed_unchecked(0, 1),
+/// ];
+///
+/// xs.sort();
+///
+/// assert_eq!(xs, vec![
+///     Interval::closed_unchecked(0, 1),
+///     Interval::closed_unchecked(0, 5),
+///     Interval::closed_unchecked(2, 3),
+/// ]);
+/// assert!(Interval::lcro_unchecked(0.0, 1.0) < Interval::lcro_unchecked(0.5, 0.75));
+/// ```
+impl<L, R> PartialOrd for Interval<L, R>
 where
-    L: bounds::Bound,
-    R: bounds::Bound<Value = L::Value>,
+    L: bounds::Bound + PartialEq,
+    R: bounds::Bound<Value = L::Value> + PartialEq,
 {
-    /// Construct an interval w/o bound validation.
-    pub fn new_unchecked(left: L, right: R) -> Self { Interval { left, right, } }
-}
+    fn partial_cmp(&self, other: &Interval<L, R>) -> Option<std::cmp::Ordering> {
+        use bounds::{cmp_bounds, Side};
 
-impl<L: bounds::Bound> Interval<L, bounds::NoBound<L::Value>> {
-    /// Construct a left-bounded interval, unbounded on the right.
-    pub fn left_bounded(left: L) -> Self {
-        Interval {
-            left,
-            right: bounds::NoBound::new(),
+        match cmp_bounds(&self.left, Side::Left, &other.left, Side::Left)? {
+            std::cmp::Ordering::Equal => cmp_bounds(&self.right, Side::Right, &other.right, Side::Right),
+            ordering => Some(ordering),
         }
     }
 }
 
-impl<V: PartialOrd> LeftOpen<V> {
-    /// Construct a left-open interval, unbounded on the right.
-    pub fn left_open(left: V) -> Self {
-        Interval {
-            left: bounds::Open(left),
-            right: bounds::NoBound::new(),
-        }
+/// Totally orders intervals over totally ordered values; see the
+/// [PartialOrd] impl.
+impl<L, R> Ord for Interval<L, R>
+where
+    L: bounds::Bound + Eq,
+    R: bounds::Bound<Value = L::Value> + Eq,
+
+    L::Value: Ord,
+{
+    fn cmp(&self, other: &Interval<L, R>) -> std::cmp::Ordering {
+        // Bounds over totally ordered values are always comparable.
+        self.partial_cmp(other).unwrap()
     }
 }
 
-impl<V: PartialOrd> LeftClosed<V> {
-    /// Construct a left-closed interval, unbounded on the right.
-    pub fn left_closed(left: V) -> Self {
-        Interval {
-            left: bounds::Closed(left),
-            right: bounds::NoBound::new(),
+// Comparison with the std range types, each of which corresponds to exactly
+// one interval type: e.g. `a..b` is `[a, b)` and `a..=b` is `[a, b]`. Intervals
+// of any other type never compare equal to a range, so no impls are provided.
+macro_rules! impl_range_eq {
+    ($v:ident; $range:ty => $interval:ty; |$x:ident, $r:ident| $test:expr) => {
+        impl<$v: PartialOrd> PartialEq<$range> for $interval {
+            fn eq(&self, $r: &$range) -> bool { let $x = self; $test }
         }
-    }
-}
 
-impl<R: bounds::Bound> Interval<bounds::NoBound<R::Value>, R> {
-    /// Construct a right-bounded interval, unbounded on the left.
-    pub fn right_bounded(right: R) -> Self {
-        Interval {
-            left: bounds::NoBound::new(),
-            right,
+        impl<$v: PartialOrd> PartialEq<$interval> for $range {
+            fn eq(&self, rhs: &$interval) -> bool { rhs == self }
         }
-    }
+    };
 }
 
-impl<V: PartialOrd> RightOpen<V> {
-    /// Construct a right-open interval, unbounded on the left.
-    pub fn right_open(right: V) -> Self {
-        Interval {
-            left: bounds::NoBound::new(),
-            right: bounds::Open(right),
+impl_range_eq!(V; std::ops::Range<V> => LCRO<V>; |x, r| {
+    x.left.0 == r.start && x.right.0 == r.end
+});
+impl_range_eq!(V; std::ops::RangeInclusive<V> => Closed<V>; |x, r| {
+    x.left.0 == *r.start() && x.right.0 == *r.end()
+});
+impl_range_eq!(V; std::ops::RangeFrom<V> => LeftClosed<V>; |x, r| x.left.0 == r.start);
+impl_range_eq!(V; std::ops::RangeTo<V> => RightOpen<V>; |x, r| x.right.0 == r.end);
+impl_range_eq!(V; std::ops::RangeToInclusive<V> => RightClosed<V>; |x, r| x.right.0 == r.end);
+impl_range_eq!(V; std::ops::RangeFull => Unbounded<V>; |_x, _r| true);
+
+/// Converts `a..b` into `[a, b)`, failing if the range is empty.
+///
+/// Empty ranges such as `5..5` or `5..3` yield
+/// [ValidationError::DecreasingBounds](bounds::ValidationError), while
+/// incomparable endpoints (e.g. NaN) yield
+/// [ValidationError::IncomparableBounds](bounds::ValidationError).
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// use std::convert::TryFrom;
+///
+/// let x = Interval::try_from(0..10).unwrap();
+///
+/// assert!(x.contains(0));
+/// assert!(x.contains(9));
+/// assert!(!x.contains(10));
+/// assert_eq!(x, Interval::lcro_unchecked(0, 10));
+///
+/// assert!(Interval::try_from(5..5).is_err());
+/// ```
+impl<V: PartialOrd> std::convert::TryFrom<std::ops::Range<V>> for LCRO<V> {
+    type Error = bounds::ValidationError<bounds::Closed<V>, bounds::Open<V>>;
+
+    fn try_from(range: std::ops::Range<V>) -> std::result::Result<LCRO<V>, Self::Error> {
+        let (left, right) = (bounds::Closed(range.start), bounds::Open(range.end));
+
+        match left.0.partial_cmp(&right.0) {
+            None => Err(bounds::ValidationError::IncomparableBounds(left, right)),
+            Some(std::cmp::Ordering::Less) => Ok(Interval::new_unchecked(left, right)),
+            Some(_) => Err(bounds::ValidationError::DecreasingBounds(left, right)),
         }
     }
 }
 
-impl<V: PartialOrd> RightClosed<V> {
-    /// Construct a right-closed interval, unbounded on the left.
-    pub fn right_closed(right: V) -> Self {
-        Interval {
-            left: bounds::NoBound::new(),
-            right: bounds::Closed(right),
+/// Converts `a..=b` into `[a, b]`, failing if the range is empty.
+///
+/// Unlike `[a, b]`, which contains `a` whenever `a <= b`, an inclusive range
+/// may be empty after being exhausted by iteration. Empty ranges therefore
+/// yield [ValidationError::DecreasingBounds](bounds::ValidationError), as do
+/// decreasing ones, while incomparable endpoints (e.g. NaN) yield
+/// [ValidationError::IncomparableBounds](bounds::ValidationError).
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// use std::convert::TryFrom;
+///
+/// assert_eq!(Interval::try_from(0..=10).unwrap(), Interval::closed_unchecked(0, 10));
+/// assert!(Interval::try_from(10..=0).is_err());
+///
+/// let mut range = 0..=0;
+/// range.next();
+///
+/// assert!(Interval::try_from(range).is_err());
+/// ```
+impl<V: PartialOrd> std::convert::TryFrom<std::ops::RangeInclusive<V>> for Closed<V> {
+    type Error = bounds::ValidationError<bounds::Closed<V>, bounds::Closed<V>>;
+
+    fn try_from(range: std::ops::RangeInclusive<V>) -> std::result::Result<Closed<V>, Self::Error> {
+        let empty = range.is_empty();
+        let (start, end) = range.into_inner();
+        let comparable = start.partial_cmp(&end).is_some();
+        let (left, right) = (bounds::Closed(start), bounds::Closed(end));
+
+        if !comparable {
+            Err(bounds::ValidationError::IncomparableBounds(left, right))
+        } else if empty {
+            Err(bounds::ValidationError::DecreasingBounds(left, right))
+        } else {
+            Ok(Interval::new_unchecked(left, right))
         }
     }
 }
 
-impl<V: PartialOrd> LORC<V> {
-    /// Construct a left-open, right-closed interval with bound validation.
-    pub fn lorc(left: V, right: V) -> Result<Self, bounds::Open<V>, bounds::Closed<V>> {
-        Interval::new(bounds::Open(left), bounds::Closed(right))
-    }
+/// Converts `a..` into `[a, ∞)`.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// assert_eq!(Interval::from(5..), Interval::left_closed(5));
+/// ```
+impl<V: PartialOrd> From<std::ops::RangeFrom<V>> for LeftClosed<V> {
+    fn from(range: std::ops::RangeFrom<V>) -> LeftClosed<V> { Interval::left_closed(range.start) }
+}
 
-    /// Construct a left-open, right-closed interval w/o bound validation.
-    pub fn lorc_unchecked(left: V, right: V) -> Self {
-        Interval::new_unchecked(bounds::Open(left), bounds::Closed(right))
-    }
+/// Converts `..b` into `(-∞, b)`.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// assert_eq!(Interval::from(..5), Interval::right_open(5));
+/// ```
+impl<V: PartialOrd> From<std::ops::RangeTo<V>> for RightOpen<V> {
+    fn from(range: std::ops::RangeTo<V>) -> RightOpen<V> { Interval::right_open(range.end) }
 }
 
-impl<V: PartialOrd> LCRO<V> {
-    /// Construct a left-closed, right-open interval with bound validation.
-    pub fn lcro(left: V, right: V) -> Result<Self, bounds::Closed<V>, bounds::Open<V>> {
-        Interval::new(bounds::Closed(left), bounds::Open(right))
-    }
+/// Converts `..=b` into `(-∞, b]`.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// assert_eq!(Interval::from(..=5), Interval::right_closed(5));
+/// ```
+impl<V: PartialOrd> From<std::ops::RangeToInclusive<V>> for RightClosed<V> {
+    fn from(range: std::ops::RangeToInclusive<V>) -> RightClosed<V> { Interval::right_closed(range.end) }
+}
 
-    /// Construct a left-closed, right-open interval w/o bound validation.
-    pub fn lcro_unchecked(left: V, right: V) -> Self {
-        Interval::new_unchecked(bounds::Closed(left), bounds::Open(right))
-    }
+/// Converts `..` into `(-∞, ∞)`.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Unbounded;
+/// assert_eq!(Unbounded::<f64>::from(..), Unbounded::unbounded());
+/// ```
+impl<V: PartialOrd> From<std::ops::RangeFull> for Unbounded<V> {
+    fn from(_: std::ops::RangeFull) -> Unbounded<V> { Interval::unbounded() }
 }
 
-impl<V: PartialOrd> Unbounded<V> {
-    /// Construct a totally unbounded interval.
-    pub fn unbounded() -> Self {
-        Interval {
-            left: bounds::NoBound::new(),
-            right: bounds::NoBound::new(),
-        }
-    }
+macro_rules! impl_from_erased {
+    ($($(#[$attr:meta])* $from:ident => $l:ident, $r:ident;)*) => {
+        $(
+            $(#[$attr])*
+            impl<V: PartialOrd> From<$from<V>> for Interval<bounds::$l<V>, bounds::$r<V>> {
+                fn from(x: $from<V>) -> Self { Interval::new_unchecked(x.left.into(), x.right.into()) }
+            }
+        )*
+    };
 }
 
-impl<V: PartialOrd> Open<V> {
-    /// Construct a bounded open interval with bound validation.
-    pub fn open(left: V, right: V) -> IntervalResult<bounds::Open<V>, bounds::Open<V>> {
-        Interval::new(bounds::Open(left), bounds::Open(right))
-    }
+impl_from_erased! {
+    /// Erases the kinds of the bounds of an open interval.
+    ///
+    /// Together with the conversions from the other bounded shapes, this
+    /// allows intervals of mixed shapes to be stored together.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, bounds::OpenOrClosed};
+    /// let xs: Vec<Interval<OpenOrClosed<f64>, OpenOrClosed<f64>>> = vec![
+    ///     Interval::open_unchecked(0.0, 1.0).into(),
+    ///     Interval::closed_unchecked(0.0, 1.0).into(),
+    ///     Interval::lcro_unchecked(0.0, 1.0).into(),
+    /// ];
+    ///
+    /// assert_eq!(xs.iter().filter(|x| x.contains(0.0)).count(), 2);
+    /// assert_eq!(xs[2].to_string(), "[0, 1)");
+    /// ```
+    Open => OpenOrClosed, OpenOrClosed;
+    /// Erases the kinds of the bounds of a closed interval.
+    Closed => OpenOrClosed, OpenOrClosed;
+    /// Erases the kinds of the bounds of a left-closed, right-open interval.
+    LCRO => OpenOrClosed, OpenOrClosed;
+    /// Erases the kinds of the bounds of a left-open, right-closed interval.
+    LORC => OpenOrClosed, OpenOrClosed;
+    /// Erases the kind of the left bound of a left-open interval.
+    LeftOpen => OpenOrClosed, NoBound;
+    /// Erases the kind of the left bound of a left-closed interval.
+    LeftClosed => OpenOrClosed, NoBound;
+    /// Erases the kind of the right bound of a right-open interval.
+    RightOpen => NoBound, OpenOrClosed;
+    /// Erases the kind of the right bound of a right-closed interval.
+    RightClosed => NoBound, OpenOrClosed;
+}
 
-    /// Construct a bounded open interval w/o bound validation.
-    pub fn open_unchecked(left: V, right: V) -> Self {
-        Interval::new_unchecked(bounds::Open(left), bounds::Open(right))
+/// Converts a pair of std bounds into an interval, validating their order.
+///
+/// Each side converts into any bound type of the matching kind:
+/// [Included](std::ops::Bound::Included) into [Closed](bounds::Closed),
+/// [Excluded](std::ops::Bound::Excluded) into [Open](bounds::Open), either into
+/// [OpenOrClosed](bounds::OpenOrClosed), and
+/// [Unbounded](std::ops::Bound::Unbounded) into [NoBound](bounds::NoBound).
+/// Pairs of unknown kind thus target the erased forms, e.g.
+/// `Interval<OpenOrClosed<V>, NoBound<V>>` for `(Excluded(a), Unbounded)`.
+///
+/// Yields [FromStdBoundsError::Mismatch](bounds::FromStdBoundsError) if a
+/// side is of the wrong kind, and
+/// [FromStdBoundsError::Invalid](bounds::FromStdBoundsError) holding the
+/// [ValidationError](bounds::ValidationError) if the bounds are decreasing.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::{Interval, Unbounded, bounds::{NoBound, OpenOrClosed}};
+/// use std::convert::TryFrom;
+/// use std::ops::Bound::{Excluded, Included, Unbounded as Inf};
+///
+/// type Erased = Interval<OpenOrClosed<i32>, OpenOrClosed<i32>>;
+///
+/// let x = Erased::try_from((Included(0), Excluded(5))).unwrap();
+/// assert_eq!(x, Interval::lcro_unchecked(0, 5));
+///
+/// let x = Interval::<NoBound<i32>, OpenOrClosed<i32>>::try_from((Inf, Excluded(5))).unwrap();
+/// assert_eq!(x, Interval::right_open(5));
+///
+/// assert!(Unbounded::<i32>::try_from((Inf, Inf)).is_ok());
+/// assert!(Erased::try_from((Inf, Excluded(5))).is_err());
+/// assert!(Erased::try_from((Included(5), Included(0))).is_err());
+/// ```
+impl<L, R> std::convert::TryFrom<(std::ops::Bound<L::Value>, std::ops::Bound<L::Value>)> for Interval<L, R>
+where
+    L: bounds::FromStdBound,
+    R: bounds::FromStdBound<Value = L::Value>,
+    bounds::Validator: bounds::ValidateBounds<L, R>,
+{
+    type Error = bounds::FromStdBoundsError<L, R>;
+
+    fn try_from(
+        (start, end): (std::ops::Bound<L::Value>, std::ops::Bound<L::Value>),
+    ) -> std::result::Result<Interval<L, R>, Self::Error> {
+        match (L::from_std_bound(start), R::from_std_bound(end)) {
+            (Ok(left), Ok(right)) => Interval::new(left, right).map_err(bounds::FromStdBoundsError::Invalid),
+            (Ok(left), Err(end)) => Err(bounds::FromStdBoundsError::Mismatch(to_std_bound(left), end)),
+            (Err(start), Ok(right)) => Err(bounds::FromStdBoundsError::Mismatch(start, to_std_bound(right))),
+            (Err(start), Err(end)) => Err(bounds::FromStdBoundsError::Mismatch(start, end)),
+        }
     }
 }
 
-impl<V: PartialOrd> Closed<V> {
-    /// Construct a bounded closed interval with bound validation.
-    pub fn closed(left: V, right: V) -> IntervalResult<bounds::Closed<V>, bounds::Closed<V>> {
-        Interval::new(bounds::Closed(left), bounds::Closed(right))
-    }
+// Returns the owned std representation of a bound.
+fn to_std_bound<B: bounds::Bound>(bound: B) -> std::ops::Bound<B::Value> {
+    let open = bound.is_open();
 
-    /// Construct a bounded closed interval w/o bound validation.
-    pub fn closed_unchecked(left: V, right: V) -> Self {
-        Interval::new_unchecked(bounds::Closed(left), bounds::Closed(right))
+    match bound.into_value() {
+        None => std::ops::Bound::Unbounded,
+        Some(v) if open => std::ops::Bound::Excluded(v),
+        Some(v) => std::ops::Bound::Included(v),
     }
 }
 
-impl<V: PartialOrd + Clone> Closed<V> {
-    /// Construct a degenerate interval: [a, a].
-    pub fn degenerate(value: V) -> Self {
-        Interval::new_unchecked(bounds::Closed(value.clone()), bounds::Closed(value))
+// Returns the std representation of a bound.
+fn std_bound<B: bounds::Bound>(bound: &B) -> std::ops::Bound<&B::Value> {
+    match bound.value() {
+        None => std::ops::Bound::Unbounded,
+        Some(v) if bound.is_open() => std::ops::Bound::Excluded(v),
+        Some(v) => std::ops::Bound::Included(v),
     }
 }
 
-impl<V: Zero + One + PartialOrd> Closed<V> {
-    /// Construct a unit interval: [0, 1].
-    pub fn unit() -> Self {
-        Interval::closed_unchecked(V::zero(), V::one())
-    }
-}
+/// Allows intervals to be passed wherever std ranges are accepted.
+///
+/// Open bounds are excluded, closed bounds included, and unbounded sides
+/// unbounded; [OpenOrClosed](bounds::OpenOrClosed) bounds are mapped
+/// according to their variant.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// use std::collections::BTreeMap;
+///
+/// let map: BTreeMap<i32, char> = (0..10).zip("abcdefghij".chars()).collect();
+///
+/// let x = Interval::lorc_unchecked(2, 5);
+/// assert_eq!(map.range(x).map(|(_, c)| *c).collect::<String>(), "def");
+///
+/// let mut v: Vec<i32> = (0..10).collect();
+/// assert_eq!(v.drain(Interval::right_closed(2)).collect::<Vec<_>>(), vec![0, 1, 2]);
+/// ```
+impl<L, R> std::ops::RangeBounds<L::Value> for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+{
+    fn start_bound(&self) -> std::ops::Bound<&L::Value> { std_bound(&self.left) }
 
-impl<V: PartialOrd> Closed<V> {
-    /// Construct a uniform partition over the interval.
-    pub fn linspace(self, n_partitions: usize) -> partitions::Uniform<V> {
-        partitions::Uniform {
-            size: n_partitions,
-            left: self.left.0,
-            right: self.right.0,
-        }
-    }
+    fn end_bound(&self) -> std::ops::Bound<&L::Value> { std_bound(&self.right) }
 }
 
-///////////////////////////////////////////////////////////////////////////////
-// Ops
-///////////////////////////////////////////////////////////////////////////////
-/// Type alias to simplify intersection return types.
-pub type IntersectionOf<L, R, LL, RR> = Interval<
-    <L as bounds::Pinch<LL>>::Left,
-    <R as bounds::Pinch<RR>>::Right,
->;
-
 impl<L, R> Interval<L, R>
 where
     L: bounds::Bound,
     R: bounds::Bound<Value = L::Value>,
 
-    L::Value: PartialOrd,
+    bounds::Validator: bounds::ValidateBounds<L, R>,
 {
-    pub fn intersect<LL, RR>(self, other: Interval<LL, RR>) -> Option<IntersectionOf<L, R, LL, RR>>
-    where
-        L: bounds::Pinch<LL>,
-        R: bounds::Pinch<RR>,
-
-        LL: bounds::Bound,
-        RR: bounds::Bound<Value = LL::Value>,
-
-        bounds::Validator: bounds::ValidateBounds<L::Left, R::Right>,
-    {
-        let left = self.left.pinch_left(other.left);
-        let right = self.right.pinch_right(other.right);
-
-        Interval::new(left, right).ok()
+    /// Construct an interval with bound validation.
+    pub fn new(left: L, right: R) -> IntervalResult<L, R> {
+        bounds::validate(left, right).map(|(left, right)| Interval { left, right, })
     }
 }
 
-/// Type alias to simplify union-closure return types.
-pub type UnionClosureOf<L, R, LL, RR> = Interval<
-    <<L as bounds::Unroll<LL>>::Left as bounds::Bound>::WithLimit,
-    <<R as bounds::Unroll<RR>>::Right as bounds::Bound>::WithLimit
->;
-
 impl<L, R> Interval<L, R>
 where
     L: bounds::Bound,
     R: bounds::Bound<Value = L::Value>,
-
-    L::Value: PartialOrd,
 {
-    pub fn union_closure<LL, RR>(self, other: Interval<LL, RR>) -> UnionClosureOf<L, R, LL, RR>
-    where
-        L: bounds::Unroll<LL>,
-        R: bounds::Unroll<RR>,
-
-        LL: bounds::Bound,
-        RR: bounds::Bound<Value = LL::Value>,
+    /// Construct an interval with bound validation under the policy `P`.
+    ///
+    /// See [bounds::ValidationPolicy] for the available policies;
+    /// [Interval::new] is equivalent to `new_with::<bounds::AllowDegenerate>`.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, bounds::{self, AllowEmpty, Strict}};
+    /// let empty = Interval::new_with::<AllowEmpty>(bounds::Open(1.0), bounds::Open(1.0));
+    ///
+    /// assert!(empty.is_ok());
+    /// assert!(Interval::new(bounds::Open(1.0), bounds::Open(1.0)).is_err());
+    ///
+    /// let nan = Interval::new_with::<Strict>(bounds::Closed(f64::NAN), bounds::NoBound::new());
+    ///
+    /// assert!(nan.is_err());
+    /// ```
+    pub fn new_with<P>(left: L, right: R) -> IntervalResult<L, R>
+    where
+        P: bounds::ValidationPolicy,
+        bounds::Validator<P>: bounds::ValidateBounds<L, R>,
+    {
+        bounds::validate_with::<P, L, R>(left, right).map(|(left, right)| Interval { left, right, })
+    }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+{
+    /// Construct an interval w/o bound validation.
+    pub fn new_unchecked(left: L, right: R) -> Self { Interval { left, right, } }
+
+    /// Map the value of the left bound through `f` w/o bound validation.
+    ///
+    /// The right bound is untouched, and an unbounded left side is left
+    /// unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::lcro_unchecked(1.0, 4.0);
+    ///
+    /// assert_eq!(x.map_left(|l| l - 1.0), Interval::lcro_unchecked(0.0, 4.0));
+    /// assert_eq!(Interval::right_open(4.0).map_left(|l| l - 1.0), Interval::right_open(4.0));
+    /// ```
+    pub fn map_left<F: FnOnce(L::Value) -> L::Value>(self, f: F) -> Self {
+        Interval::new_unchecked(self.left.map_value(f), self.right)
+    }
+
+    /// Map the value of the right bound through `f` w/o bound validation.
+    ///
+    /// The left bound is untouched, and an unbounded right side is left
+    /// unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::lcro_unchecked(1.0, 4.0);
+    ///
+    /// assert_eq!(x.map_right(|r| r * 2.0), Interval::lcro_unchecked(1.0, 8.0));
+    /// assert_eq!(Interval::left_open(1.0).map_right(|r| r * 2.0), Interval::left_open(1.0));
+    /// ```
+    pub fn map_right<F: FnOnce(L::Value) -> L::Value>(self, f: F) -> Self {
+        Interval::new_unchecked(self.left, self.right.map_value(f))
+    }
+
+    /// Convert both bound values through `f` w/o bound validation.
+    ///
+    /// The kinds of the bounds are preserved. The caller is responsible for
+    /// ensuring that `f` is monotonically non-decreasing over the interval;
+    /// see [Interval::try_map_monotone] for a validated variant.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let seconds = Interval::lcro_unchecked(1.5, 2.25);
+    ///
+    /// assert_eq!(seconds.map(|s| (s * 1000.0) as u64), Interval::lcro_unchecked(1500, 2250));
+    /// assert_eq!(Interval::left_open(1i32).map(f64::from), Interval::left_open(1.0));
+    /// ```
+    pub fn map<U, F>(self, f: F) -> Interval<L::Mapped<U>, R::Mapped<U>>
+    where
+        U: PartialOrd,
+        F: Fn(L::Value) -> U,
+    {
+        Interval::new_unchecked(self.left.map(&f), self.right.map(&f))
+    }
+
+    /// Convert both bound values through `f` with bound validation.
+    ///
+    /// This guards against functions that are not monotone, for which the
+    /// mapped bounds may be out of order.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(-1i32, 2);
+    ///
+    /// assert_eq!(x.try_map_monotone(|v| v * 2).unwrap(), Interval::closed_unchecked(-2, 4));
+    /// assert!(x.try_map_monotone(|v| -v).is_err());
+    /// ```
+    pub fn try_map_monotone<U, F>(self, f: F) -> IntervalResult<L::Mapped<U>, R::Mapped<U>>
+    where
+        U: PartialOrd,
+        F: Fn(L::Value) -> U,
+
+        bounds::Validator: bounds::ValidateBounds<L::Mapped<U>, R::Mapped<U>>,
+    {
+        Interval::new(self.left.map(&f), self.right.map(&f))
+    }
+
+    /// Convert both bound values to another numeric type w/o bound
+    /// validation.
+    ///
+    /// Returns `None` if either value cannot be represented in `U`. Float
+    /// values are truncated towards zero when cast to an integer type, so an
+    /// open interval may collapse to an empty one.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::closed_unchecked(1i32, 5).cast::<f64>(), Some(Interval::closed_unchecked(1.0, 5.0)));
+    /// assert_eq!(Interval::closed_unchecked(-1i32, 5).cast::<u32>(), None);
+    /// assert_eq!(Interval::left_open(1e10f64).cast::<i32>(), None);
+    /// ```
+    pub fn cast<U>(self) -> Option<Interval<L::Mapped<U>, R::Mapped<U>>>
+    where
+        U: NumCast + PartialOrd,
+        L::Value: ToPrimitive + Clone,
+    {
+        // The outer option is None on failure, the inner if there's no value.
+        fn cast_value<V: ToPrimitive + Clone, U: NumCast>(value: Option<&V>) -> Option<Option<U>> {
+            match value {
+                Some(v) => U::from(v.clone()).map(Some),
+                None => Some(None),
+            }
+        }
+
+        let left = cast_value(self.left.value())?;
+        let right = cast_value(self.right.value())?;
+
+        // Bounds only call the closure if they have a value, in which case
+        // the cast value is present.
+        Some(Interval::new_unchecked(
+            self.left.map(|_| left.unwrap()),
+            self.right.map(|_| right.unwrap()),
+        ))
+    }
+
+    /// Returns a view of the interval that borrows its bound values.
+    ///
+    /// The view supports the same comparisons and formatting as the original
+    /// interval, without cloning either of the values.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::lcro_unchecked(String::from("apple"), String::from("banana"));
+    /// let view = x.as_ref();
+    ///
+    /// assert!(view.contains(&String::from("avocado")));
+    /// assert!(!view.contains(&String::from("banana")));
+    /// assert_eq!(view.to_string(), "[apple, banana)");
+    ///
+    /// // Combined with `map`, string slices can be tested directly:
+    /// assert!(view.map(String::as_str).contains("avocado"));
+    /// ```
+    pub fn as_ref(&self) -> Interval<L::Ref<'_>, R::Ref<'_>> {
+        Interval::new_unchecked(self.left.as_ref(), self.right.as_ref())
+    }
+
+    /// Returns the bounds of the interval as a pair of std bounds.
+    ///
+    /// Open bounds are excluded, closed bounds included, and unbounded sides
+    /// unbounded; this is the inverse of the `TryFrom` conversion from such
+    /// pairs.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// use std::ops::Bound;
+    ///
+    /// let x = Interval::lorc_unchecked(1, 4);
+    ///
+    /// assert_eq!(x.to_std_bounds(), (Bound::Excluded(&1), Bound::Included(&4)));
+    /// assert_eq!(Interval::left_closed(1).to_std_bounds(), (Bound::Included(&1), Bound::Unbounded));
+    /// ```
+    pub fn to_std_bounds(&self) -> (std::ops::Bound<&L::Value>, std::ops::Bound<&L::Value>) {
+        (std_bound(&self.left), std_bound(&self.right))
+    }
+
+    /// Consumes the interval, returning its bounds as a pair of std bounds;
+    /// see [Interval::to_std_bounds].
+    pub fn into_std_bounds(self) -> (std::ops::Bound<L::Value>, std::ops::Bound<L::Value>) {
+        (to_std_bound(self.left), to_std_bound(self.right))
+    }
+
+    /// Returns the interior of the interval, i.e. the interval without its
+    /// limit points.
+    ///
+    /// Each bounded side becomes open, and unbounded sides are left as they
+    /// are. Note that the interior of a degenerate interval is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::closed_unchecked(0, 1).interior(), Interval::open_unchecked(0, 1));
+    /// assert_eq!(Interval::left_closed(0).interior(), Interval::left_open(0));
+    /// ```
+    pub fn interior(self) -> Interval<L::WithoutLimit, R::WithoutLimit> {
+        Interval::new_unchecked(self.left.without_limit_point(), self.right.without_limit_point())
+    }
+
+    /// Returns the closure of the interval, i.e. the interval with its limit
+    /// points.
+    ///
+    /// Each bounded side becomes closed, and unbounded sides are left as they
+    /// are.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::lorc_unchecked(0.0, 1.0);
+    ///
+    /// assert_eq!(x.closure(), Interval::closed_unchecked(0.0, 1.0));
+    /// assert_eq!(x.interior().closure(), x.closure());
+    /// assert_eq!(x.closure().interior(), x.interior());
+    /// ```
+    pub fn closure(self) -> Interval<L::WithLimit, R::WithLimit> {
+        Interval::new_unchecked(self.left.with_limit_point(), self.right.with_limit_point())
+    }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    bounds::Validator: bounds::ValidateBounds<L, R>,
+{
+    /// Map the value of the left bound through `f` with bound validation.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(1.0, 4.0);
+    ///
+    /// assert!(x.try_map_left(|l| l + 3.0).is_ok());
+    /// assert!(x.try_map_left(|l| l + 4.0).is_err());
+    /// ```
+    pub fn try_map_left<F: FnOnce(L::Value) -> L::Value>(self, f: F) -> IntervalResult<L, R> {
+        Interval::new(self.left.map_value(f), self.right)
+    }
+
+    /// Map the value of the right bound through `f` with bound validation.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(1.0, 4.0);
+    ///
+    /// assert!(x.try_map_right(|r| r - 3.0).is_ok());
+    /// assert!(x.try_map_right(|r| r - 4.0).is_err());
+    /// ```
+    pub fn try_map_right<F: FnOnce(L::Value) -> L::Value>(self, f: F) -> IntervalResult<L, R> {
+        Interval::new(self.left, self.right.map_value(f))
+    }
+}
+
+impl<L: bounds::Bound> Interval<L, bounds::NoBound<L::Value>> {
+    /// Construct a left-bounded interval, unbounded on the right.
+    pub fn left_bounded(left: L) -> Self {
+        Interval {
+            left,
+            right: bounds::NoBound::new(),
+        }
+    }
+}
+
+impl<V: PartialOrd> LeftOpen<V> {
+    /// Construct a left-open interval, unbounded on the right.
+    pub fn left_open(left: V) -> Self {
+        Interval {
+            left: bounds::Open(left),
+            right: bounds::NoBound::new(),
+        }
+    }
+}
+
+impl<V: PartialOrd> LeftClosed<V> {
+    /// Construct a left-closed interval, unbounded on the right.
+    pub fn left_closed(left: V) -> Self {
+        Interval {
+            left: bounds::Closed(left),
+            right: bounds::NoBound::new(),
+        }
+    }
+}
+
+impl<R: bounds::Bound> Interval<bounds::NoBound<R::Value>, R> {
+    /// Construct a right-bounded interval, unbounded on the left.
+    pub fn right_bounded(right: R) -> Self {
+        Interval {
+            left: bounds::NoBound::new(),
+            right,
+        }
+    }
+}
+
+impl<V: PartialOrd> RightOpen<V> {
+    /// Construct a right-open interval, unbounded on the left.
+    pub fn right_open(right: V) -> Self {
+        Interval {
+            left: bounds::NoBound::new(),
+            right: bounds::Open(right),
+        }
+    }
+}
+
+impl<V: PartialOrd> RightClosed<V> {
+    /// Construct a right-closed interval, unbounded on the left.
+    pub fn right_closed(right: V) -> Self {
+        Interval {
+            left: bounds::NoBound::new(),
+            right: bounds::Closed(right),
+        }
+    }
+}
+
+impl<V: PartialOrd> LORC<V> {
+    /// Construct a left-open, right-closed interval with bound validation.
+    pub fn lorc(left: V, right: V) -> Result<Self, bounds::Open<V>, bounds::Closed<V>> {
+        Interval::new(bounds::Open(left), bounds::Closed(right))
+    }
+
+    /// Construct a left-open, right-closed interval w/o bound validation.
+    pub fn lorc_unchecked(left: V, right: V) -> Self {
+        Interval::new_unchecked(bounds::Open(left), bounds::Closed(right))
+    }
+}
+
+impl<V: PartialOrd> LCRO<V> {
+    /// Construct a left-closed, right-open interval with bound validation.
+    pub fn lcro(left: V, right: V) -> Result<Self, bounds::Closed<V>, bounds::Open<V>> {
+        Interval::new(bounds::Closed(left), bounds::Open(right))
+    }
+
+    /// Construct a left-closed, right-open interval w/o bound validation.
+    pub fn lcro_unchecked(left: V, right: V) -> Self {
+        Interval::new_unchecked(bounds::Closed(left), bounds::Open(right))
+    }
+}
+
+impl LCRO<usize> {
+    /// Construct the interval of valid indices into a slice: [0, len).
+    ///
+    /// The interval is empty, [0, 0), if the slice is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let buffer = [0u8; 10];
+    /// let requested = Interval::closed_unchecked(4, 15);
+    ///
+    /// let window = requested.intersect(Interval::indices_of(&buffer)).unwrap();
+    ///
+    /// assert_eq!(window, Interval::lcro_unchecked(4, 10));
+    /// ```
+    pub fn indices_of<T>(slice: &[T]) -> Self {
+        Interval::lcro_unchecked(0, slice.len())
+    }
+}
+
+impl Closed<usize> {
+    /// Construct the interval of valid indices into a slice: [0, len - 1].
+    ///
+    /// This is `None` if the slice is empty, as there is no closed interval
+    /// without members.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::indices_inclusive(&[1, 2, 3]), Some(Interval::closed_unchecked(0, 2)));
+    /// assert_eq!(Interval::indices_inclusive::<u8>(&[]), None);
+    /// ```
+    pub fn indices_inclusive<T>(slice: &[T]) -> Option<Self> {
+        slice.len().checked_sub(1).map(|last| Interval::closed_unchecked(0, last))
+    }
+}
+
+impl<V: PartialOrd> Unbounded<V> {
+    /// Construct a totally unbounded interval.
+    pub fn unbounded() -> Self {
+        Interval {
+            left: bounds::NoBound::new(),
+            right: bounds::NoBound::new(),
+        }
+    }
+}
+
+impl<V: PartialOrd> Open<V> {
+    /// Construct a bounded open interval with bound validation.
+    pub fn open(left: V, right: V) -> IntervalResult<bounds::Open<V>, bounds::Open<V>> {
+        Interval::new(bounds::Open(left), bounds::Open(right))
+    }
+
+    /// Construct a bounded open interval w/o bound validation.
+    pub fn open_unchecked(left: V, right: V) -> Self {
+        Interval::new_unchecked(bounds::Open(left), bounds::Open(right))
+    }
+}
+
+impl<V: PartialOrd> Closed<V> {
+    /// Construct a bounded closed interval with bound validation.
+    pub fn closed(left: V, right: V) -> IntervalResult<bounds::Closed<V>, bounds::Closed<V>> {
+        Interval::new(bounds::Closed(left), bounds::Closed(right))
+    }
+
+    /// Construct a bounded closed interval w/o bound validation.
+    pub fn closed_unchecked(left: V, right: V) -> Self {
+        Interval::new_unchecked(bounds::Closed(left), bounds::Closed(right))
+    }
+}
+
+impl<V: PartialOrd + Clone> Closed<V> {
+    /// Construct a degenerate interval: [a, a].
+    pub fn degenerate(value: V) -> Self {
+        Interval::new_unchecked(bounds::Closed(value.clone()), bounds::Closed(value))
+    }
+
+    /// Construct the smallest closed interval containing every value of an
+    /// iterator, or `None` if it yields no (comparable) values.
+    ///
+    /// The iterator is consumed in a single pass. Values that are not
+    /// comparable with themselves, such as NaN, are skipped.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let data = [3.0, -1.0, f64::NAN, 4.0, 1.5];
+    ///
+    /// assert_eq!(Interval::spanning(data.iter().cloned()), Some(Interval::closed_unchecked(-1.0, 4.0)));
+    /// assert_eq!(Interval::spanning(vec![7]), Some(Interval::degenerate(7)));
+    /// assert_eq!(Interval::spanning(Vec::<f64>::new()), None);
+    /// ```
+    pub fn spanning<I: IntoIterator<Item = V>>(values: I) -> Option<Self> {
+        let mut values = values.into_iter().filter(|x| x.partial_cmp(x).is_some());
+        let first = values.next()?;
+
+        let (left, right) = values.fold((first.clone(), first), |(left, right), x| {
+            if x < left {
+                (x, right)
+            } else if x > right {
+                (left, x)
+            } else {
+                (left, right)
+            }
+        });
+
+        Some(Interval::new_unchecked(bounds::Closed(left), bounds::Closed(right)))
+    }
+
+    /// Construct the smallest closed interval containing every value of a
+    /// sample, or `None` if it holds no (comparable) values.
+    ///
+    /// The slice is scanned once, skipping NaN as for [Interval::spanning].
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::from_sample(&[3.0, -1.0, 4.0, 1.5]).unwrap();
+    ///
+    /// assert_eq!(x, Interval::closed_unchecked(-1.0, 4.0));
+    /// assert_eq!(x.linspace(5).partition_width(), 1.0);
+    /// ```
+    pub fn from_sample(values: &[V]) -> Option<Self> { Interval::spanning(values.iter().cloned()) }
+
+    /// Construct the closed interval between the empirical `lower_q`- and
+    /// `upper_q`-quantiles of a sample, or `None` if it holds no (comparable)
+    /// values or unless `0 <= lower_q <= upper_q <= 1` (so NaN quantiles also
+    /// yield `None`).
+    ///
+    /// With the `n` comparable values sorted as `x[0], ..., x[n - 1]`, the
+    /// `q`-quantile is taken to be `x[round(q * (n - 1))]`, i.e. the sample
+    /// value nearest to the interpolated quantile, so the bounds are always
+    /// sample values. NaN values are skipped, and do not count towards `n`.
+    /// Quantiles of 0 and 1 give [Interval::from_sample].
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let data = [-1000.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 1000.0];
+    ///
+    /// assert_eq!(Interval::from_sample_trimmed(&data, 0.1, 0.9), Some(Interval::closed_unchecked(1.0, 8.0)));
+    /// assert_eq!(Interval::from_sample_trimmed(&data, 0.9, 0.1), None);
+    /// ```
+    pub fn from_sample_trimmed(values: &[V], lower_q: f64, upper_q: f64) -> Option<Self> {
+        if !(0.0 <= lower_q && lower_q <= upper_q && upper_q <= 1.0) { return None; }
+
+        let mut sorted: Vec<&V> = values.iter().filter(|x| x.partial_cmp(x).is_some()).collect();
+
+        if sorted.is_empty() { return None; }
+
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let last = (sorted.len() - 1) as f64;
+        let left = sorted[(lower_q * last).round() as usize].clone();
+        let right = sorted[(upper_q * last).round() as usize].clone();
+
+        Some(Interval::new_unchecked(bounds::Closed(left), bounds::Closed(right)))
+    }
+}
+
+impl<V: Zero + One + PartialOrd> Closed<V> {
+    /// Construct a unit interval: [0, 1].
+    pub fn unit() -> Self {
+        Interval::closed_unchecked(V::zero(), V::one())
+    }
+}
+
+impl<V: PartialOrd> Closed<V> {
+    /// Construct a uniform partition over the interval.
+    pub fn linspace(self, n_partitions: usize) -> partitions::Uniform<V> {
+        partitions::Uniform::new(n_partitions, self.left.0, self.right.0)
+    }
+}
+
+/// Trait for values between which an interval can be split evenly.
+pub trait SplitValue: Clone + PartialOrd {
+    /// Returns the `n + 1` edges splitting `[left, right]` into `n` pieces of
+    /// equal width, or `None` if there are not enough values between them.
+    fn even_edges(left: &Self, right: &Self, n: usize) -> Option<Vec<Self>>;
+
+    /// Returns the value halfway between `left` and `right`, rounded towards
+    /// `left` for integers.
+    fn midpoint_between(left: &Self, right: &Self) -> Self;
+}
+
+macro_rules! impl_split_int {
+    ($($t:ty as $u:ty),+) => {
+        $(
+            impl SplitValue for $t {
+                fn even_edges(left: &$t, right: &$t, n: usize) -> Option<Vec<$t>> {
+                    // The width always fits in the unsigned counterpart.
+                    let width = (*right as $u).wrapping_sub(*left as $u) as u128;
+                    let n_w = n as u128;
+
+                    // Every piece but the last is half-open, so needs a width of
+                    // at least one.
+                    if n == 0 || (n > 1 && n_w > width) { return None; }
+
+                    // Spreads the remainder, such that width * k / n can't
+                    // overflow.
+                    let (step, rem) = (width / n_w, width % n_w);
+
+                    Some((0..=n).map(|k| {
+                        let offset = step * k as u128 + rem * k as u128 / n_w;
+
+                        left.wrapping_add(offset as $u as $t)
+                    }).collect())
+                }
+
+                fn midpoint_between(left: &$t, right: &$t) -> $t {
+                    let half = (*right as $u).wrapping_sub(*left as $u) / 2;
+
+                    left.wrapping_add(half as $t)
+                }
+            }
+        )+
+    };
+}
+
+impl_split_int!(
+    u8 as u8, u16 as u16, u32 as u32, u64 as u64, u128 as u128, usize as usize,
+    i8 as u8, i16 as u16, i32 as u32, i64 as u64, i128 as u128, isize as usize
+);
+
+macro_rules! impl_split_float {
+    ($($t:ty),+) => {
+        $(
+            impl SplitValue for $t {
+                fn even_edges(left: &$t, right: &$t, n: usize) -> Option<Vec<$t>> {
+                    if n == 0 { return None; }
+
+                    // Each edge is computed directly, so no error accumulates
+                    // across the pieces, and the last edge is exactly right.
+                    let edges: Vec<$t> = (0..=n).map(|k| if k == n {
+                        *right
+                    } else {
+                        left + (right - left) * k as $t / n as $t
+                    }).collect();
+
+                    // Every piece but the last is half-open, so coincident
+                    // edges (e.g. below the resolution of the type) would
+                    // leave it empty.
+                    if n > 1 && !edges.windows(2).all(|w| w[0] < w[1]) { return None; }
+
+                    Some(edges)
+                }
+
+                fn midpoint_between(left: &$t, right: &$t) -> $t { left + (right - left) / 2.0 }
+            }
+        )+
+    };
+}
+
+impl_split_float!(f32, f64);
+
+impl<V: SplitValue> Closed<V> {
+    /// Split the interval into `n` pieces of equal width.
+    ///
+    /// The pieces are closed on the left and open on the right, except for
+    /// the last which is closed on both sides, such that they tile the
+    /// interval. For integers, the widths of the pieces differ by at most
+    /// one, and the edges are computed without overflow.
+    ///
+    /// Yields [partitions::PartitionError::IllFormedBounds] (holding a copy of
+    /// the interval) if `n` is zero, or if some piece would be empty: for
+    /// `n > 1`, integer intervals require `n` to be at most the width
+    /// `right - left` (so `[0, 2]` splits into at most two pieces), and float
+    /// intervals require the computed edges to be strictly increasing.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, bounds::OpenOrClosed};
+    /// let pieces = Interval::closed_unchecked(0.0, 1.0).split_evenly(4).unwrap();
+    ///
+    /// assert_eq!(pieces.len(), 4);
+    /// assert_eq!(pieces[0], Interval::lcro_unchecked(0.0, 0.25));
+    /// assert_eq!(pieces[3], Interval::closed_unchecked(0.75, 1.0));
+    ///
+    /// assert!(Interval::closed_unchecked(0u32, 2).split_evenly(4).is_err());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn split_evenly(
+        &self,
+        n: usize,
+    ) -> std::result::Result<
+        Vec<Interval<bounds::Closed<V>, bounds::OpenOrClosed<V>>>,
+        partitions::PartitionError<Self>,
+    > {
+        let edges = V::even_edges(&self.left.0, &self.right.0, n)
+            .ok_or_else(|| partitions::PartitionError::IllFormedBounds(self.clone()))?;
+
+        Ok(edges.windows(2).enumerate().map(|(k, w)| {
+            let right = if k + 1 == n {
+                bounds::OpenOrClosed::Closed(w[1].clone())
+            } else {
+                bounds::OpenOrClosed::Open(w[1].clone())
+            };
+
+            Interval::new_unchecked(bounds::Closed(w[0].clone()), right)
+        }).collect())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Ops
+///////////////////////////////////////////////////////////////////////////////
+/// Type alias to simplify intersection return types.
+pub type IntersectionOf<L, R, LL, RR> = Interval<
+    <L as bounds::Pinch<LL>>::Left,
+    <R as bounds::Pinch<RR>>::Right,
+>;
+
+impl<L, R> Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: PartialOrd,
+{
+    pub fn intersect<LL, RR>(self, other: Interval<LL, RR>) -> Option<IntersectionOf<L, R, LL, RR>>
+    where
+        L: bounds::Pinch<LL>,
+        R: bounds::Pinch<RR>,
+
+        LL: bounds::Bound,
+        RR: bounds::Bound<Value = LL::Value>,
+
+        bounds::Validator: bounds::ValidateBounds<L::Left, R::Right>,
+    {
+        let left = self.left.pinch_left(other.left);
+        let right = self.right.pinch_right(other.right);
+
+        Interval::new(left, right).ok()
+    }
+
+    /// Returns the intersection of the interval with `other`, leaving both
+    /// intact.
+    ///
+    /// The result is identical to that of [Interval::intersect]. The bounds
+    /// are only cloned if the intervals intersect, so scanning a collection
+    /// for overlaps with a fixed interval costs no clones for the misses.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(String::from("b"), String::from("d"));
+    /// let y = Interval::closed_unchecked(String::from("c"), String::from("e"));
+    ///
+    /// assert_eq!(x.intersect_ref(&y), Some(Interval::closed_unchecked(String::from("c"), String::from("d"))));
+    /// assert_eq!(x.intersect_ref(&y), x.clone().intersect(y));
+    /// ```
+    pub fn intersect_ref<LL, RR>(&self, other: &Interval<LL, RR>) -> Option<IntersectionOf<L, R, LL, RR>>
+    where
+        L: bounds::Pinch<LL> + Clone,
+        R: bounds::Pinch<RR> + Clone,
+
+        LL: bounds::Bound<Value = L::Value> + Clone,
+        RR: bounds::Bound<Value = L::Value> + Clone,
+
+        bounds::Validator: bounds::ValidateBounds<L::Left, R::Right>,
+    {
+        if !self.intersects(other) { return None; }
+
+        self.clone().intersect(other.clone())
+    }
+
+    /// Returns true if the interval intersects `other`.
+    ///
+    /// This agrees with `self.intersect(other).is_some()`, but only compares
+    /// the bounds in place, without constructing the intersection.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(0, 1);
+    ///
+    /// assert!(x.intersects(&Interval::closed_unchecked(1, 2)));
+    /// assert!(!x.intersects(&Interval::open_unchecked(1, 2)));
+    /// assert!(x.intersects(&Interval::right_open(1)));
+    /// ```
+    pub fn intersects<LL, RR>(&self, other: &Interval<LL, RR>) -> bool
+    where
+        LL: bounds::Bound<Value = L::Value>,
+        RR: bounds::Bound<Value = L::Value>,
+    {
+        use bounds::{cmp_bounds, Side};
+        use std::cmp::Ordering;
+
+        fn below<A, B>(left: &A, right: &B) -> bool
+        where
+            A: bounds::Bound,
+            B: bounds::Bound<Value = A::Value>,
+        {
+            matches!(
+                cmp_bounds(left, Side::Left, right, Side::Right),
+                Some(Ordering::Less) | Some(Ordering::Equal)
+            )
+        }
+
+        below(&self.left, &self.right) && below(&other.left, &other.right) &&
+            below(&self.left, &other.right) && below(&other.left, &self.right)
+    }
+
+    /// Returns the width of the intersection with `other`, or `None` if the
+    /// intersection is unbounded.
+    ///
+    /// The result is zero if the intervals are disjoint or meet at a single
+    /// point, and otherwise agrees with the width of `self.intersect(other)`,
+    /// but is computed from the bound values in place.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(0, 10);
+    ///
+    /// assert_eq!(x.overlap(&Interval::lcro_unchecked(5, 20)), Some(5));
+    /// assert_eq!(x.overlap(&Interval::closed_unchecked(10, 20)), Some(0));
+    /// assert_eq!(x.overlap(&Interval::left_open(20)), Some(0));
+    ///
+    /// assert_eq!(Interval::left_closed(0.0).overlap(&Interval::left_open(1.0)), None);
+    /// ```
+    pub fn overlap<LL, RR>(&self, other: &Interval<LL, RR>) -> Option<L::Value>
+    where
+        LL: bounds::Bound<Value = L::Value>,
+        RR: bounds::Bound<Value = L::Value>,
+
+        L::Value: Clone + Zero + std::ops::Sub<Output = L::Value>,
+    {
+        if !self.intersects(other) { return Some(L::Value::zero()); }
+
+        let left = match (self.left.value(), other.left.value()) {
+            (Some(a), Some(b)) => if a < b { b } else { a },
+            (Some(a), None) | (None, Some(a)) => a,
+            (None, None) => return None,
+        };
+
+        let right = match (self.right.value(), other.right.value()) {
+            (Some(a), Some(b)) => if a > b { b } else { a },
+            (Some(a), None) | (None, Some(a)) => a,
+            (None, None) => return None,
+        };
+
+        Some(right.clone() - left.clone())
+    }
+
+    /// Returns the Jaccard similarity with `other`: the width of the
+    /// intersection over the width of the span of the two intervals.
+    ///
+    /// Widths are computed in `f64`, as for [Interval::measure], with
+    /// unbounded widths being infinite. The result is 0 for disjoint
+    /// intervals and 1 for equal, non-degenerate bounded ones. It is NaN if
+    /// the ratio is undefined, i.e. when both intervals are the same point or
+    /// both widths are infinite.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(0, 4);
+    ///
+    /// assert_eq!(x.jaccard(&Interval::closed_unchecked(2, 6)), 1.0 / 3.0);
+    /// assert_eq!(x.jaccard(&Interval::closed_unchecked(1, 2)), 0.25);
+    /// assert_eq!(x.jaccard(&Interval::closed_unchecked(5, 6)), 0.0);
+    /// assert_eq!(x.jaccard(&Interval::left_closed(2)), 0.0);
+    /// ```
+    pub fn jaccard<LL, RR>(&self, other: &Interval<LL, RR>) -> f64
+    where
+        LL: bounds::Bound<Value = L::Value>,
+        RR: bounds::Bound<Value = L::Value>,
+
+        L::Value: ToPrimitive,
+    {
+        if !self.intersects(other) { return 0.0; }
+
+        let (a, b) = (extent(self), extent(other));
+
+        (a.1.min(b.1) - a.0.max(b.0)) / (a.1.max(b.1) - a.0.min(b.0))
+    }
+
+    /// Returns the overlap coefficient with `other`: the width of the
+    /// intersection over the width of the narrower interval.
+    ///
+    /// Widths are computed as for [Interval::jaccard]. The result is 0 for
+    /// disjoint intervals and 1 when one contains the other, provided the
+    /// narrower one is bounded and not degenerate; otherwise it is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(0.0, 4.0);
+    ///
+    /// assert_eq!(x.overlap_coefficient(&Interval::closed_unchecked(2.0, 6.0)), 0.5);
+    /// assert_eq!(x.overlap_coefficient(&Interval::closed_unchecked(1.0, 2.0)), 1.0);
+    /// assert_eq!(x.overlap_coefficient(&Interval::left_closed(2.0)), 0.5);
+    /// assert!(x.overlap_coefficient(&Interval::degenerate(2.0)).is_nan());
+    /// ```
+    pub fn overlap_coefficient<LL, RR>(&self, other: &Interval<LL, RR>) -> f64
+    where
+        LL: bounds::Bound<Value = L::Value>,
+        RR: bounds::Bound<Value = L::Value>,
+
+        L::Value: ToPrimitive,
+    {
+        if !self.intersects(other) { return 0.0; }
+
+        let (a, b) = (extent(self), extent(other));
+
+        (a.1.min(b.1) - a.0.max(b.0)) / (a.1 - a.0).min(b.1 - b.0)
+    }
+}
+
+// Returns the bound values of an interval as `f64`s, with unbounded sides at
+// the infinities.
+fn extent<L, R>(x: &Interval<L, R>) -> (f64, f64)
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: ToPrimitive,
+{
+    let to_f64 = |v: Option<&L::Value>, unbounded: f64| {
+        v.map_or(unbounded, |v| v.to_f64().unwrap_or(f64::NAN))
+    };
+
+    (to_f64(x.left.value(), f64::NEG_INFINITY), to_f64(x.right.value(), f64::INFINITY))
+}
+
+/// Type alias to simplify union-closure return types.
+pub type UnionClosureOf<L, R, LL, RR> = Interval<
+    <<L as bounds::Unroll<LL>>::Left as bounds::Bound>::WithLimit,
+    <<R as bounds::Unroll<RR>>::Right as bounds::Bound>::WithLimit
+>;
+
+impl<L, R> Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: PartialOrd,
+{
+    pub fn union_closure<LL, RR>(self, other: Interval<LL, RR>) -> UnionClosureOf<L, R, LL, RR>
+    where
+        L: bounds::Unroll<LL>,
+        R: bounds::Unroll<RR>,
+
+        LL: bounds::Bound,
+        RR: bounds::Bound<Value = LL::Value>,
+    {
+        use bounds::Bound;
+
+        let left = self.left.unroll_left(other.left).with_limit_point();
+        let right = self.right.unroll_right(other.right).with_limit_point();
+
+        Interval::new_unchecked(left, right)
+    }
+}
+
+/// Type alias to simplify span return types.
+pub type SpanOf<L, R, LL, RR> = Interval<
+    <L as bounds::Unroll<LL>>::Left,
+    <R as bounds::Unroll<RR>>::Right
+>;
+
+impl<L, R> Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: PartialOrd,
+{
+    /// Returns the smallest interval spanning both `self` and `other`.
+    ///
+    /// Unlike [Interval::union_closure], the limit points are not added: the
+    /// outermost bounds keep the openness of whichever operand supplied them.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, bounds};
+    /// let x = Interval::open_unchecked(0.0, 1.0);
+    /// let y = Interval::open_unchecked(2.0, 3.0);
+    ///
+    /// assert_eq!(x.span(y), Interval::open_unchecked(0.0, 3.0));
+    /// assert_eq!(x.union_closure(y), Interval::closed_unchecked(0.0, 3.0));
+    /// ```
+    pub fn span<LL, RR>(self, other: Interval<LL, RR>) -> SpanOf<L, R, LL, RR>
+    where
+        L: bounds::Unroll<LL>,
+        R: bounds::Unroll<RR>,
+
+        LL: bounds::Bound,
+        RR: bounds::Bound<Value = LL::Value>,
+    {
+        let left = self.left.unroll_left(other.left);
+        let right = self.right.unroll_right(other.right);
+
+        Interval::new_unchecked(left, right)
+    }
+
+    /// Returns the union of `self` and `other` if it is an interval.
+    ///
+    /// The union is an interval exactly when the operands overlap or touch at
+    /// a point that at least one of them contains, in which case it equals
+    /// their [span](Interval::span). Otherwise there is a gap between them
+    /// (or their bounds are incomparable), and `None` is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::lcro_unchecked(0, 1);
+    ///
+    /// assert_eq!(x.union(Interval::closed_unchecked(1, 2)).unwrap().to_string(), "[0, 2]");
+    /// assert_eq!(x.union(Interval::lorc_unchecked(1, 2)), None);
+    /// assert_eq!(x.union(Interval::left_open(0)).unwrap().to_string(), "[0, \u{221E})");
+    /// ```
+    pub fn union<LL, RR>(self, other: Interval<LL, RR>) -> Option<SpanOf<L, R, LL, RR>>
+    where
+        L: bounds::Unroll<LL>,
+        R: bounds::Unroll<RR>,
+
+        LL: bounds::Bound<Value = L::Value>,
+        RR: bounds::Bound<Value = L::Value>,
+    {
+        use std::cmp::Ordering;
+
+        // Returns true if no value lies between `right` and a greater `left`,
+        // i.e. the point at which they meet is covered.
+        fn connected<A, B>(right: &A, left: &B) -> bool
+        where
+            A: bounds::Bound,
+            B: bounds::Bound<Value = A::Value>,
+        {
+            match (right.value(), left.value()) {
+                (Some(r), Some(l)) => match r.partial_cmp(l) {
+                    Some(Ordering::Greater) => true,
+                    Some(Ordering::Equal) => right.is_closed() || left.is_closed(),
+                    _ => false,
+                },
+                _ => true,
+            }
+        }
+
+        if connected(&self.right, &other.left) && connected(&other.right, &self.left) {
+            Some(self.span(other))
+        } else {
+            None
+        }
+    }
+}
+
+/// Type alias to simplify extension return types.
+pub type ExtensionOf<L, R> = SpanOf<
+    L, R,
+    bounds::Closed<<L as bounds::Bound>::Value>,
+    bounds::Closed<<L as bounds::Bound>::Value>
+>;
+
+impl<L, R> Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: PartialOrd + Clone,
+{
+    /// Returns the smallest interval containing both `self` and `val`.
+    ///
+    /// This is equivalent to the [span](Interval::span) with the degenerate
+    /// interval `{val}`. Any bound that moves to `val` becomes closed, as does
+    /// an open bound equal to `val`; unbounded sides are never changed.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, bounds};
+    /// let x = Interval::closed_unchecked(0.0, 1.0);
+    ///
+    /// assert_eq!(x.extend_to(2.0), Interval::closed_unchecked(0.0, 2.0));
+    ///
+    /// let x = Interval::open_unchecked(0.0, 1.0).extend_to(0.0);
+    ///
+    /// assert_eq!(x.left, bounds::Closed(0.0));
+    /// assert_eq!(x.right, bounds::Open(1.0));
+    /// ```
+    pub fn extend_to(self, val: L::Value) -> ExtensionOf<L, R>
+    where
+        L: bounds::Unroll<bounds::Closed<<L as bounds::Bound>::Value>>,
+        R: bounds::Unroll<bounds::Closed<<L as bounds::Bound>::Value>>,
+    {
+        self.span(Interval::degenerate(val))
+    }
+}
+
+impl<V: PartialOrd> Interval<bounds::OpenOrClosed<V>, bounds::OpenOrClosed<V>> {
+    /// Returns the smallest interval covering all of `intervals`, or `None`
+    /// if there are none.
+    ///
+    /// The result is the [span](Interval::span) of the intervals, so the
+    /// outermost bounds keep their openness and a single interval is returned
+    /// unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, bounds::OpenOrClosed};
+    /// let hull = Interval::hull_of(vec![
+    ///     Interval::new_unchecked(OpenOrClosed::Open(2), OpenOrClosed::Closed(3)),
+    ///     Interval::new_unchecked(OpenOrClosed::Open(0), OpenOrClosed::Open(1)),
+    ///     Interval::new_unchecked(OpenOrClosed::Closed(1), OpenOrClosed::Open(3)),
+    /// ]);
+    ///
+    /// assert_eq!(hull, Some(Interval::new_unchecked(OpenOrClosed::Open(0), OpenOrClosed::Closed(3))));
+    /// assert_eq!(Interval::<_, _>::hull_of(Vec::<Interval<_, OpenOrClosed<i32>>>::new()), None);
+    /// ```
+    ///
+    /// Bounded intervals of other shapes can be converted with `into`:
+    ///
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let xs = vec![Interval::closed_unchecked(0.0, 1.0), Interval::closed_unchecked(4.0, 5.0)];
+    /// let ys = vec![Interval::lcro_unchecked(2.0, 6.0)];
+    ///
+    /// let hull = Interval::hull_of(
+    ///     xs.into_iter().map(Interval::into).chain(ys.into_iter().map(Interval::into))
+    /// );
+    ///
+    /// assert_eq!(hull.unwrap().to_string(), "[0, 6)");
+    /// ```
+    pub fn hull_of<I: IntoIterator<Item = Self>>(intervals: I) -> Option<Self> {
+        let mut intervals = intervals.into_iter();
+        let first = intervals.next()?;
+
+        Some(intervals.fold(first, |hull, x| hull.span(x)))
+    }
+}
+
+impl<V: PartialOrd + Clone> Interval<bounds::OpenOrClosed<V>, bounds::OpenOrClosed<V>> {
+    /// Extend the interval in place such that it contains `val`.
+    ///
+    /// This is the mutating counterpart of [Interval::extend_to], suited to
+    /// tracking the running range of a stream of values.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, bounds::OpenOrClosed};
+    /// let mut x = Interval::new_unchecked(OpenOrClosed::Closed(0), OpenOrClosed::Closed(0));
+    ///
+    /// for v in [3, -1, 2].iter() {
+    ///     x.extend_to_include(*v);
+    /// }
+    ///
+    /// assert_eq!(x, Interval::closed_unchecked(-1, 3));
+    /// ```
+    pub fn extend_to_include(&mut self, val: V) {
+        let extend_left = match self.left {
+            bounds::OpenOrClosed::Open(ref l) => val <= *l,
+            bounds::OpenOrClosed::Closed(ref l) => val < *l,
+        };
+        let extend_right = match self.right {
+            bounds::OpenOrClosed::Open(ref r) => val >= *r,
+            bounds::OpenOrClosed::Closed(ref r) => val > *r,
+        };
+
+        if extend_left { self.left = bounds::OpenOrClosed::Closed(val.clone()); }
+        if extend_right { self.right = bounds::OpenOrClosed::Closed(val); }
+    }
+}
+
+/// Reason a list of cut points could not be used to split an interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitError {
+    /// The point at the given index does not lie strictly inside the interval.
+    OutOfRange(usize),
+
+    /// The point at the given index is not strictly greater than its
+    /// predecessor.
+    Unordered(usize),
+}
+
+impl std::fmt::Display for SplitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SplitError::OutOfRange(index) => write!(
+                f, "The cut point at index {} is not strictly inside the interval.", index
+            ),
+            SplitError::Unordered(index) => write!(
+                f, "The cut points are not strictly increasing at index {}.", index
+            ),
+        }
+    }
+}
+
+/// Type alias for the pieces produced by [Interval::split_at_many].
+pub type Pieces<V> = Vec<Interval<bounds::OpenOrClosed<V>, bounds::OpenOrClosed<V>>>;
+
+impl<L, R> Interval<L, R>
+where
+    L: bounds::ProperBound + Into<bounds::OpenOrClosed<L::Value>>,
+    R: bounds::ProperBound<Value = L::Value> + Into<bounds::OpenOrClosed<L::Value>>,
+
+    L::Value: PartialOrd + Clone,
+{
+    /// Split the interval at each of the given cut points.
+    ///
+    /// The points must be strictly increasing and lie strictly inside the
+    /// interval; duplicate points are rejected rather than merged. Each piece
+    /// is closed on the left and open on the right at the cuts, while the
+    /// outermost pieces keep the openness of the original bounds. An empty
+    /// list of points yields the interval itself as a single piece.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, SplitError};
+    /// let pieces = Interval::lorc_unchecked(0, 10).split_at_many(&[3, 7]).unwrap();
+    ///
+    /// assert_eq!(pieces[0], Interval::open_unchecked(0, 3));
+    /// assert_eq!(pieces[1], Interval::lcro_unchecked(3, 7));
+    /// assert_eq!(pieces[2], Interval::closed_unchecked(7, 10));
+    ///
+    /// assert_eq!(Interval::closed_unchecked(0, 10).split_at_many(&[3, 3]), Err(SplitError::Unordered(1)));
+    /// assert_eq!(Interval::closed_unchecked(0, 10).split_at_many(&[3, 10]), Err(SplitError::OutOfRange(1)));
+    /// ```
+    pub fn split_at_many(self, points: &[L::Value]) -> std::result::Result<Pieces<L::Value>, SplitError> {
+        for (i, p) in points.iter().enumerate() {
+            if !(self.left.proper_value() < p && p < self.right.proper_value()) {
+                return Err(SplitError::OutOfRange(i));
+            }
+
+            if i > 0 && points[i - 1] >= *p {
+                return Err(SplitError::Unordered(i));
+            }
+        }
+
+        let mut left = self.left.into();
+        let mut pieces = Vec::with_capacity(points.len() + 1);
+
+        for p in points {
+            pieces.push(Interval::new_unchecked(left, bounds::OpenOrClosed::Open(p.clone())));
+            left = bounds::OpenOrClosed::Closed(p.clone());
+        }
+
+        pieces.push(Interval::new_unchecked(left, self.right.into()));
+
+        Ok(pieces)
+    }
+}
+
+/// Type alias for the pieces produced by [Interval::split_at].
+pub type SplitOf<L, R> = (
+    Interval<L, bounds::Open<<L as bounds::Bound>::Value>>,
+    Interval<bounds::Closed<<L as bounds::Bound>::Value>, R>,
+);
+
+impl<L, R> Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: Clone,
+{
+    /// Split the interval into the parts below and above `x`.
+    ///
+    /// The cut point belongs to the upper part, as in
+    /// [Interval::split_at_many], so the lower part is open and the upper part
+    /// closed at `x`; the outer bounds are unchanged. Yields
+    /// [SplitError::OutOfRange] unless `x` lies strictly inside the interval,
+    /// such that both parts are non-empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, SplitError};
+    /// let (lower, upper) = Interval::closed_unchecked(0, 10).split_at(4).unwrap();
+    ///
+    /// assert_eq!(lower, Interval::lcro_unchecked(0, 4));
+    /// assert_eq!(upper, Interval::closed_unchecked(4, 10));
+    ///
+    /// let (lower, upper) = Interval::left_open(0.0).split_at(1.0).unwrap();
+    ///
+    /// assert_eq!(lower, Interval::open_unchecked(0.0, 1.0));
+    /// assert_eq!(upper, Interval::left_closed(1.0));
+    ///
+    /// assert_eq!(Interval::closed_unchecked(0, 10).split_at(0), Err(SplitError::OutOfRange(0)));
+    /// ```
+    pub fn split_at(self, x: L::Value) -> std::result::Result<SplitOf<L, R>, SplitError> {
+        let above_left = self.left.value().is_none_or(|l| *l < x);
+        let below_right = self.right.value().is_none_or(|r| x < *r);
+
+        if !(above_left && below_right) { return Err(SplitError::OutOfRange(0)); }
+
+        Ok((
+            Interval::new_unchecked(self.left, bounds::Open(x.clone())),
+            Interval::new_unchecked(bounds::Closed(x), self.right),
+        ))
+    }
+
+    /// Split the interval into two halves at its [midpoint](Interval::midpoint).
+    ///
+    /// The halves are as given by [Interval::split_at]: the lower half is
+    /// right-open and the upper half inherits the original right bound. Yields
+    /// [SplitError::OutOfRange] if either side is unbounded, or if the
+    /// midpoint does not lie strictly inside the interval; the latter is the
+    /// case for degenerate intervals, and for integer intervals of width one
+    /// or less, whose midpoint truncates to the left bound.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, SplitError};
+    /// let (lower, upper) = Interval::closed_unchecked(0.0, 1.0).bisect().unwrap();
+    ///
+    /// assert_eq!(lower, Interval::lcro_unchecked(0.0, 0.5));
+    /// assert_eq!(upper, Interval::closed_unchecked(0.5, 1.0));
+    ///
+    /// assert_eq!(Interval::degenerate(1.0).bisect(), Err(SplitError::OutOfRange(0)));
+    /// ```
+    pub fn bisect(&self) -> std::result::Result<SplitOf<L, R>, SplitError>
+    where
+        L: Clone,
+        R: Clone,
+        L::Value: SplitValue,
     {
-        use bounds::Bound;
+        let mid = self.midpoint().ok_or(SplitError::OutOfRange(0))?;
 
-        let left = self.left.unroll_left(other.left).with_limit_point();
-        let right = self.right.unroll_right(other.right).with_limit_point();
+        self.clone().split_at(mid)
+    }
+}
 
-        Interval::new_unchecked(left, right)
+// Returns the bound at the same point with the opposite openness, i.e. the
+// bound of the complementary ray.
+fn complement_bound<V>(bound: bounds::OpenOrClosed<V>) -> bounds::OpenOrClosed<V> {
+    match bound {
+        bounds::OpenOrClosed::Open(x) => bounds::OpenOrClosed::Closed(x),
+        bounds::OpenOrClosed::Closed(x) => bounds::OpenOrClosed::Open(x),
+    }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: bounds::ProperBound + Into<bounds::OpenOrClosed<L::Value>>,
+    R: bounds::ProperBound<Value = L::Value> + Into<bounds::OpenOrClosed<L::Value>>,
+
+    L::Value: PartialOrd + Clone,
+{
+    /// Returns the part of the interval not covered by `other`.
+    ///
+    /// The result holds zero, one or two pieces, in order. The openness at
+    /// each cut is the complement of the corresponding bound of `other`, and
+    /// pieces that would be empty are dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let outer = Interval::closed_unchecked(0.0, 10.0);
+    /// let pieces = outer.difference(Interval::lcro_unchecked(2.0, 5.0));
+    ///
+    /// assert_eq!(pieces.len(), 2);
+    /// assert_eq!(pieces[0], Interval::lcro_unchecked(0.0, 2.0));
+    /// assert_eq!(pieces[1], Interval::closed_unchecked(5.0, 10.0));
+    /// ```
+    pub fn difference<LL, RR>(self, other: Interval<LL, RR>) -> Pieces<L::Value>
+    where
+        LL: bounds::ProperBound<Value = L::Value> + Into<bounds::OpenOrClosed<L::Value>>,
+        RR: bounds::ProperBound<Value = L::Value> + Into<bounds::OpenOrClosed<L::Value>>,
+    {
+        use bounds::Pinch;
+
+        let (left, right): (bounds::OpenOrClosed<_>, bounds::OpenOrClosed<_>) =
+            (self.left.into(), self.right.into());
+
+        let below = Interval::new(
+            left.clone(),
+            right.clone().pinch_right(complement_bound(other.left.into())),
+        );
+        let above = Interval::new(
+            left.pinch_left(complement_bound(other.right.into())),
+            right,
+        );
+
+        below.into_iter().chain(above).collect()
+    }
+
+    /// Returns the part of `outer` not covered by the interval.
+    ///
+    /// This is equivalent to `outer.difference(self)`; see
+    /// [Interval::difference].
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let mask = Interval::closed_unchecked(-5, 2);
+    /// let pieces = mask.complement_within(Interval::closed_unchecked(0, 10));
+    ///
+    /// assert_eq!(pieces.len(), 1);
+    /// assert_eq!(pieces[0], Interval::lorc_unchecked(2, 10));
+    /// ```
+    pub fn complement_within<LL, RR>(self, outer: Interval<LL, RR>) -> Pieces<L::Value>
+    where
+        LL: bounds::ProperBound<Value = L::Value> + Into<bounds::OpenOrClosed<L::Value>>,
+        RR: bounds::ProperBound<Value = L::Value> + Into<bounds::OpenOrClosed<L::Value>>,
+    {
+        outer.difference(self)
     }
 }
 
@@ -334,6 +1967,54 @@ where
         Contains::<L, R>::contains(self, val)
     }
 
+    /// Returns true if the interval contains the value behind `val`.
+    ///
+    /// __Note__: see [Contains::contains_ref] for more details.
+    pub fn contains_ref(&self, val: &L::Value) -> bool
+    where
+        Self: Contains<L, R>
+    {
+        Contains::<L, R>::contains_ref(self, val)
+    }
+
+    /// Returns, for each of `values`, whether the interval contains it.
+    ///
+    /// The result agrees element-wise with [Interval::contains], but avoids
+    /// moving each value; see [Contains::contains_each].
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::lcro_unchecked(0.0, 1.0);
+    ///
+    /// assert_eq!(x.contains_slice(&[-1.0, 0.0, 0.5, 1.0, f64::NAN]), vec![false, true, true, false, false]);
+    /// ```
+    pub fn contains_slice(&self, values: &[L::Value]) -> Vec<bool>
+    where
+        Self: Contains<L, R>,
+    {
+        let mut out = vec![false; values.len()];
+
+        Contains::<L, R>::contains_each(self, values, &mut out);
+
+        out
+    }
+
+    /// Writes into `out`, for each of `values`, whether the interval contains
+    /// it; see [Interval::contains_slice].
+    ///
+    /// # Panics
+    /// If `out` and `values` differ in length.
+    pub fn contains_slice_into(&self, values: &[L::Value], out: &mut [bool])
+    where
+        Self: Contains<L, R>,
+    {
+        assert_eq!(values.len(), out.len(), "Output buffer length must match the number of values.");
+
+        Contains::<L, R>::contains_each(self, values, out)
+    }
+
     /// Returns true if the interval is degenerate.
     ///
     /// A degenerate interval is bounded, where the upper and lower bounds are equal.
@@ -355,20 +2036,425 @@ where
             _ => false,
         }
     }
+
+    /// Returns true if the interval contains exactly one point.
+    ///
+    /// This is equivalent to [Interval::is_degenerate].
+    pub fn is_singleton(&self) -> bool { self.singleton_value().is_some() }
+
+    /// Returns a reference to the sole point of a degenerate interval.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::degenerate(1.0).singleton_value(), Some(&1.0));
+    /// assert_eq!(Interval::closed_unchecked(0.0, 1.0).singleton_value(), None);
+    /// assert_eq!(Interval::lcro_unchecked(1.0, 1.0).singleton_value(), None);
+    /// ```
+    pub fn singleton_value(&self) -> Option<&L::Value> {
+        match (self.left.value(), self.right.value()) {
+            (Some(left), Some(right)) if self.left.is_closed() && self.right.is_closed()
+                && left == right => Some(left),
+            _ => None,
+        }
+    }
+
+    /// Consumes a degenerate interval, returning its sole point, or returns
+    /// the interval unchanged otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(-1.0, 0.0);
+    /// let y = Interval::closed_unchecked(0.0, 1.0);
+    ///
+    /// let z = Interval::lcro_unchecked(0.0, 1.0);
+    ///
+    /// assert_eq!(x.intersect(y).unwrap().into_singleton(), Ok(0.0));
+    /// assert_eq!(x.intersect(z).unwrap().into_singleton(), Ok(0.0));
+    /// assert_eq!(x.into_singleton(), Err(x));
+    /// ```
+    pub fn into_singleton(self) -> std::result::Result<L::Value, Self> {
+        if self.is_singleton() {
+            Ok(self.left.into_value().expect("degenerate intervals are bounded"))
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Returns the width of the interval, or `None` if either side is
+    /// unbounded or the subtraction overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::closed_unchecked(-10i8, 10).checked_width(), Some(20));
+    /// assert_eq!(Interval::closed_unchecked(i8::MIN, i8::MAX).checked_width(), None);
+    /// assert_eq!(Interval::left_closed(0i8).checked_width(), None);
+    /// ```
+    pub fn checked_width(&self) -> Option<L::Value>
+    where
+        L::Value: CheckedSub,
+    {
+        self.left.value()
+            .zip(self.right.value())
+            .and_then(|(left, right)| right.checked_sub(left))
+    }
+
+    /// Returns the Lebesgue measure of the interval as an `f64`.
+    ///
+    /// This is the width for bounded intervals, computed in `f64` such that
+    /// integer types cannot overflow, [f64::INFINITY] if either side is
+    /// unbounded, and zero for degenerate and empty intervals. The result is
+    /// NaN if a bound value is NaN or has no `f64` representation.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::closed_unchecked(i8::MIN, i8::MAX).measure(), 255.0);
+    /// assert_eq!(Interval::open_unchecked(0.5, 0.5).measure(), 0.0);
+    /// assert_eq!(Interval::left_open(0u32).measure(), f64::INFINITY);
+    /// ```
+    pub fn measure(&self) -> f64
+    where
+        L::Value: ToPrimitive,
+    {
+        match (self.left.value(), self.right.value()) {
+            (Some(left), Some(right)) => match (left.to_f64(), right.to_f64()) {
+                (Some(left), Some(right)) if right < left => 0.0,
+                (Some(left), Some(right)) => right - left,
+                _ => f64::NAN,
+            },
+            _ => f64::INFINITY,
+        }
+    }
+
+    /// Returns the midpoint of the interval, or `None` if either side is
+    /// unbounded.
+    ///
+    /// The midpoint is computed as `left + (right - left) / 2`, without
+    /// overflow for integers, whose midpoints are rounded towards `left`.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::closed_unchecked(0.0, 1.0).midpoint(), Some(0.5));
+    /// assert_eq!(Interval::lcro_unchecked(250u8, 255).midpoint(), Some(252));
+    /// assert_eq!(Interval::closed_unchecked(i8::MIN, i8::MAX).midpoint(), Some(-1));
+    /// assert_eq!(Interval::left_open(0.0).midpoint(), None);
+    /// ```
+    pub fn midpoint(&self) -> Option<L::Value>
+    where
+        L::Value: SplitValue,
+    {
+        self.left.value().zip(self.right.value()).map(|(left, right)| L::Value::midpoint_between(left, right))
+    }
+
+    /// Returns the position of `val` relative to the bounds, computed as
+    /// `(val - left) / (right - left)`, or `None` if the interval is
+    /// unbounded or degenerate.
+    ///
+    /// This is the inverse of [Interval::lerp]: the bound values map to 0 and
+    /// 1, and values outside the interval map outside `[0, 1]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(10.0, 20.0);
+    ///
+    /// assert_eq!(x.relative_position(&12.5), Some(0.25));
+    /// assert_eq!(x.relative_position(&25.0), Some(1.5));
+    /// assert_eq!(Interval::degenerate(1.0).relative_position(&1.0), None);
+    /// ```
+    pub fn relative_position(&self, val: &L::Value) -> Option<L::Value>
+    where
+        L::Value: Clone + std::ops::Sub<Output = L::Value> + std::ops::Div<Output = L::Value>,
+    {
+        self.left.value()
+            .zip(self.right.value())
+            .filter(|(left, right)| left != right)
+            .map(|(left, right)| (val.clone() - left.clone()) / (right.clone() - left.clone()))
+    }
+
+    /// Map `val` from the interval onto `other`, preserving its relative
+    /// position; see [Interval::relative_position] and [Interval::lerp].
+    ///
+    /// Returns `None` if the interval is unbounded or degenerate.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let celsius = Interval::closed_unchecked(0.0, 100.0);
+    /// let fahrenheit = Interval::closed_unchecked(32.0, 212.0);
+    ///
+    /// assert_eq!(celsius.rescale(&fahrenheit, &37.0), Some(98.6));
+    /// assert_eq!(fahrenheit.rescale(&celsius, &-40.0), Some(-40.0));
+    /// ```
+    pub fn rescale<LL, RR>(&self, other: &Interval<LL, RR>, val: &L::Value) -> Option<L::Value>
+    where
+        LL: bounds::ProperBound<Value = L::Value>,
+        RR: bounds::ProperBound<Value = L::Value>,
+
+        L::Value: Clone
+            + std::ops::Add<Output = L::Value>
+            + std::ops::Sub<Output = L::Value>
+            + std::ops::Mul<Output = L::Value>
+            + std::ops::Div<Output = L::Value>,
+    {
+        self.relative_position(val).map(|t| other.lerp(t))
+    }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: bounds::ProperBound,
+    R: bounds::ProperBound<Value = L::Value>,
+
+    L::Value: Clone,
+{
+    /// Linearly interpolate between the bounds, returning
+    /// `left + t * (right - left)`.
+    ///
+    /// A parameter `t` in `[0, 1]` maps onto the closure of the interval,
+    /// with `t = 0` and `t = 1` giving the left and right bound values
+    /// regardless of their openness. Values of `t` outside `[0, 1]`
+    /// extrapolate beyond the bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(10.0, 20.0);
+    ///
+    /// assert_eq!(x.lerp(0.25), 12.5);
+    /// assert_eq!(x.lerp(1.5), 25.0);
+    /// assert_eq!(Interval::unit().lerp(0.3), 0.3);
+    /// ```
+    pub fn lerp(&self, t: L::Value) -> L::Value
+    where
+        L::Value: std::ops::Add<Output = L::Value>
+            + std::ops::Sub<Output = L::Value>
+            + std::ops::Mul<Output = L::Value>,
+    {
+        let (left, right) = (self.left.proper_value(), self.right.proper_value());
+
+        left.clone() + t * (right.clone() - left.clone())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Formatting
+///////////////////////////////////////////////////////////////////////////////
+/// Formats an interval in bracket notation, including degenerate intervals.
+///
+/// See [Interval::display_bracketed].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayBracketed<'a, L: bounds::Bound, R: bounds::Bound<Value = L::Value>>(
+    &'a Interval<L, R>
+);
+
+impl<L, R> Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+{
+    /// Returns a wrapper that always formats the interval in bracket
+    /// notation.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::degenerate(3);
+    ///
+    /// assert_eq!(x.to_string(), "{3}");
+    /// assert_eq!(x.display_bracketed().to_string(), "[3, 3]");
+    /// ```
+    pub fn display_bracketed(&self) -> DisplayBracketed<'_, L, R> { DisplayBracketed(self) }
+}
+
+impl<L, R> std::fmt::Display for DisplayBracketed<'_, L, R>
+where
+    L: bounds::BoundDisplay,
+    R: bounds::BoundDisplay<Value = L::Value>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.left.fmt_left(f)
+            .and_then(|_| write!(f, ", "))
+            .and_then(|_| self.0.right.fmt_right(f))
+    }
+}
+
+/// Symbols used to format intervals; see [Interval::display_with].
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::{Interval, Notation};
+/// let notation = Notation { separator: "; ", ..Notation::ASCII };
+///
+/// assert_eq!(Interval::left_closed(0).display_with(notation).to_string(), "[0; inf)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Notation {
+    /// Opening bracket of an open (or absent) left bound.
+    pub left_open: &'static str,
+
+    /// Opening bracket of a closed left bound.
+    pub left_closed: &'static str,
+
+    /// Closing bracket of an open (or absent) right bound.
+    pub right_open: &'static str,
+
+    /// Closing bracket of a closed right bound.
+    pub right_closed: &'static str,
+
+    /// Symbol for infinity; negative infinity is prefixed with `-`.
+    pub infinity: &'static str,
+
+    /// Separator between the left and right bounds.
+    pub separator: &'static str,
+}
+
+impl Notation {
+    /// The notation of `Display`, e.g. `[0, ∞)`.
+    pub const STANDARD: Notation = Notation {
+        left_open: "(",
+        left_closed: "[",
+        right_open: ")",
+        right_closed: "]",
+        infinity: "\u{221E}",
+        separator: ", ",
+    };
+
+    /// Reversed-bracket notation, e.g. `[0, ∞[`; as `Display` with `{:#}`.
+    pub const EUROPEAN: Notation = Notation {
+        left_open: "]",
+        right_open: "[",
+        ..Notation::STANDARD
+    };
+
+    /// Plain ASCII notation, e.g. `[0, inf)`.
+    pub const ASCII: Notation = Notation { infinity: "inf", ..Notation::STANDARD };
+
+    /// LaTeX math notation, e.g. `\left[0, \infty\right)`.
+    pub const LATEX: Notation = Notation {
+        left_open: "\\left(",
+        left_closed: "\\left[",
+        right_open: "\\right)",
+        right_closed: "\\right]",
+        infinity: "\\infty",
+        separator: ", ",
+    };
+}
+
+impl Default for Notation {
+    fn default() -> Notation { Notation::STANDARD }
+}
+
+/// Formats an interval in bracket notation with the given [Notation].
+///
+/// See [Interval::display_with].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayWith<'a, L: bounds::Bound, R: bounds::Bound<Value = L::Value>> {
+    interval: &'a Interval<L, R>,
+    notation: Notation,
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+{
+    /// Returns a wrapper that formats the interval in bracket notation using
+    /// the symbols of `notation`.
+    ///
+    /// Degenerate intervals are written with brackets, as in
+    /// [Interval::display_bracketed], and the formatter flags apply to the
+    /// bound values as they do for `Display`.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, Notation};
+    /// let x = Interval::right_closed(3);
+    ///
+    /// assert_eq!(x.display_with(Notation::ASCII).to_string(), "(-inf, 3]");
+    /// assert_eq!(x.display_with(Notation::EUROPEAN).to_string(), "]-\u{221E}, 3]");
+    /// ```
+    pub fn display_with(&self, notation: Notation) -> DisplayWith<'_, L, R> {
+        DisplayWith { interval: self, notation, }
+    }
+
+    /// Returns a wrapper that formats the interval in LaTeX math notation.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert_eq!(Interval::lcro_unchecked(0, 1).display_latex().to_string(), r"\left[0, 1\right)");
+    /// assert_eq!(Interval::right_closed(3).display_latex().to_string(), r"\left(-\infty, 3\right]");
+    /// ```
+    pub fn display_latex(&self) -> DisplayWith<'_, L, R> { self.display_with(Notation::LATEX) }
+}
+
+impl<L, R> std::fmt::Display for DisplayWith<'_, L, R>
+where
+    L: bounds::BoundDisplay,
+    R: bounds::BoundDisplay<Value = L::Value>,
+
+    L::Value: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let notation = &self.notation;
+
+        self.interval.left.fmt_left_with(f, notation)
+            .and_then(|_| f.write_str(notation.separator))
+            .and_then(|_| self.interval.right.fmt_right_with(f, notation))
+    }
 }
 
-///////////////////////////////////////////////////////////////////////////////
-// Formatting
-///////////////////////////////////////////////////////////////////////////////
+/// Formats the interval in bracket notation, or as a singleton set `{v}` if
+/// the interval is degenerate.
+///
+/// The alternate flag selects the reversed-bracket (European) notation for
+/// open and unbounded sides, while precision, width, fill and alignment are
+/// applied to each bound value.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::Interval;
+/// let x = Interval::lcro_unchecked(0.0, 1.0);
+///
+/// assert_eq!(format!("{}", x), "[0, 1)");
+/// assert_eq!(format!("{:#}", x), "[0, 1[");
+/// assert_eq!(format!("{:#}", Interval::left_open(0)), "]0, \u{221E}[");
+/// assert_eq!(format!("{:.2}", x), "[0.00, 1.00)");
+/// ```
 impl<L, R> std::fmt::Display for Interval<L, R>
 where
     L: bounds::BoundDisplay,
     R: bounds::BoundDisplay<Value = L::Value>,
+
+    L::Value: std::fmt::Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.left.fmt_left(f)
-            .and_then(|_| write!(f, ", "))
-            .and_then(|_| self.right.fmt_right(f))
+        match self.singleton_value() {
+            Some(value) => {
+                f.write_str("{")?;
+                std::fmt::Display::fmt(value, f)?;
+                f.write_str("}")
+            },
+            None => self.display_bracketed().fmt(f),
+        }
     }
 }
 
@@ -403,6 +2489,533 @@ impl_bounded!(bounds::Open<V>, bounds::Open<V>);
 ///////////////////////////////////////////////////////////////////////////////
 // Containment
 ///////////////////////////////////////////////////////////////////////////////
+/// Position of a value relative to an interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum Position {
+    /// The value lies strictly below the left bound.
+    Below,
+
+    /// The value equals the value of an open left bound.
+    OnLeftBoundary,
+
+    /// The value is contained in the interval.
+    Inside,
+
+    /// The value equals the value of an open right bound.
+    OnRightBoundary,
+
+    /// The value lies strictly above the right bound.
+    Above,
+
+    /// The value is incomparable with one of the bounds (e.g. NaN).
+    Incomparable,
+}
+
+/// Relation between two intervals in Allen's interval algebra.
+///
+/// Each variant reads as "`self` ... `other`", e.g. [Relation::During] means
+/// that `self` lies strictly inside `other`; see [Interval::relate].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum Relation {
+    /// The interval ends before the other starts, with a gap between them.
+    Before,
+
+    /// The interval ends exactly where the other starts, without overlap.
+    Meets,
+
+    /// The interval starts first and ends inside the other.
+    Overlaps,
+
+    /// The two start together, and the interval ends first.
+    Starts,
+
+    /// The interval lies inside the other, sharing neither end.
+    During,
+
+    /// The two end together, and the interval starts last.
+    Finishes,
+
+    /// The two intervals are equal.
+    Equals,
+
+    /// The two end together, and the interval starts first.
+    FinishedBy,
+
+    /// The interval contains the other, sharing neither end.
+    Contains,
+
+    /// The two start together, and the interval ends last.
+    StartedBy,
+
+    /// The interval starts inside the other and ends last.
+    OverlappedBy,
+
+    /// The interval starts exactly where the other ends, without overlap.
+    MetBy,
+
+    /// The interval starts after the other ends, with a gap between them.
+    After,
+
+    /// A bound of one interval is incomparable with a bound of the other.
+    Incomparable,
+}
+
+impl Relation {
+    /// Returns the relation of the other interval to the first, such that
+    /// `x.relate(&y).inverse() == y.relate(&x)`.
+    pub fn inverse(self) -> Relation {
+        match self {
+            Relation::Before => Relation::After,
+            Relation::Meets => Relation::MetBy,
+            Relation::Overlaps => Relation::OverlappedBy,
+            Relation::Starts => Relation::StartedBy,
+            Relation::During => Relation::Contains,
+            Relation::Finishes => Relation::FinishedBy,
+            Relation::Equals => Relation::Equals,
+            Relation::FinishedBy => Relation::Finishes,
+            Relation::Contains => Relation::During,
+            Relation::StartedBy => Relation::Starts,
+            Relation::OverlappedBy => Relation::Overlaps,
+            Relation::MetBy => Relation::Meets,
+            Relation::After => Relation::Before,
+            Relation::Incomparable => Relation::Incomparable,
+        }
+    }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+{
+    /// Classify the position of `val` relative to the interval.
+    ///
+    /// The result is [Position::Inside] if and only if the interval contains
+    /// `val`. Unbounded sides never produce [Position::Below] or
+    /// [Position::Above], and the boundary variants are only produced by open
+    /// bounds; a value equal to a closed bound is inside the interval.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, Position};
+    /// let x = Interval::lcro_unchecked(0.0, 1.0);
+    ///
+    /// assert_eq!(x.classify(&-1.0), Position::Below);
+    /// assert_eq!(x.classify(&0.0), Position::Inside);
+    /// assert_eq!(x.classify(&1.0), Position::OnRightBoundary);
+    /// assert_eq!(x.classify(&2.0), Position::Above);
+    /// assert_eq!(x.classify(&f64::NAN), Position::Incomparable);
+    ///
+    /// assert_eq!(Interval::left_closed(0.0).classify(&1e300), Position::Inside);
+    /// ```
+    pub fn classify(&self, val: &L::Value) -> Position {
+        use std::cmp::Ordering;
+
+        if let Some(left) = self.left.value() {
+            match val.partial_cmp(left) {
+                None => return Position::Incomparable,
+                Some(Ordering::Less) => return Position::Below,
+                Some(Ordering::Equal) if self.left.is_open() => return Position::OnLeftBoundary,
+                _ => {},
+            }
+        }
+
+        if let Some(right) = self.right.value() {
+            match val.partial_cmp(right) {
+                None => return Position::Incomparable,
+                Some(Ordering::Greater) => return Position::Above,
+                Some(Ordering::Equal) if self.right.is_open() => return Position::OnRightBoundary,
+                _ => {},
+            }
+        }
+
+        Position::Inside
+    }
+
+    /// Returns true if the interval contains every value of `other`.
+    ///
+    /// Bounds are compared with [bounds::cmp_bounds], so that shared endpoints
+    /// are resolved by their openness and unbounded sides extend to infinity.
+    /// Returns false if any pair of bound values is incomparable.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert!(Interval::closed_unchecked(0, 1).contains_interval(&Interval::open_unchecked(0, 1)));
+    /// assert!(!Interval::open_unchecked(0, 1).contains_interval(&Interval::closed_unchecked(0, 1)));
+    ///
+    /// assert!(Interval::left_closed(0).contains_interval(&Interval::lcro_unchecked(0, 100)));
+    /// assert!(!Interval::lcro_unchecked(0, 100).contains_interval(&Interval::left_closed(0)));
+    /// ```
+    pub fn contains_interval<LL, RR>(&self, other: &Interval<LL, RR>) -> bool
+    where
+        LL: bounds::Bound<Value = L::Value>,
+        RR: bounds::Bound<Value = L::Value>,
+    {
+        use bounds::{cmp_bounds, Side};
+        use std::cmp::Ordering;
+
+        let left = cmp_bounds(&self.left, Side::Left, &other.left, Side::Left);
+        let right = cmp_bounds(&self.right, Side::Right, &other.right, Side::Right);
+
+        matches!(left, Some(Ordering::Less) | Some(Ordering::Equal)) &&
+            matches!(right, Some(Ordering::Greater) | Some(Ordering::Equal))
+    }
+
+    /// Returns true if the interval contains `other`; an alias of
+    /// [Interval::contains_interval].
+    pub fn is_superset<LL, RR>(&self, other: &Interval<LL, RR>) -> bool
+    where
+        LL: bounds::Bound<Value = L::Value>,
+        RR: bounds::Bound<Value = L::Value>,
+    {
+        self.contains_interval(other)
+    }
+
+    /// Returns true if `other` contains the interval.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// assert!(Interval::lorc_unchecked(0.5, 1.0).is_subset(&Interval::unit()));
+    /// assert!(!Interval::right_closed(1.0).is_subset(&Interval::unit()));
+    /// ```
+    pub fn is_subset<LL, RR>(&self, other: &Interval<LL, RR>) -> bool
+    where
+        LL: bounds::Bound<Value = L::Value>,
+        RR: bounds::Bound<Value = L::Value>,
+    {
+        other.contains_interval(self)
+    }
+
+    /// Returns true if `other` contains the interval, and the two are not
+    /// equal as sets.
+    ///
+    /// Intervals are set-equal when their bounds agree in both value and
+    /// openness (or are both unbounded), regardless of the bound types used
+    /// to represent them.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let unit = Interval::closed_unchecked(0, 1);
+    ///
+    /// assert!(Interval::open_unchecked(0, 1).is_proper_subset(&unit));
+    /// assert!(!unit.is_proper_subset(&unit));
+    /// assert!(unit.is_subset(&unit));
+    /// ```
+    pub fn is_proper_subset<LL, RR>(&self, other: &Interval<LL, RR>) -> bool
+    where
+        LL: bounds::Bound<Value = L::Value>,
+        RR: bounds::Bound<Value = L::Value>,
+    {
+        other.contains_interval(self) && !self.contains_interval(other)
+    }
+
+    /// Returns true if the interval contains `other`, and the two are not
+    /// equal as sets; see [Interval::is_proper_subset].
+    pub fn is_proper_superset<LL, RR>(&self, other: &Interval<LL, RR>) -> bool
+    where
+        LL: bounds::Bound<Value = L::Value>,
+        RR: bounds::Bound<Value = L::Value>,
+    {
+        other.is_proper_subset(self)
+    }
+
+    /// Compare the interval with `other` under set inclusion.
+    ///
+    /// Returns `Less` if the interval is a proper subset of `other`, `Greater`
+    /// if it is a proper superset, `Equal` if the two are set-equal, and
+    /// `None` if neither contains the other (or their bounds are
+    /// incomparable). Unlike the lexicographic [PartialOrd] impl, this works
+    /// across bound types.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// use std::cmp::Ordering;
+    ///
+    /// let unit = Interval::closed_unchecked(0, 1);
+    ///
+    /// assert_eq!(Interval::open_unchecked(0, 1).partial_cmp_inclusion(&unit), Some(Ordering::Less));
+    /// assert_eq!(Interval::left_closed(0).partial_cmp_inclusion(&unit), Some(Ordering::Greater));
+    /// assert_eq!(unit.partial_cmp_inclusion(&Interval::closed_unchecked(0, 1)), Some(Ordering::Equal));
+    /// assert_eq!(unit.partial_cmp_inclusion(&Interval::closed_unchecked(1, 2)), None);
+    /// ```
+    pub fn partial_cmp_inclusion<LL, RR>(&self, other: &Interval<LL, RR>) -> Option<std::cmp::Ordering>
+    where
+        LL: bounds::Bound<Value = L::Value>,
+        RR: bounds::Bound<Value = L::Value>,
+    {
+        use std::cmp::Ordering;
+
+        match (self.contains_interval(other), other.contains_interval(self)) {
+            (true, true) => Some(Ordering::Equal),
+            (true, false) => Some(Ordering::Greater),
+            (false, true) => Some(Ordering::Less),
+            (false, false) => None,
+        }
+    }
+
+    /// Project `val` onto the interval, returning a reference to the nearest
+    /// endpoint if `val` lies outside it.
+    ///
+    /// The projection is onto the closure of the interval, so an open
+    /// endpoint is returned as-is even though the interval excludes it.
+    /// Unbounded sides never clamp, so this is the identity on an unbounded
+    /// interval. Values that are incomparable with the bounds (e.g. NaN) are
+    /// returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked("b".to_string(), "d".to_string());
+    /// let a = "a".to_string();
+    ///
+    /// assert_eq!(x.clamp_ref(&a), "b");
+    /// ```
+    pub fn clamp_ref<'a>(&'a self, val: &'a L::Value) -> &'a L::Value {
+        match (self.left.value(), self.right.value()) {
+            (Some(left), _) if val < left => left,
+            (_, Some(right)) if val > right => right,
+            _ => val,
+        }
+    }
+
+    /// Project `val` onto the interval, returning the nearest endpoint if
+    /// `val` lies outside it; see [Interval::clamp_ref].
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::closed_unchecked(0.0, 1.0);
+    ///
+    /// assert_eq!(x.clamp_value(-1.0), 0.0);
+    /// assert_eq!(x.clamp_value(0.5), 0.5);
+    /// assert_eq!(x.clamp_value(2.0), 1.0);
+    /// assert!(x.clamp_value(f64::NAN).is_nan());
+    ///
+    /// assert_eq!(Interval::lcro_unchecked(0, 10).clamp_value(20), 10);
+    /// assert_eq!(Interval::left_closed(0).clamp_value(20), 20);
+    /// ```
+    pub fn clamp_value(&self, val: L::Value) -> L::Value
+    where
+        L::Value: Clone,
+    {
+        match (self.left.value(), self.right.value()) {
+            (Some(left), _) if val < *left => left.clone(),
+            (_, Some(right)) if val > *right => right.clone(),
+            _ => val,
+        }
+    }
+
+    /// Classify the relation of the interval to `other` in Allen's interval
+    /// algebra.
+    ///
+    /// Openness is taken into account: two intervals that touch at a point
+    /// held by exactly one of them meet, while two that both hold it overlap
+    /// there, and two that both exclude it have a gap between them. Unbounded
+    /// sides are treated as the infinities, so that two intervals unbounded
+    /// on the same side share that end; e.g. `[0, ∞)` is finished by `[1, ∞)`
+    /// and contains `[1, 2]`. Both intervals are assumed to be non-empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{Interval, Relation};
+    /// let x = Interval::lcro_unchecked(0, 1);
+    ///
+    /// assert_eq!(x.relate(&Interval::closed_unchecked(1, 2)), Relation::Meets);
+    /// assert_eq!(x.relate(&Interval::open_unchecked(1, 2)), Relation::Before);
+    /// assert_eq!(x.relate(&Interval::closed_unchecked(0, 2)), Relation::Starts);
+    ///
+    /// let y = Interval::closed_unchecked(0, 1);
+    ///
+    /// assert_eq!(y.relate(&Interval::closed_unchecked(1, 2)), Relation::Overlaps);
+    /// assert_eq!(y.relate(&Interval::left_closed(0)), Relation::Starts);
+    /// assert_eq!(Interval::left_closed(-1).relate(&y), Relation::Contains);
+    /// ```
+    pub fn relate<LL, RR>(&self, other: &Interval<LL, RR>) -> Relation
+    where
+        LL: bounds::Bound<Value = L::Value>,
+        RR: bounds::Bound<Value = L::Value>,
+    {
+        use bounds::{cmp_bounds, Side};
+        use std::cmp::Ordering::{Equal, Greater, Less};
+
+        // Two intervals that are apart meet if exactly one holds the point
+        // between them.
+        fn meets<A, B>(right: &A, left: &B) -> bool
+        where
+            A: bounds::Bound,
+            B: bounds::Bound<Value = A::Value>,
+        {
+            right.value() == left.value() && (right.is_closed() || left.is_closed())
+        }
+
+        let starts = cmp_bounds(&self.left, Side::Left, &other.left, Side::Left);
+        let ends = cmp_bounds(&self.right, Side::Right, &other.right, Side::Right);
+
+        let before = cmp_bounds(&self.right, Side::Right, &other.left, Side::Left);
+        let after = cmp_bounds(&other.right, Side::Right, &self.left, Side::Left);
+
+        match (starts, ends, before, after) {
+            (Some(s), Some(e), Some(b), Some(a)) => match (s, e) {
+                _ if b == Less && meets(&self.right, &other.left) => Relation::Meets,
+                _ if b == Less => Relation::Before,
+                _ if a == Less && meets(&other.right, &self.left) => Relation::MetBy,
+                _ if a == Less => Relation::After,
+
+                (Less, Less) => Relation::Overlaps,
+                (Less, Equal) => Relation::FinishedBy,
+                (Less, Greater) => Relation::Contains,
+                (Equal, Less) => Relation::Starts,
+                (Equal, Equal) => Relation::Equals,
+                (Equal, Greater) => Relation::StartedBy,
+                (Greater, Less) => Relation::During,
+                (Greater, Equal) => Relation::Finishes,
+                (Greater, Greater) => Relation::OverlappedBy,
+            },
+            _ => Relation::Incomparable,
+        }
+    }
+}
+
+// Returns true if `hi - lo <= eps`, for `lo < hi` and non-negative `eps`,
+// without the subtraction overflowing for integer types.
+fn gap_within<V: num_traits::Num + PartialOrd + Clone>(lo: &V, hi: &V, eps: &V) -> bool {
+    let zero = V::zero();
+
+    // The difference only overflows across zero, where the sum can't.
+    if *lo < zero && *hi > zero {
+        *hi <= lo.clone() + eps.clone()
+    } else {
+        hi.clone() - lo.clone() <= *eps
+    }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: num_traits::Num + Clone,
+{
+    /// Returns true if `val` lies within `eps` of the interval.
+    ///
+    /// Each bounded side is moved outwards by `eps` and treated as closed,
+    /// i.e. this is the containment test for the closure of the interval
+    /// expanded by `eps`. In particular, with `eps` equal to zero this differs
+    /// from [Interval::contains] only at the values of open bounds, which are
+    /// accepted: a value at an open bound is within any tolerance of it.
+    ///
+    /// __Note__: returns false if `val` is NaN, or if `eps` is NaN or
+    /// negative, including for unbounded intervals.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::lcro_unchecked(0.0, 1.0);
+    ///
+    /// assert!(!x.contains(1.0 + 1e-12));
+    /// assert!(x.contains_approx(1.0 + 1e-12, 1e-9));
+    /// assert!(!x.contains_approx(1.1, 1e-9));
+    ///
+    /// assert!(!x.contains(1.0));
+    /// assert!(x.contains_approx(1.0, 0.0));
+    /// ```
+    pub fn contains_approx(&self, val: L::Value, eps: L::Value) -> bool {
+        match (eps.partial_cmp(&L::Value::zero()), val.partial_cmp(&val)) {
+            (None, _) | (_, None) | (Some(std::cmp::Ordering::Less), _) => return false,
+            _ => {},
+        }
+
+        // Only the distance to a bound that val lies beyond is measured.
+        let above_left = match self.left.value().map(|l| (l, val.partial_cmp(l))) {
+            Some((l, Some(std::cmp::Ordering::Less))) => gap_within(&val, l, &eps),
+            Some((_, None)) => false,
+            _ => true,
+        };
+        let below_right = match self.right.value().map(|r| (r, val.partial_cmp(r))) {
+            Some((r, Some(std::cmp::Ordering::Greater))) => gap_within(r, &val, &eps),
+            Some((_, None)) => false,
+            _ => true,
+        };
+
+        above_left && below_right
+    }
+
+    /// Returns true if the gap between the intervals is at most `eps`.
+    ///
+    /// The gap is the distance between the right end of one interval and the
+    /// left end of the other, or zero if they overlap; the openness of the
+    /// bounds plays no part. With `eps` equal to zero this is therefore true
+    /// for intervals that overlap or are adjacent, e.g. `[0, 1)` and `(1, 2]`,
+    /// even though the two share no point. The relation is symmetric.
+    ///
+    /// __Note__: returns false if `eps` is NaN or negative, or if any of the
+    /// compared endpoints are NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::Interval;
+    /// let x = Interval::open_unchecked(0.0, 1.0);
+    ///
+    /// assert!(x.overlaps_within(&Interval::open_unchecked(1.0, 2.0), 0.0));
+    /// assert!(x.overlaps_within(&Interval::open_unchecked(1.5, 2.0), 0.5));
+    /// assert!(!x.overlaps_within(&Interval::open_unchecked(1.5, 2.0), 0.25));
+    ///
+    /// assert!(x.overlaps_within(&Interval::left_open(1.5), 0.5));
+    /// ```
+    pub fn overlaps_within<LL, RR>(&self, other: &Interval<LL, RR>, eps: L::Value) -> bool
+    where
+        LL: bounds::Bound<Value = L::Value>,
+        RR: bounds::Bound<Value = L::Value>,
+    {
+        fn reaches<V: num_traits::Num + PartialOrd + Clone>(
+            left: Option<&V>,
+            right: Option<&V>,
+            eps: V,
+        ) -> bool {
+            match (left, right) {
+                (Some(l), Some(r)) => match l.partial_cmp(r) {
+                    Some(std::cmp::Ordering::Greater) => gap_within(r, l, &eps),
+                    Some(_) => true,
+                    None => false,
+                },
+                _ => true,
+            }
+        }
+
+        match eps.partial_cmp(&L::Value::zero()) {
+            None | Some(std::cmp::Ordering::Less) => return false,
+            _ => {},
+        }
+
+        reaches(other.left.value(), self.right.value(), eps.clone()) &&
+            reaches(self.left.value(), other.right.value(), eps)
+    }
+}
+
 /// Trait for intervals which can assert containment of their values.
 pub trait Contains<L: bounds::Bound, R: bounds::Bound<Value = L::Value>> {
     /// Returns true if the interval contains `val`.
@@ -415,68 +3028,139 @@ pub trait Contains<L: bounds::Bound, R: bounds::Bound<Value = L::Value>> {
     /// assert!(Interval::unit().contains(0.5));
     /// assert!(Interval::unit().contains(1.0));
     /// ```
-    fn contains(&self, val: L::Value) -> bool;
+    fn contains(&self, val: L::Value) -> bool { self.contains_ref(&val) }
+
+    /// Returns true if the interval contains the value behind `val`.
+    ///
+    /// Unlike [Contains::contains], this needn't take ownership of the value,
+    /// so no clone is required for non-`Copy` types.
+    ///
+    /// # Examples
+    /// ```
+    /// # use intervals::{Contains, Interval};
+    /// let x = Interval::lcro_unchecked(String::from("apple"), String::from("banana"));
+    ///
+    /// assert!(x.contains_ref(&String::from("avocado")));
+    /// assert!(!x.contains_ref(&String::from("banana")));
+    ///
+    /// let y = Interval::closed_unchecked("a", "m");
+    ///
+    /// assert!(y.contains_ref(&"kiwi"));
+    /// assert!(!y.contains_ref(&"orange"));
+    /// ```
+    fn contains_ref(&self, val: &L::Value) -> bool;
+
+    /// Writes into `out` whether the interval contains each of `values`.
+    ///
+    /// The default implementation calls [Contains::contains_ref] on each
+    /// value; intervals with fixed bound types override it with a loop that
+    /// the compiler can vectorise.
+    fn contains_each(&self, values: &[L::Value], out: &mut [bool]) {
+        for (o, v) in out.iter_mut().zip(values) { *o = self.contains_ref(v); }
+    }
+}
+
+// Writes `test(v)` into `out` for each `v` in `values`.
+#[inline(always)]
+fn fill_each<V, F: Fn(&V) -> bool>(values: &[V], out: &mut [bool], test: F) {
+    for (o, v) in out.iter_mut().zip(values) { *o = test(v); }
 }
 
 impl<V: PartialOrd> Contains<bounds::NoBound<V>, bounds::NoBound<V>> for Unbounded<V> {
-    fn contains(&self, _: V) -> bool { true }
+    fn contains_ref(&self, _: &V) -> bool { true }
+
+    fn contains_each(&self, _: &[V], out: &mut [bool]) {
+        out.iter_mut().for_each(|o| *o = true);
+    }
 }
 
 impl<V: PartialOrd> Contains<bounds::Open<V>, bounds::Open<V>> for Open<V> {
-    fn contains(&self, val: V) -> bool {
-        val > self.left.0 && val < self.right.0
+    fn contains_ref(&self, val: &V) -> bool {
+        *val > self.left.0 && *val < self.right.0
+    }
+
+    fn contains_each(&self, values: &[V], out: &mut [bool]) {
+        fill_each(values, out, |v| (*v > self.left.0) & (*v < self.right.0));
     }
 }
 
 impl<V: PartialOrd> Contains<bounds::Open<V>, bounds::NoBound<V>> for LeftOpen<V> {
-    fn contains(&self, val: V) -> bool {
-        val > self.left.0
+    fn contains_ref(&self, val: &V) -> bool {
+        *val > self.left.0
+    }
+
+    fn contains_each(&self, values: &[V], out: &mut [bool]) {
+        fill_each(values, out, |v| *v > self.left.0);
     }
 }
 
 impl<V: PartialOrd> Contains<bounds::NoBound<V>, bounds::Open<V>> for RightOpen<V> {
-    fn contains(&self, val: V) -> bool {
-        val < self.right.0
+    fn contains_ref(&self, val: &V) -> bool {
+        *val < self.right.0
+    }
+
+    fn contains_each(&self, values: &[V], out: &mut [bool]) {
+        fill_each(values, out, |v| *v < self.right.0);
     }
 }
 
 impl<V: PartialOrd> Contains<bounds::Closed<V>, bounds::Closed<V>> for Closed<V> {
-    fn contains(&self, val: V) -> bool {
-        val >= self.left.0 && val <= self.right.0
+    fn contains_ref(&self, val: &V) -> bool {
+        *val >= self.left.0 && *val <= self.right.0
+    }
+
+    fn contains_each(&self, values: &[V], out: &mut [bool]) {
+        fill_each(values, out, |v| (*v >= self.left.0) & (*v <= self.right.0));
     }
 }
 
 impl<V: PartialOrd> Contains<bounds::Closed<V>, bounds::NoBound<V>> for LeftClosed<V> {
-    fn contains(&self, val: V) -> bool {
-        val >= self.left.0
+    fn contains_ref(&self, val: &V) -> bool {
+        *val >= self.left.0
+    }
+
+    fn contains_each(&self, values: &[V], out: &mut [bool]) {
+        fill_each(values, out, |v| *v >= self.left.0);
     }
 }
 
-impl<V: PartialOrd> Contains<bounds::NoBound<V>, bounds::Closed<V>> for Closed<V> {
-    fn contains(&self, val: V) -> bool {
-        val <= self.right.0
+impl<V: PartialOrd> Contains<bounds::NoBound<V>, bounds::Closed<V>> for RightClosed<V> {
+    fn contains_ref(&self, val: &V) -> bool {
+        *val <= self.right.0
+    }
+
+    fn contains_each(&self, values: &[V], out: &mut [bool]) {
+        fill_each(values, out, |v| *v <= self.right.0);
     }
 }
 
 impl<V: PartialOrd> Contains<bounds::Closed<V>, bounds::Open<V>> for LCRO<V> {
-    fn contains(&self, val: V) -> bool {
-        val >= self.left.0 && val < self.right.0
+    fn contains_ref(&self, val: &V) -> bool {
+        *val >= self.left.0 && *val < self.right.0
+    }
+
+    fn contains_each(&self, values: &[V], out: &mut [bool]) {
+        fill_each(values, out, |v| (*v >= self.left.0) & (*v < self.right.0));
     }
 }
 
 impl<V: PartialOrd> Contains<bounds::Open<V>, bounds::Closed<V>> for LORC<V> {
-    fn contains(&self, val: V) -> bool {
-        val > self.left.0 && val <= self.right.0
+    fn contains_ref(&self, val: &V) -> bool {
+        *val > self.left.0 && *val <= self.right.0
+    }
+
+    fn contains_each(&self, values: &[V], out: &mut [bool]) {
+        fill_each(values, out, |v| (*v > self.left.0) & (*v <= self.right.0));
     }
 }
 
 impl<V: PartialOrd> Contains<bounds::NoBound<V>, bounds::OpenOrClosed<V>> for Interval<
     bounds::NoBound<V>, bounds::OpenOrClosed<V>
 > {
-    fn contains(&self, val: V) -> bool {
+    fn contains_ref(&self, val: &V) -> bool {
         match self.right {
-            bounds::OpenOrClosed::Open(ref r) => val < *r,
-            bounds::OpenOrClosed::Closed(ref r) => val <= *r,
+            bounds::OpenOrClosed::Open(ref r) => *val < *r,
+            bounds::OpenOrClosed::Closed(ref r) => *val <= *r,
         }
     }
 }
@@ -484,10 +3168,10 @@ impl<V: PartialOrd> Contains<bounds::NoBound<V>, bounds::OpenOrClosed<V>> for In
 impl<V: PartialOrd> Contains<bounds::Open<V>, bounds::OpenOrClosed<V>> for Interval<
     bounds::Open<V>, bounds::OpenOrClosed<V>
 > {
-    fn contains(&self, val: V) -> bool {
-        val > self.left.0 && match &self.right {
-            bounds::OpenOrClosed::Open(ref r) => val > *r,
-            bounds::OpenOrClosed::Closed(ref r) => val <= *r,
+    fn contains_ref(&self, val: &V) -> bool {
+        *val > self.left.0 && match &self.right {
+            bounds::OpenOrClosed::Open(ref r) => *val < *r,
+            bounds::OpenOrClosed::Closed(ref r) => *val <= *r,
         }
     }
 }
@@ -495,10 +3179,10 @@ impl<V: PartialOrd> Contains<bounds::Open<V>, bounds::OpenOrClosed<V>> for Inter
 impl<V: PartialOrd> Contains<bounds::Closed<V>, bounds::OpenOrClosed<V>> for Interval<
     bounds::Closed<V>, bounds::OpenOrClosed<V>
 > {
-    fn contains(&self, val: V) -> bool {
-        val >= self.left.0 && match &self.right {
-            bounds::OpenOrClosed::Open(ref r) => val > *r,
-            bounds::OpenOrClosed::Closed(ref r) => val <= *r,
+    fn contains_ref(&self, val: &V) -> bool {
+        *val >= self.left.0 && match &self.right {
+            bounds::OpenOrClosed::Open(ref r) => *val < *r,
+            bounds::OpenOrClosed::Closed(ref r) => *val <= *r,
         }
     }
 }
@@ -506,10 +3190,10 @@ impl<V: PartialOrd> Contains<bounds::Closed<V>, bounds::OpenOrClosed<V>> for Int
 impl<V: PartialOrd> Contains<bounds::OpenOrClosed<V>, bounds::NoBound<V>> for Interval<
     bounds::OpenOrClosed<V>, bounds::NoBound<V>
 > {
-    fn contains(&self, val: V) -> bool {
+    fn contains_ref(&self, val: &V) -> bool {
         match self.left {
-            bounds::OpenOrClosed::Open(ref l) => val > *l,
-            bounds::OpenOrClosed::Closed(ref l) => val >= *l,
+            bounds::OpenOrClosed::Open(ref l) => *val > *l,
+            bounds::OpenOrClosed::Closed(ref l) => *val >= *l,
         }
     }
 }
@@ -517,10 +3201,10 @@ impl<V: PartialOrd> Contains<bounds::OpenOrClosed<V>, bounds::NoBound<V>> for In
 impl<V: PartialOrd> Contains<bounds::OpenOrClosed<V>, bounds::Open<V>> for Interval<
     bounds::OpenOrClosed<V>, bounds::Open<V>
 > {
-    fn contains(&self, val: V) -> bool {
-        val < self.right.0 && match self.left {
-            bounds::OpenOrClosed::Open(ref l) => val > *l,
-            bounds::OpenOrClosed::Closed(ref l) => val >= *l,
+    fn contains_ref(&self, val: &V) -> bool {
+        *val < self.right.0 && match self.left {
+            bounds::OpenOrClosed::Open(ref l) => *val > *l,
+            bounds::OpenOrClosed::Closed(ref l) => *val >= *l,
         }
     }
 }
@@ -528,10 +3212,24 @@ impl<V: PartialOrd> Contains<bounds::OpenOrClosed<V>, bounds::Open<V>> for Inter
 impl<V: PartialOrd> Contains<bounds::OpenOrClosed<V>, bounds::Closed<V>> for Interval<
     bounds::OpenOrClosed<V>, bounds::Closed<V>
 > {
-    fn contains(&self, val: V) -> bool {
-        val <= self.right.0 && match self.left {
-            bounds::OpenOrClosed::Open(ref l) => val > *l,
-            bounds::OpenOrClosed::Closed(ref l) => val >= *l,
+    fn contains_ref(&self, val: &V) -> bool {
+        *val <= self.right.0 && match self.left {
+            bounds::OpenOrClosed::Open(ref l) => *val > *l,
+            bounds::OpenOrClosed::Closed(ref l) => *val >= *l,
+        }
+    }
+}
+
+impl<V: PartialOrd> Contains<bounds::OpenOrClosed<V>, bounds::OpenOrClosed<V>> for Interval<
+    bounds::OpenOrClosed<V>, bounds::OpenOrClosed<V>
+> {
+    fn contains_ref(&self, val: &V) -> bool {
+        (match self.left {
+            bounds::OpenOrClosed::Open(ref l) => *val > *l,
+            bounds::OpenOrClosed::Closed(ref l) => *val >= *l,
+        }) && match self.right {
+            bounds::OpenOrClosed::Open(ref r) => *val < *r,
+            bounds::OpenOrClosed::Closed(ref r) => *val <= *r,
         }
     }
 }
@@ -0,0 +1,230 @@
+//! Module containing operations over collections of intervals.
+use crate::{Interval, Position, bounds::Bound, partitions::WidthValue};
+use std::{cmp::Ordering, ops::Range};
+
+type Width<V> = <V as WidthValue>::Width;
+
+// Returns the width of the interval, or `None` if either side is unbounded or
+// the width overflows; either way, the interval is wider than any other.
+fn width<L, R>(interval: &Interval<L, R>) -> Option<Width<L::Value>>
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+    L::Value: WidthValue,
+{
+    interval.left.value()
+        .zip(interval.right.value())
+        .and_then(|(left, right)| L::Value::checked_width_between(left, right))
+}
+
+// Returns true if the width can be ranked, i.e. it is comparable with itself.
+fn is_rankable<V: PartialOrd>(width: &V) -> bool { width.partial_cmp(width).is_some() }
+
+/// Returns the interval of least width.
+///
+/// Unbounded intervals are skipped, as are intervals whose width overflows
+/// the value type or is not comparable (e.g. NaN). Ties are resolved in favour
+/// of the first interval encountered, and each width is computed exactly once.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::{Interval, ops::narrowest};
+/// let cis = vec![
+///     Interval::closed_unchecked(0.0, 4.0),
+///     Interval::closed_unchecked(1.0, 2.0),
+///     Interval::closed_unchecked(1.5, 3.0),
+/// ];
+///
+/// assert_eq!(narrowest(cis), Some(Interval::closed_unchecked(1.0, 2.0)));
+/// ```
+pub fn narrowest<I, L, R>(intervals: I) -> Option<Interval<L, R>>
+where
+    I: IntoIterator<Item = Interval<L, R>>,
+    L: Bound,
+    R: Bound<Value = L::Value>,
+    L::Value: WidthValue,
+    Width<L::Value>: PartialOrd,
+{
+    let mut best = None;
+
+    for interval in intervals {
+        let w = match width(&interval) {
+            Some(w) if is_rankable(&w) => w,
+            _ => continue,
+        };
+
+        let is_narrower = match best {
+            Some((ref bw, _)) => w.partial_cmp(bw) == Some(Ordering::Less),
+            None => true,
+        };
+
+        if is_narrower { best = Some((w, interval)); }
+    }
+
+    best.map(|(_, interval)| interval)
+}
+
+/// Returns the interval of greatest width.
+///
+/// Unbounded intervals, and those whose width overflows the value type, are
+/// taken to be wider than any other, such that the first such interval
+/// encountered is returned if there is one.
+/// Intervals whose width is not comparable (e.g. NaN) are skipped. Ties are
+/// resolved in favour of the first interval encountered, and each width is
+/// computed exactly once.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::{Interval, ops::widest};
+/// let cis = vec![
+///     Interval::closed_unchecked(0.0, 4.0),
+///     Interval::closed_unchecked(1.0, 2.0),
+///     Interval::closed_unchecked(1.5, 3.0),
+/// ];
+///
+/// assert_eq!(widest(cis), Some(Interval::closed_unchecked(0.0, 4.0)));
+/// ```
+pub fn widest<I, L, R>(intervals: I) -> Option<Interval<L, R>>
+where
+    I: IntoIterator<Item = Interval<L, R>>,
+    L: Bound,
+    R: Bound<Value = L::Value>,
+    L::Value: WidthValue,
+    Width<L::Value>: PartialOrd,
+{
+    let mut best = None;
+
+    for interval in intervals {
+        let w = match width(&interval) {
+            Some(w) if is_rankable(&w) => w,
+            Some(_) => continue,
+            None => return Some(interval),
+        };
+
+        let is_wider = match best {
+            Some((ref bw, _)) => w.partial_cmp(bw) == Some(Ordering::Greater),
+            None => true,
+        };
+
+        if is_wider { best = Some((w, interval)); }
+    }
+
+    best.map(|(_, interval)| interval)
+}
+
+// Returns true if every point bounded above by `right` lies strictly below
+// every point bounded below by `left`.
+fn precedes<A, B>(right: &A, left: &B) -> bool
+where
+    A: Bound,
+    B: Bound<Value = A::Value>,
+    A::Value: PartialOrd,
+{
+    match (right.value(), left.value()) {
+        (Some(r), Some(l)) => match r.partial_cmp(l) {
+            Some(Ordering::Less) => true,
+            Some(Ordering::Equal) => right.is_open() || left.is_open(),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns true if the intervals are sorted and pairwise disjoint.
+///
+/// This is the precondition of [find_containing] and [find_overlapping]:
+/// each interval must lie strictly below its successor, so touching bounds
+/// are permitted only if at least one of them is open.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::{Interval, ops::is_sorted_disjoint};
+/// assert!(is_sorted_disjoint(&[Interval::lcro_unchecked(0, 1), Interval::lcro_unchecked(1, 2)]));
+/// assert!(!is_sorted_disjoint(&[Interval::closed_unchecked(0, 1), Interval::closed_unchecked(1, 2)]));
+/// ```
+pub fn is_sorted_disjoint<L, R>(sorted: &[Interval<L, R>]) -> bool
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+    L::Value: PartialOrd,
+{
+    sorted.windows(2).all(|w| precedes(&w[0].right, &w[1].left))
+}
+
+/// Returns the index of the interval containing `x`, if any.
+///
+/// The intervals must be sorted and disjoint (see [is_sorted_disjoint]);
+/// this is checked in debug builds only. The search is a binary search, so
+/// takes `O(log n)` comparisons, and the openness of each bound is honoured.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::{Interval, ops::find_containing};
+/// let bins = [
+///     Interval::lcro_unchecked(0.0, 1.0),
+///     Interval::lcro_unchecked(1.0, 2.0),
+///     Interval::lcro_unchecked(5.0, 6.0),
+/// ];
+///
+/// assert_eq!(find_containing(&bins, &1.0), Some(1));
+/// assert_eq!(find_containing(&bins, &2.0), None);
+/// assert_eq!(find_containing(&bins, &5.5), Some(2));
+/// ```
+pub fn find_containing<L, R>(sorted: &[Interval<L, R>], x: &L::Value) -> Option<usize>
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+    L::Value: PartialOrd,
+{
+    debug_assert!(is_sorted_disjoint(sorted), "Intervals must be sorted and disjoint.");
+
+    let k = sorted.partition_point(|interval| matches!(
+        interval.classify(x), Position::Above | Position::OnRightBoundary
+    ));
+
+    sorted.get(k).filter(|interval| interval.classify(x) == Position::Inside).map(|_| k)
+}
+
+/// Returns the range of indices of the intervals overlapping `query`.
+///
+/// Two intervals overlap if they share at least one point. The intervals
+/// must be sorted and disjoint (see [is_sorted_disjoint]); this is checked
+/// in debug builds only. The range is empty, and positioned where the query
+/// would be inserted, if no interval overlaps it.
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # use intervals::{Interval, ops::find_overlapping};
+/// let bins = [
+///     Interval::lcro_unchecked(0, 10),
+///     Interval::lcro_unchecked(10, 20),
+///     Interval::lcro_unchecked(30, 40),
+/// ];
+///
+/// assert_eq!(find_overlapping(&bins, &Interval::closed_unchecked(5, 30)), 0..3);
+/// assert_eq!(find_overlapping(&bins, &Interval::open_unchecked(10, 30)), 1..2);
+/// assert_eq!(find_overlapping(&bins, &Interval::closed_unchecked(20, 25)), 2..2);
+/// ```
+pub fn find_overlapping<L, R, LL, RR>(
+    sorted: &[Interval<L, R>],
+    query: &Interval<LL, RR>,
+) -> Range<usize>
+where
+    L: Bound,
+    R: Bound<Value = L::Value>,
+    LL: Bound<Value = L::Value>,
+    RR: Bound<Value = L::Value>,
+    L::Value: PartialOrd,
+{
+    debug_assert!(is_sorted_disjoint(sorted), "Intervals must be sorted and disjoint.");
+
+    let start = sorted.partition_point(|interval| precedes(&interval.right, &query.left));
+    let end = start + sorted[start..].partition_point(|interval| !precedes(&query.right, &interval.left));
+
+    start..end
+}
@@ -0,0 +1,170 @@
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, TimeZone, Timelike};
+use super::{EdgePolicy, OutOfRange, Partition, SubInterval, WidthValue};
+
+/// Calendar unit delimiting the cells of a [Calendar] partition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarUnit {
+    /// Cells aligned to the start of each local hour.
+    Hour,
+
+    /// Cells aligned to the start of each local day.
+    Day,
+
+    /// Cells aligned to the start of each local month.
+    Month,
+}
+
+/// Type representing a partition of a closed time interval into calendar
+/// units.
+///
+/// The interior edges of the partition are the unit boundaries, in the local
+/// time of the time zone, that lie strictly within the interval; the first and
+/// last cells may therefore be partial. Days and months start at the first
+/// instant of the local date (usually midnight), so cells track daylight
+/// saving transitions and month lengths, while hours are exactly 3600 seconds
+/// long and aligned to the local hour of the interval's left bound.
+///
+/// Cells follow [EdgePolicy::LeftClosed], and [Partition::index] is computed
+/// arithmetically from the local date and time of the value.
+///
+/// # Examples
+/// ```
+/// # extern crate chrono;
+/// # extern crate intervals;
+/// # use chrono::{TimeZone, Utc};
+/// # use intervals::{Interval, partitions::{Calendar, Partition}};
+/// let start = Utc.with_ymd_and_hms(2024, 1, 30, 12, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2024, 3, 2, 0, 0, 0).unwrap();
+/// let partition = Calendar::months(Interval::closed_unchecked(start, end));
+///
+/// assert_eq!(partition.len(), 3);
+/// assert_eq!(partition.index(&Utc.with_ymd_and_hms(2024, 2, 29, 23, 0, 0).unwrap()), Some(1));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calendar<Tz: TimeZone> {
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+    unit: CalendarUnit,
+    size: usize,
+}
+
+impl<Tz: TimeZone> Calendar<Tz> {
+    /// Construct a partition of `interval` into the given calendar unit.
+    pub fn new(interval: crate::Closed<DateTime<Tz>>, unit: CalendarUnit) -> Self {
+        let start = interval.left.0;
+        let end = interval.right.0;
+
+        let mut calendar = Calendar { start, end, unit, size: 1, };
+
+        let n = calendar.raw_index(&calendar.end).max(0) as usize;
+        let on_boundary = n > 0 && calendar.edge(n) == calendar.end;
+
+        calendar.size = if on_boundary { n } else { n + 1 };
+        calendar
+    }
+
+    /// Construct a partition of `interval` into local hours.
+    pub fn hours(interval: crate::Closed<DateTime<Tz>>) -> Self {
+        Calendar::new(interval, CalendarUnit::Hour)
+    }
+
+    /// Construct a partition of `interval` into local days.
+    pub fn days(interval: crate::Closed<DateTime<Tz>>) -> Self {
+        Calendar::new(interval, CalendarUnit::Day)
+    }
+
+    /// Construct a partition of `interval` into local months.
+    pub fn months(interval: crate::Closed<DateTime<Tz>>) -> Self {
+        Calendar::new(interval, CalendarUnit::Month)
+    }
+
+    /// Returns the calendar unit of the partition.
+    pub fn unit(&self) -> CalendarUnit { self.unit }
+
+    // Returns the start of the local hour containing the left bound.
+    fn hour_floor(&self) -> DateTime<Tz> {
+        let local = self.start.naive_local();
+        let elapsed = Duration::seconds(local.minute() as i64 * 60 + local.second() as i64)
+            + Duration::nanoseconds(local.nanosecond() as i64);
+
+        self.start.clone() - elapsed
+    }
+
+    // Returns the first instant of the local date, skipping any gap at midnight.
+    fn start_of_day(&self, date: NaiveDate) -> DateTime<Tz> {
+        let tz = self.start.timezone();
+
+        (0..24)
+            .filter_map(|h| date.and_hms_opt(h, 0, 0))
+            .find_map(|t| tz.from_local_datetime(&t).earliest())
+            .expect("Every local date has an hour that exists.")
+    }
+
+    // Returns the number of unit boundaries in (start, value], unclamped.
+    fn raw_index(&self, value: &DateTime<Tz>) -> i64 {
+        let value = value.with_timezone(&self.start.timezone());
+        let (d0, d1) = (self.start.date_naive(), value.date_naive());
+
+        match self.unit {
+            CalendarUnit::Hour => value.signed_duration_since(self.hour_floor()).num_seconds().div_euclid(3600),
+            CalendarUnit::Day => d1.signed_duration_since(d0).num_days(),
+            CalendarUnit::Month => {
+                let months = |d: NaiveDate| d.year() as i64 * 12 + d.month0() as i64;
+
+                months(d1) - months(d0)
+            },
+        }
+    }
+
+    // Returns the kth edge of the partition.
+    fn edge(&self, k: usize) -> DateTime<Tz> {
+        if k == 0 { return self.start.clone(); }
+
+        match self.unit {
+            CalendarUnit::Hour => self.hour_floor() + Duration::hours(k as i64),
+            CalendarUnit::Day => self.start_of_day(self.start.date_naive() + Duration::days(k as i64)),
+            CalendarUnit::Month => {
+                let first = self.start.date_naive().with_day(1).unwrap();
+
+                self.start_of_day(first + Months::new(k as u32))
+            },
+        }
+    }
+}
+
+impl<Tz: TimeZone> Partition for Calendar<Tz> {
+    type Value = DateTime<Tz>;
+
+    fn len(&self) -> usize { self.size }
+
+    fn index(&self, value: &DateTime<Tz>) -> Option<usize> { self.try_index(value).ok() }
+
+    fn try_index(&self, value: &DateTime<Tz>) -> Result<usize, OutOfRange> {
+        if *value < self.start {
+            Err(OutOfRange::Below)
+        } else if *value > self.end {
+            Err(OutOfRange::Above)
+        } else {
+            Ok((self.raw_index(value).max(0) as usize).min(self.size - 1))
+        }
+    }
+
+    fn subinterval(&self, k: usize) -> Option<SubInterval<DateTime<Tz>>> {
+        if k >= self.size { return None; }
+
+        let right = if k + 1 == self.size { self.end.clone() } else { self.edge(k + 1) };
+
+        Some(SubInterval {
+            index: k,
+            interval: EdgePolicy::LeftClosed.subinterval(k, self.size, self.edge(k), right),
+        })
+    }
+}
+
+impl<Tz: TimeZone> WidthValue for DateTime<Tz> {
+    type Width = Duration;
+
+    fn checked_width_between(left: &Self, right: &Self) -> Option<Duration> {
+        Some(right.clone().signed_duration_since(left.clone()))
+    }
+}
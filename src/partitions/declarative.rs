@@ -1,50 +1,112 @@
-use crate::bounds;
-use std::cmp::Ordering;
-use super::{Partition, SubInterval, PartitionError};
+use super::{EdgePolicy, OutOfRange, Partition, SubInterval, SubIntervalRef, PartitionError};
 
 /// Type representing an explicitly defined partition of an interval.
+///
+/// # Examples
+/// ```
+/// # use intervals::partitions::{Declarative, EdgePolicy, Partition};
+/// let partition = Declarative::new_unchecked([0, 5, 10]);
+///
+/// assert_eq!(partition.index(&5), Some(1));
+/// assert_eq!(partition.with_policy(EdgePolicy::RightClosed).index(&5), Some(0));
+/// ```
+///
+/// A partition requires at least two edges; fewer are rejected at compile time:
+/// ```compile_fail
+/// # use intervals::partitions::Declarative;
+/// let partition = Declarative::new_unchecked([0]);
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Declarative<const N: usize, V>(pub [V; N]);
+pub struct Declarative<const N: usize, V>(pub [V; N], pub EdgePolicy);
+
+impl<const N: usize, V> Declarative<N, V> {
+    // Referenced by every method, such that partitions with fewer than two
+    // edges fail to compile rather than panicking at runtime.
+    const MIN_EDGES: () = assert!(N >= 2, "Declarative partitions require at least two edges.");
+}
 
 impl<const N: usize, V: PartialOrd> Declarative<N, V> {
+    /// Construct a partition from non-decreasing edges.
+    ///
+    /// As for [Edges::new](super::Edges::new), edges that fail to be
+    /// non-decreasing (including NaNs) yield [PartitionError::UnorderedBounds]
+    /// with the index of the first offending edge.
     pub fn new(bounds: [V; N]) -> Result<Self, PartitionError<[V; N]>> {
-        if bounds.windows(2).all(|w| w[0] <= w[1]) {
-            Ok(Declarative(bounds))
-        } else {
-            Err(PartitionError::IllFormedBounds(bounds))
+        let () = Self::MIN_EDGES;
+
+        let unordered = bounds.windows(2).position(|w| {
+            matches!(w[0].partial_cmp(&w[1]), Some(std::cmp::Ordering::Greater) | None)
+        });
+
+        match unordered {
+            Some(k) => Err(PartitionError::UnorderedBounds(bounds, k)),
+            None => Ok(Declarative(bounds, EdgePolicy::default())),
         }
     }
 
-    pub fn new_unchecked(bounds: [V; N]) -> Self { Declarative(bounds) }
+    pub fn new_unchecked(bounds: [V; N]) -> Self {
+        let () = Self::MIN_EDGES;
+
+        Declarative(bounds, EdgePolicy::default())
+    }
 
-    pub fn iter(&self) -> std::slice::Iter<V> { self.0.iter() }
+    /// Returns the partition with the given [EdgePolicy].
+    pub fn with_policy(self, policy: EdgePolicy) -> Self { Declarative(self.0, policy) }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, V> { self.0.iter() }
+
+    /// Map the edges of the partition through the increasing function `f`.
+    ///
+    /// The mapped edges are re-validated as in [Declarative::new], so a
+    /// decreasing map is an error rather than inverting the subintervals. The
+    /// edge policy is preserved.
+    ///
+    /// # Examples
+    /// ```
+    /// # use intervals::partitions::Declarative;
+    /// let metres = Declarative::new_unchecked([0.0, 1.0, 2.0]);
+    ///
+    /// assert_eq!(metres.map_values(|m| m * 100.0).unwrap().0, [0.0, 100.0, 200.0]);
+    /// assert!(metres.map_values(|m| -m).is_err());
+    /// ```
+    pub fn map_values<W, F>(self, f: F) -> Result<Declarative<N, W>, PartitionError<[W; N]>>
+    where
+        W: PartialOrd,
+        F: Fn(V) -> W,
+    {
+        let policy = self.1;
+
+        Declarative::new(self.0.map(f)).map(|d| d.with_policy(policy))
+    }
 }
 
 impl<const N: usize, V: PartialOrd + Clone> Partition for Declarative<N, V> {
     type Value = V;
 
-    fn len(&self) -> usize { N - 2 }
+    fn len(&self) -> usize {
+        let () = Self::MIN_EDGES;
 
-    fn index(&self, value: &V) -> Option<usize> {
-        if value == &self.0[N - 1] {
-            Some(N - 2)
-        } else {
-            binary_search(&self.0, value)
-        }
+        N - 1
+    }
+
+    fn index(&self, value: &V) -> Option<usize> { self.try_index(value).ok() }
+
+    fn try_index(&self, value: &V) -> Result<usize, OutOfRange> {
+        let () = Self::MIN_EDGES;
+
+        self.1.locate(&self.0, value)
     }
 
     fn subinterval(&self, k: usize) -> Option<SubInterval<V>> {
-        Some(SubInterval {
-            index: k,
-            interval: crate::Interval {
-                left: bounds::Closed(self.0[k].clone()),
-                right: if k == N - 1 {
-                    bounds::OpenOrClosed::Closed(self.0[k + 1].clone())
-                } else {
-                    bounds::OpenOrClosed::Open(self.0[k + 1].clone())
-                },
-            }
-        })
+        let () = Self::MIN_EDGES;
+
+        self.1.subinterval_of(&self.0, k)
+    }
+
+    fn subinterval_ref(&self, k: usize) -> Option<SubIntervalRef<'_, V>> {
+        let () = Self::MIN_EDGES;
+
+        self.1.subinterval_ref_of(&self.0, k)
     }
 }
 
@@ -56,45 +118,97 @@ impl<const N: usize, V> std::ops::Index<usize> for Declarative<N, V> {
 
 impl<const N: usize, V: std::fmt::Display> std::fmt::Display for Declarative<N, V> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let () = Self::MIN_EDGES;
+
         let l = &self.0[0];
-        let r = &self.0[self.0.len() - 1];
+        let r = &self.0[N - 1];
 
         match N {
-            1 => write!(f, "{{{} = x0, x1 = {}}}", l, r),
-            2 => write!(f, "{{{} = x0, x1, x2 = {}}}", l, r),
+            2 => write!(f, "{{{} = x0, x1 = {}}}", l, r),
+            3 => write!(f, "{{{} = x0, x1, x2 = {}}}", l, r),
             _ => write!(f, "{{{} = x0, x1, ..., x{} = {}}}", l, N - 1, r),
         }
     }
 }
 
-fn binary_search<'a, const N: usize, V: PartialOrd>(
-    bounds: &'a [V; N],
-    value: &V
-) -> Option<usize> {
-    let mut low: usize = 0;
-    let mut high: usize = N - 1;
-
-    while low < high {
-        let middle = (low + high) / 2;
-
-        let l = bounds[middle].partial_cmp(value);
-        let r = bounds[middle + 1].partial_cmp(value);
-
-        if let Some((l, r)) = l.zip(r) {
-            match l {
-                Ordering::Less | Ordering::Equal => {
-                    match r {
-                        Ordering::Greater => { return Some(middle) },
-                        Ordering::Equal => { return Some(middle + 1) },
-                        _ => { low = middle; }
-                    }
-                },
-                _ => { high = middle; }
-            }
-        } else {
-            return None
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        assert_eq!(Declarative::new_unchecked([0, 5, 10]).len(), 2);
+        assert_eq!(Declarative::new_unchecked([0, 1]).len(), 1);
     }
 
-    None
+    #[test]
+    fn test_display() {
+        assert_eq!(Declarative::new_unchecked([0, 1]).to_string(), "{0 = x0, x1 = 1}");
+        assert_eq!(Declarative::new_unchecked([0, 1, 2]).to_string(), "{0 = x0, x1, x2 = 2}");
+        assert_eq!(Declarative::new_unchecked([0, 1, 2, 5]).to_string(), "{0 = x0, x1, ..., x3 = 5}");
+    }
+
+    #[test]
+    fn test_subinterval() {
+        let d = Declarative::new_unchecked([0, 5, 10]);
+
+        assert_eq!(d.subinterval(0).unwrap().interval, crate::Interval::lcro_unchecked(0, 5));
+        assert_eq!(d.subinterval(1).unwrap().interval, crate::Interval::closed_unchecked(5, 10));
+        assert!(d.subinterval(2).is_none());
+    }
+
+    #[test]
+    fn test_try_index() {
+        let d = Declarative::new_unchecked([0.0, 1.0, 4.0, 5.0]);
+
+        assert_eq!(d.try_index(&-1.0), Err(OutOfRange::Below));
+        assert_eq!(d.try_index(&0.0), Ok(0));
+        assert_eq!(d.try_index(&1.0), Ok(1));
+        assert_eq!(d.try_index(&4.5), Ok(2));
+        assert_eq!(d.try_index(&5.0), Ok(2));
+        assert_eq!(d.try_index(&6.0), Err(OutOfRange::Above));
+        assert_eq!(d.try_index(&f64::NAN), Err(OutOfRange::Incomparable));
+
+        assert_eq!(d.index(&6.0), None);
+    }
+
+    #[test]
+    fn test_map_values() {
+        let d = Declarative::new_unchecked([1, 2, 3]).with_policy(EdgePolicy::RightClosed);
+        let m = d.map_values(|x| x as f64 * 0.5).unwrap();
+
+        assert_eq!(m.0, [0.5, 1.0, 1.5]);
+        assert_eq!(m.1, EdgePolicy::RightClosed);
+        assert!(matches!(d.map_values(|x| 10 - x), Err(PartitionError::UnorderedBounds(_, 0))));
+    }
+
+    #[test]
+    fn test_new_unordered() {
+        assert!(Declarative::new([0, 5, 5, 10]).is_ok());
+        assert!(matches!(Declarative::new([0, 5, 3, 10]), Err(PartitionError::UnorderedBounds(_, 1))));
+        assert!(matches!(Declarative::new([0.0, 1.0, f64::NAN]), Err(PartitionError::UnorderedBounds(_, 1))));
+    }
+
+    #[test]
+    fn test_policies_at_edges() {
+        let lc = Declarative::new_unchecked([0.0, 1.0, 4.0, 5.0]);
+        let rc = lc.with_policy(EdgePolicy::RightClosed);
+
+        assert_eq!(lc.index(&0.0), Some(0));
+        assert_eq!(rc.index(&0.0), Some(0));
+        assert_eq!(lc.index(&5.0), Some(2));
+        assert_eq!(rc.index(&5.0), Some(2));
+
+        assert_eq!(lc.index(&1.0), Some(1));
+        assert_eq!(rc.index(&1.0), Some(0));
+        assert_eq!(lc.index(&4.0), Some(2));
+        assert_eq!(rc.index(&4.0), Some(1));
+
+        assert_eq!(rc.subinterval(0).unwrap().interval, crate::Interval::closed_unchecked(0.0, 1.0));
+        assert_eq!(rc.subinterval(1).unwrap().interval, crate::Interval::lorc_unchecked(1.0, 4.0));
+
+        for &v in [0.5, 2.5, 4.5].iter() {
+            assert_eq!(lc.index(&v), rc.index(&v));
+        }
+    }
 }
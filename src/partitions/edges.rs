@@ -0,0 +1,350 @@
+use num_traits::{Num, NumCast};
+use std::cmp::Ordering;
+use super::{EdgePolicy, OutOfRange, Partition, SubInterval, SubIntervalRef, PartitionError, Uniform};
+
+/// Type representing a partition of an interval by edges known at runtime.
+///
+/// This is the dynamically-sized counterpart of
+/// [Declarative](super::Declarative).
+///
+/// # Examples
+/// ```
+/// # use intervals::partitions::{Edges, Partition};
+/// let partition = Edges::new(vec![0, 5, 10, 20]).unwrap();
+///
+/// assert_eq!(partition.len(), 3);
+/// assert_eq!(partition.index(&12), Some(2));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(
+        crate = "serde_crate",
+        try_from = "RawEdges<V>",
+        bound(deserialize = "V: PartialOrd + ::serde_crate::Deserialize<'de>")
+    )
+)]
+pub struct Edges<V> {
+    edges: Vec<V>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    policy: EdgePolicy,
+}
+
+// Unvalidated form of an Edges partition, as deserialized.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(crate = "serde_crate", rename = "Edges")]
+struct RawEdges<V> {
+    edges: Vec<V>,
+
+    #[serde(default)]
+    policy: EdgePolicy,
+}
+
+#[cfg(feature = "serde")]
+impl<V: PartialOrd> std::convert::TryFrom<RawEdges<V>> for Edges<V> {
+    type Error = &'static str;
+
+    fn try_from(raw: RawEdges<V>) -> Result<Self, &'static str> {
+        match Edges::new(raw.edges) {
+            Ok(edges) => Ok(edges.with_policy(raw.policy)),
+            Err(PartitionError::IllFormedBounds(_)) => Err("fewer than two edges"),
+            Err(PartitionError::UnorderedBounds(..)) => Err("the edges are not non-decreasing"),
+        }
+    }
+}
+
+impl<V: PartialOrd> Edges<V> {
+    /// Construct a partition from at least two non-decreasing edges.
+    ///
+    /// Fewer than two edges yield [PartitionError::IllFormedBounds], and edges
+    /// that fail to be non-decreasing (including NaNs) yield
+    /// [PartitionError::UnorderedBounds] with the index of the first offending
+    /// edge.
+    pub fn new(edges: Vec<V>) -> Result<Self, PartitionError<Vec<V>>> {
+        if edges.len() < 2 {
+            return Err(PartitionError::IllFormedBounds(edges));
+        }
+
+        let unordered = edges.windows(2).position(|w| {
+            matches!(w[0].partial_cmp(&w[1]), Some(Ordering::Greater) | None)
+        });
+
+        match unordered {
+            Some(k) => Err(PartitionError::UnorderedBounds(edges, k)),
+            None => Ok(Edges { edges, policy: EdgePolicy::default(), }),
+        }
+    }
+
+    /// Construct a partition w/o checking that the edges are non-decreasing.
+    ///
+    /// # Panics
+    /// Panics if fewer than two edges are given.
+    pub fn new_unchecked(edges: Vec<V>) -> Self {
+        assert!(edges.len() >= 2, "Edges partitions require at least two edges.");
+
+        Edges { edges, policy: EdgePolicy::default(), }
+    }
+
+    /// Returns the partition with the given [EdgePolicy].
+    pub fn with_policy(self, policy: EdgePolicy) -> Self { Edges { policy, ..self } }
+
+    /// Returns the [EdgePolicy] of the partition.
+    pub fn policy(&self) -> EdgePolicy { self.policy }
+
+    /// Returns the edges of the partition.
+    pub fn edges(&self) -> &[V] { &self.edges }
+
+    /// Consumes the partition, returning its edges.
+    pub fn into_edges(self) -> Vec<V> { self.edges }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, V> { self.edges.iter() }
+
+    /// Map the edges of the partition through the increasing function `f`.
+    ///
+    /// The mapped edges are re-validated as in [Edges::new], so a decreasing
+    /// map yields [PartitionError::UnorderedBounds] rather than inverting the
+    /// subintervals. The edge policy is preserved.
+    ///
+    /// # Examples
+    /// ```
+    /// # use intervals::partitions::Edges;
+    /// let seconds = Edges::new(vec![0.0, 1.5, 3.0]).unwrap();
+    /// let millis = seconds.clone().map_values(|s| s * 1000.0).unwrap();
+    ///
+    /// assert_eq!(millis.edges(), &[0.0, 1500.0, 3000.0]);
+    /// assert!(seconds.map_values(|s| -s).is_err());
+    /// ```
+    pub fn map_values<W, F>(self, f: F) -> Result<Edges<W>, PartitionError<Vec<W>>>
+    where
+        W: PartialOrd,
+        F: Fn(V) -> W,
+    {
+        let policy = self.policy;
+
+        Edges::new(self.edges.into_iter().map(f).collect()).map(|e| e.with_policy(policy))
+    }
+}
+
+impl<V: Clone + PartialOrd + Num + NumCast> Edges<V> {
+    /// Returns true if every edge lies within `tol` of the corresponding edge
+    /// of the [Uniform] partition over the same interval.
+    ///
+    /// # Examples
+    /// ```
+    /// # use intervals::partitions::Edges;
+    /// assert!(Edges::new(vec![0.0, 0.5, 1.0]).unwrap().is_uniform(0.0));
+    /// assert!(!Edges::new(vec![0.0, 0.6, 1.0]).unwrap().is_uniform(0.05));
+    /// assert!(Edges::new(vec![0.0, 0.6, 1.0]).unwrap().is_uniform(0.1));
+    /// ```
+    pub fn is_uniform(&self, tol: V) -> bool {
+        let uniform = Uniform::new(self.len(), self.edges[0].clone(), self.edges[self.len()].clone());
+
+        self.edges.iter().enumerate().all(|(k, e)| {
+            let expected = uniform.edge(k);
+            let error = if *e > expected { e.clone() - expected } else { expected - e.clone() };
+
+            error <= tol
+        })
+    }
+
+    /// Returns the equivalent [Uniform] partition if the edges are uniform to
+    /// within `tol`; see [Edges::is_uniform].
+    pub fn to_uniform(&self, tol: V) -> Option<Uniform<V>> {
+        if self.is_uniform(tol) {
+            let uniform = Uniform::new(self.len(), self.edges[0].clone(), self.edges[self.len()].clone());
+
+            Some(uniform.with_policy(self.policy))
+        } else {
+            None
+        }
+    }
+}
+
+/// Construct a partition of `support` into `n_bins` subintervals of equal
+/// probability mass.
+///
+/// The interior edges are given by the quantile function (inverse CDF)
+/// evaluated at `k / n_bins` for `k = 1, ..., n_bins - 1`, clipped to the
+/// support, with the outermost edges taken from the support itself. A
+/// quantile function that fails to be non-decreasing yields
+/// [PartitionError::UnorderedBounds], and zero bins yield
+/// [PartitionError::IllFormedBounds].
+///
+/// # Examples
+/// ```
+/// # use intervals::{Interval, partitions::{Partition, from_cdf_inverse}};
+/// // Uniform distribution on [0, 2].
+/// let partition = from_cdf_inverse(|p| 2.0 * p, 4, Interval::closed_unchecked(0.0, 2.0)).unwrap();
+///
+/// assert_eq!(partition.edges(), &[0.0, 0.5, 1.0, 1.5, 2.0]);
+/// assert_eq!(partition.index(&1.2), Some(2));
+/// ```
+pub fn from_cdf_inverse<V, F>(
+    quantile_fn: F,
+    n_bins: usize,
+    support: crate::Closed<V>,
+) -> Result<Edges<V>, PartitionError<Vec<V>>>
+where
+    V: PartialOrd + Clone,
+    F: Fn(f64) -> V,
+{
+    let left = support.left.0;
+    let right = support.right.0;
+
+    if n_bins == 0 {
+        return Err(PartitionError::IllFormedBounds(vec![left, right]));
+    }
+
+    let mut edges = Vec::with_capacity(n_bins + 1);
+
+    edges.push(left.clone());
+    edges.extend((1..n_bins).map(|k| {
+        let q = quantile_fn(k as f64 / n_bins as f64);
+
+        if q < left {
+            left.clone()
+        } else if q > right {
+            right.clone()
+        } else {
+            q
+        }
+    }));
+    edges.push(right);
+
+    Edges::new(edges)
+}
+
+impl<V: PartialOrd + Clone> Partition for Edges<V> {
+    type Value = V;
+
+    fn len(&self) -> usize { self.edges.len() - 1 }
+
+    fn index(&self, value: &V) -> Option<usize> { self.try_index(value).ok() }
+
+    fn try_index(&self, value: &V) -> Result<usize, OutOfRange> {
+        self.policy.locate(&self.edges, value)
+    }
+
+    fn subinterval(&self, k: usize) -> Option<SubInterval<V>> {
+        self.policy.subinterval_of(&self.edges, k)
+    }
+
+    fn subinterval_ref(&self, k: usize) -> Option<SubIntervalRef<'_, V>> {
+        self.policy.subinterval_ref_of(&self.edges, k)
+    }
+}
+
+impl<V> std::ops::Index<usize> for Edges<V> {
+    type Output = V;
+
+    fn index(&self, idx: usize) -> &V { self.edges.index(idx) }
+}
+
+impl<V: std::fmt::Display> std::fmt::Display for Edges<V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let n = self.edges.len() - 1;
+        let l = &self.edges[0];
+        let r = &self.edges[n];
+
+        match n {
+            1 => write!(f, "{{{} = x0, x1 = {}}}", l, r),
+            2 => write!(f, "{{{} = x0, x1, x2 = {}}}", l, r),
+            _ => write!(f, "{{{} = x0, x1, ..., x{} = {}}}", l, n, r),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Edges::new(vec![0, 1, 1, 2]).is_ok());
+        assert!(Edges::new(vec![0, 2, 1]).is_err());
+        assert!(matches!(Edges::new(vec![0.0, 1.0, 2.0, 1.5]), Err(PartitionError::UnorderedBounds(_, 2))));
+        assert!(matches!(Edges::new(vec![0.0, f64::NAN, 2.0]), Err(PartitionError::UnorderedBounds(_, 0))));
+        assert!(Edges::new(vec![0]).is_err());
+        assert!(Edges::<i32>::new(vec![]).is_err());
+    }
+
+    #[test]
+    fn test_try_index() {
+        let d = Edges::new(vec![0.0, 1.0, 4.0, 5.0]).unwrap();
+
+        assert_eq!(d.try_index(&-1.0), Err(OutOfRange::Below));
+        assert_eq!(d.try_index(&0.0), Ok(0));
+        assert_eq!(d.try_index(&1.0), Ok(1));
+        assert_eq!(d.try_index(&5.0), Ok(2));
+        assert_eq!(d.try_index(&6.0), Err(OutOfRange::Above));
+        assert_eq!(d.try_index(&f64::NAN), Err(OutOfRange::Incomparable));
+
+        let d = d.with_policy(EdgePolicy::RightClosed);
+
+        assert_eq!(d.try_index(&0.0), Ok(0));
+        assert_eq!(d.try_index(&1.0), Ok(0));
+        assert_eq!(d.try_index(&5.0), Ok(2));
+    }
+
+    fn logistic(p: f64) -> f64 { (p / (1.0 - p)).ln() }
+
+    #[test]
+    fn test_from_cdf_inverse() {
+        let support = crate::Interval::closed_unchecked(-10.0, 10.0);
+        let d = from_cdf_inverse(logistic, 4, support).unwrap();
+        let e = d.edges();
+
+        assert_eq!(e.len(), 5);
+        assert_eq!(e[0], -10.0);
+        assert!((e[1] - (1.0f64 / 3.0).ln()).abs() < 1e-12);
+        assert!(e[2].abs() < 1e-12);
+        assert!((e[3] - 3.0f64.ln()).abs() < 1e-12);
+        assert_eq!(e[4], 10.0);
+
+        // The subintervals cover the support exactly.
+        for i in -100..=100 {
+            let v = i as f64 / 10.0;
+
+            assert_eq!(d.index(&v).is_some(), support.contains(v));
+        }
+    }
+
+    #[test]
+    fn test_from_cdf_inverse_clipped() {
+        let support = crate::Interval::closed_unchecked(-1.0, 1.0);
+        let d = from_cdf_inverse(logistic, 10, support).unwrap();
+
+        assert_eq!(d.edges()[1], -1.0);
+        assert_eq!(d.edges()[9], 1.0);
+        assert!(d.edges().windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_from_cdf_inverse_invalid() {
+        let support = crate::Interval::closed_unchecked(0.0, 1.0);
+
+        assert!(matches!(from_cdf_inverse(|p| 1.0 - p, 4, support), Err(PartitionError::UnorderedBounds(_, 1))));
+        assert!(matches!(from_cdf_inverse(|p| p, 0, support), Err(PartitionError::IllFormedBounds(_))));
+    }
+
+    #[test]
+    fn test_map_values() {
+        let d = Edges::new(vec![1.0, 10.0, 100.0]).unwrap().with_policy(EdgePolicy::RightClosed);
+        let m = d.clone().map_values(f64::log10).unwrap();
+
+        assert_eq!(m.edges(), &[0.0, 1.0, 2.0]);
+        assert_eq!(m.policy(), EdgePolicy::RightClosed);
+
+        assert!(matches!(d.clone().map_values(|x| -x), Err(PartitionError::UnorderedBounds(_, 0))));
+        assert!(matches!(d.map_values(|x| (x - 10.0).abs()), Err(PartitionError::UnorderedBounds(_, 0))));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Edges::new(vec![0, 1]).unwrap().to_string(), "{0 = x0, x1 = 1}");
+        assert_eq!(Edges::new(vec![0, 1, 2, 5]).unwrap().to_string(), "{0 = x0, x1, ..., x3 = 5}");
+    }
+}
@@ -0,0 +1,429 @@
+use crate::bounds::ProperBound;
+use num_traits::{NumCast, ToPrimitive};
+use super::{OutOfRange, Partition};
+
+/// Type representing counts of values accumulated over a partition.
+///
+/// # Examples
+/// ```
+/// # use intervals::partitions::{Histogram, Uniform};
+/// let mut histogram = Histogram::new(Uniform::new(4, 0.0, 4.0));
+///
+/// histogram.extend(vec![0.5, 1.5, 1.7, 3.0, 9.0]);
+///
+/// assert_eq!(histogram.counts(), &[1, 2, 0, 1]);
+/// assert_eq!(histogram.total(), 4);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(
+        crate = "serde_crate",
+        try_from = "RawHistogram<P>",
+        bound(deserialize = "P: Partition + ::serde_crate::Deserialize<'de>")
+    )
+)]
+pub struct Histogram<P> {
+    partition: P,
+    counts: Vec<u64>,
+}
+
+// Unvalidated form of a Histogram, as deserialized.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(crate = "serde_crate", rename = "Histogram")]
+struct RawHistogram<P> {
+    partition: P,
+    counts: Vec<u64>,
+}
+
+#[cfg(feature = "serde")]
+impl<P: Partition> std::convert::TryFrom<RawHistogram<P>> for Histogram<P> {
+    type Error = &'static str;
+
+    fn try_from(raw: RawHistogram<P>) -> Result<Self, &'static str> {
+        if raw.counts.len() != raw.partition.len() {
+            return Err("the number of counts differs from the number of bins");
+        }
+
+        Ok(Histogram { partition: raw.partition, counts: raw.counts, })
+    }
+}
+
+impl<P: Partition> Histogram<P> {
+    /// Construct an empty histogram over the given partition.
+    pub fn new(partition: P) -> Self {
+        let counts = vec![0; partition.len()];
+
+        Histogram { partition, counts, }
+    }
+
+    /// Add a value to the histogram, returning the index of its bin.
+    ///
+    /// Values outside the partition are not counted.
+    pub fn add(&mut self, value: &P::Value) -> Result<usize, OutOfRange> {
+        let k = self.partition.try_index(value)?;
+
+        self.counts[k] += 1;
+
+        Ok(k)
+    }
+
+    /// Returns the underlying partition.
+    pub fn partition(&self) -> &P { &self.partition }
+
+    /// Returns the count of each bin.
+    pub fn counts(&self) -> &[u64] { &self.counts }
+
+    /// Returns the total count over all bins.
+    pub fn total(&self) -> u64 { self.counts.iter().sum() }
+
+    /// Returns true if no values have been counted.
+    pub fn is_empty(&self) -> bool { self.counts.iter().all(|&n| n == 0) }
+}
+
+/// Values outside the partition are skipped; see [Histogram::add].
+impl<P: Partition> Extend<P::Value> for Histogram<P> {
+    fn extend<I: IntoIterator<Item = P::Value>>(&mut self, iter: I) {
+        for value in iter {
+            let _ = self.add(&value);
+        }
+    }
+}
+
+impl<P: Partition> Histogram<P> {
+    /// Returns the count of each bin divided by the total count.
+    ///
+    /// All proportions are zero for empty histograms.
+    pub fn normalized_counts(&self) -> Vec<f64> {
+        let total = self.total();
+
+        self.counts.iter().map(|&n| if total == 0 { 0.0 } else { n as f64 / total as f64 }).collect()
+    }
+}
+
+impl<P> Histogram<P>
+where
+    P: Partition,
+    P::Value: Clone,
+{
+    /// Returns the bin edges and counts of the histogram.
+    ///
+    /// There is one more edge than there are counts, with bin `k` spanning
+    /// edges `k` and `k + 1`, as expected by numpy and most plotting
+    /// libraries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use intervals::partitions::{Histogram, Uniform};
+    /// let mut histogram = Histogram::new(Uniform::new(4, 0.0, 2.0));
+    ///
+    /// histogram.extend(vec![0.1, 0.6, 0.7, 1.9]);
+    ///
+    /// let (edges, counts) = histogram.to_arrays();
+    ///
+    /// assert_eq!(edges, vec![0.0, 0.5, 1.0, 1.5, 2.0]);
+    /// assert_eq!(counts, vec![1, 2, 0, 1]);
+    /// ```
+    ///
+    /// The arrays can then be handed to e.g. matplotlib (here via Python):
+    /// ```python
+    /// import matplotlib.pyplot as plt
+    ///
+    /// plt.stairs(counts, edges, fill=True)
+    /// plt.show()
+    /// ```
+    pub fn to_arrays(&self) -> (Vec<P::Value>, Vec<u64>) {
+        let n = self.counts.len();
+        let mut edges: Vec<P::Value> = (0..n)
+            .filter_map(|k| self.partition.subinterval(k))
+            .map(|s| s.interval.left.proper_value().clone())
+            .collect();
+
+        if let Some(last) = n.checked_sub(1).and_then(|k| self.partition.subinterval(k)) {
+            edges.push(last.interval.right.proper_value().clone());
+        }
+
+        (edges, self.counts.clone())
+    }
+}
+
+impl<P> Histogram<P>
+where
+    P: Partition,
+    P::Value: ToPrimitive,
+{
+    /// Returns the probability density of each bin.
+    ///
+    /// The density of a bin is its count divided by its width and by the
+    /// total count, such that the densities integrate to one.
+    ///
+    /// Bins of zero width (e.g. from repeated edges of an
+    /// [Edges](super::Edges) partition) hold point masses, which have no
+    /// finite density. Rather than producing infinities, such bins are given
+    /// a density of zero and their counts are excluded from the total, so the
+    /// densities of the remaining bins still integrate to one; the point
+    /// masses remain visible through [Histogram::normalized_counts]. All
+    /// densities are zero if there are no counts in bins of positive width.
+    ///
+    /// # Examples
+    /// ```
+    /// # use intervals::partitions::{Histogram, Uniform};
+    /// let mut histogram = Histogram::new(Uniform::new(4, 0.0, 2.0));
+    ///
+    /// histogram.extend(vec![0.1, 0.6, 0.7, 1.9]);
+    ///
+    /// assert_eq!(histogram.densities(), vec![0.5, 1.0, 0.0, 0.5]);
+    /// ```
+    pub fn densities(&self) -> Vec<f64> {
+        let widths: Vec<f64> = (0..self.counts.len())
+            .map(|k| {
+                self.partition.subinterval(k)
+                    .and_then(|s| {
+                        let left = s.interval.left.proper_value().to_f64()?;
+                        let right = s.interval.right.proper_value().to_f64()?;
+
+                        Some(right - left)
+                    })
+                    .unwrap_or(0.0)
+            })
+            .collect();
+
+        let total: u64 = self.counts.iter()
+            .zip(widths.iter())
+            .filter(|&(_, &w)| w > 0.0)
+            .map(|(&n, _)| n)
+            .sum();
+
+        self.counts.iter()
+            .zip(widths.iter())
+            .map(|(&n, &w)| if w > 0.0 && total > 0 { n as f64 / w / total as f64 } else { 0.0 })
+            .collect()
+    }
+}
+
+impl<P> Histogram<P>
+where
+    P: Partition,
+    P::Value: Clone + ToPrimitive + NumCast,
+{
+    /// Returns the approximate `q`-quantile of the counted values.
+    ///
+    /// The quantile is found by linear interpolation within the bin at which
+    /// the cumulative count reaches `q` times the total, treating the values
+    /// of each bin as spread uniformly over it. A quantile that falls exactly
+    /// on the boundary between two bins is the right edge of the lower of the
+    /// two non-empty bins. This is `None` for empty histograms, and if `q` lies
+    /// outside `[0, 1]` (including NaN).
+    ///
+    /// # Examples
+    /// ```
+    /// # use intervals::partitions::{Histogram, Uniform};
+    /// let mut histogram = Histogram::new(Uniform::new(4, 0.0, 4.0));
+    ///
+    /// histogram.extend(vec![0.5, 1.5, 1.7, 3.0]);
+    ///
+    /// assert_eq!(histogram.quantile(0.0), Some(0.0));
+    /// assert_eq!(histogram.quantile(0.25), Some(1.0));
+    /// assert_eq!(histogram.median(), Some(1.5));
+    /// assert_eq!(histogram.quantile(1.0), Some(4.0));
+    /// assert_eq!(histogram.quantile(1.5), None);
+    /// ```
+    pub fn quantile(&self, q: f64) -> Option<P::Value> {
+        if !(0.0..=1.0).contains(&q) { return None; }
+
+        let target = q * self.total() as f64;
+        let mut cumulative = 0.0;
+
+        for (k, &n) in self.counts.iter().enumerate().filter(|&(_, &n)| n > 0) {
+            let next = cumulative + n as f64;
+
+            if target <= next {
+                let bin = self.partition.subinterval(k)?.interval;
+                let left = bin.left.proper_value().to_f64()?;
+                let right = bin.right.proper_value().to_f64()?;
+                let fraction = (target - cumulative) / n as f64;
+
+                return NumCast::from(left + fraction * (right - left));
+            }
+
+            cumulative = next;
+        }
+
+        None
+    }
+
+    /// Returns the approximate median of the counted values; see
+    /// [Histogram::quantile].
+    pub fn median(&self) -> Option<P::Value> { self.quantile(0.5) }
+
+    /// Returns the approximate `p`th percentile of the counted values; see
+    /// [Histogram::quantile]. This is `None` if `p` lies outside `[0, 100]`
+    /// (including NaN).
+    pub fn percentile(&self, p: f64) -> Option<P::Value> {
+        if !(0.0..=100.0).contains(&p) { return None; }
+
+        self.quantile(p / 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::partitions::{Edges, Uniform};
+
+    #[test]
+    fn test_add() {
+        let mut h = Histogram::new(Uniform::new(2, 0, 10));
+
+        assert_eq!(h.add(&0), Ok(0));
+        assert_eq!(h.add(&5), Ok(1));
+        assert_eq!(h.add(&10), Ok(1));
+        assert_eq!(h.add(&11), Err(OutOfRange::Above));
+        assert_eq!(h.counts(), &[1, 2]);
+        assert_eq!(h.total(), 3);
+    }
+
+    #[test]
+    fn test_empty() {
+        let h = Histogram::new(Uniform::new(4, 0.0, 1.0));
+
+        assert!(h.is_empty());
+        assert_eq!(h.quantile(0.5), None);
+        assert_eq!(h.median(), None);
+    }
+
+    #[test]
+    fn test_uniform_sample() {
+        // One sample at the midpoint of each bin, so the interpolated CDF is
+        // exactly that of the uniform distribution.
+        let n = 1000;
+        let mut h = Histogram::new(Uniform::new(n, 0.0, 1.0));
+
+        h.extend((0..n).map(|i| (i as f64 + 0.5) / n as f64));
+
+        for i in 0..=100 {
+            let q = i as f64 / 100.0;
+
+            assert!((h.quantile(q).unwrap() - q).abs() < 1e-12);
+            assert!((h.percentile(i as f64).unwrap() - q).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_finely_binned_sample() {
+        let mut sample: Vec<f64> = (0..500).map(|i| (i as f64 / 500.0).powi(3) * 8.0).collect();
+        let mut h = Histogram::new(Uniform::new(8000, 0.0, 8.0));
+
+        h.extend(sample.iter().cloned());
+        sample.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        for &q in [0.1, 0.25, 0.5, 0.75, 0.9].iter() {
+            let exact = sample[(q * sample.len() as f64) as usize - 1];
+
+            assert!((h.quantile(q).unwrap() - exact).abs() <= 1e-3, "q = {}", q);
+        }
+    }
+
+    #[test]
+    fn test_bin_boundaries() {
+        let mut h = Histogram::new(Edges::new(vec![0.0, 1.0, 2.0, 3.0, 4.0]).unwrap());
+
+        h.extend(vec![0.5, 0.5, 2.5, 3.5]);
+
+        // The cumulative count reaches half the total at the right edge of
+        // the first bin; the empty bin between is skipped.
+        assert_eq!(h.median(), Some(1.0));
+        assert_eq!(h.quantile(0.75), Some(3.0));
+        assert_eq!(h.quantile(0.625), Some(2.5));
+        assert_eq!(h.quantile(0.0), Some(0.0));
+        assert_eq!(h.quantile(1.0), Some(4.0));
+    }
+
+    #[test]
+    fn test_integer_values() {
+        let mut h = Histogram::new(Uniform::new(5, 0u32, 100));
+
+        h.extend(vec![10, 30, 50, 70, 90]);
+
+        assert_eq!(h.median(), Some(50));
+    }
+
+    #[test]
+    fn test_invalid_quantile() {
+        let mut h = Histogram::new(Uniform::new(4, 0.0, 1.0));
+
+        h.extend(vec![0.1, 0.6]);
+
+        assert_eq!(h.quantile(1.5), None);
+        assert_eq!(h.quantile(-0.1), None);
+        assert_eq!(h.percentile(101.0), None);
+    }
+
+    #[test]
+    fn test_nan_quantile() {
+        let mut h = Histogram::new(Uniform::new(4, 0.0, 1.0));
+
+        h.extend(vec![0.1, 0.6]);
+
+        assert_eq!(h.quantile(f64::NAN), None);
+        assert_eq!(h.percentile(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_densities_integrate_to_one() {
+        let mut h = Histogram::new(Edges::new(vec![0.0, 0.5, 2.0, 2.25, 4.0]).unwrap());
+
+        h.extend(vec![0.1, 0.2, 1.0, 1.5, 1.9, 2.1, 3.0, 3.5, 3.9]);
+
+        let (edges, _) = h.to_arrays();
+        let integral: f64 = h.densities().iter()
+            .zip(edges.windows(2))
+            .map(|(d, e)| d * (e[1] - e[0]))
+            .sum();
+
+        assert!((integral - 1.0).abs() < 1e-12);
+        assert!((h.normalized_counts().iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_zero_width_bins() {
+        use crate::partitions::EdgePolicy;
+
+        // Interior zero-width bins can never hold values.
+        let mut h = Histogram::new(Edges::new(vec![0.0, 1.0, 1.0, 2.0]).unwrap());
+
+        h.extend(vec![0.5, 1.0, 1.5]);
+
+        assert_eq!(h.counts(), &[1, 0, 2]);
+        assert_eq!(h.densities(), vec![1.0 / 3.0, 0.0, 2.0 / 3.0]);
+
+        // Outermost zero-width bins are degenerate, and hold point masses.
+        let partition = Edges::new(vec![0.0, 0.0, 1.0]).unwrap().with_policy(EdgePolicy::RightClosed);
+        let mut h = Histogram::new(partition);
+
+        h.extend(vec![0.0, 0.0, 0.5, 1.0]);
+
+        assert_eq!(h.counts(), &[2, 2]);
+        assert_eq!(h.densities(), vec![0.0, 1.0]);
+        assert_eq!(h.normalized_counts(), vec![0.5, 0.5]);
+
+        // Only point masses: no finite densities at all.
+        let mut h = Histogram::new(Edges::new(vec![0.0, 0.0]).unwrap());
+
+        h.extend(vec![0.0]);
+
+        assert_eq!(h.densities(), vec![0.0]);
+        assert_eq!(h.normalized_counts(), vec![1.0]);
+    }
+
+    #[test]
+    fn test_empty_densities() {
+        let h = Histogram::new(Uniform::new(2, 0.0, 1.0));
+
+        assert_eq!(h.densities(), vec![0.0, 0.0]);
+        assert_eq!(h.normalized_counts(), vec![0.0, 0.0]);
+        assert_eq!(h.to_arrays(), (vec![0.0, 0.5, 1.0], vec![0, 0]));
+    }
+}
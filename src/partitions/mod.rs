@@ -1,5 +1,6 @@
 //! Module containing interval partition utilities.
-use crate::{Interval, bounds};
+use crate::{Interval, bounds::{self, Bound, ProperBound}};
+use std::borrow::Cow;
 
 #[derive(Debug)]
 #[cfg_attr(
@@ -9,6 +10,9 @@ use crate::{Interval, bounds};
 )]
 pub enum PartitionError<B> {
     IllFormedBounds(B),
+
+    /// The bounds decrease from the given index to the next.
+    UnorderedBounds(B, usize),
 }
 
 impl<V: std::fmt::Debug + std::fmt::Display> std::fmt::Display for PartitionError<V> {
@@ -17,10 +21,138 @@ impl<V: std::fmt::Debug + std::fmt::Display> std::fmt::Display for PartitionErro
             PartitionError::IllFormedBounds(bounds) => write!(
                 f, "The bounds {} are not well defined.", bounds
             ),
+            PartitionError::UnorderedBounds(bounds, index) => write!(
+                f, "The bounds {} are not ordered at index {}.", bounds, index
+            ),
+        }
+    }
+}
+
+/// Reason a value could not be assigned to a subinterval of a partition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum OutOfRange {
+    /// The value lies below the partitioned interval.
+    Below,
+
+    /// The value lies above the partitioned interval.
+    Above,
+
+    /// The value is incomparable with the partition bounds (e.g. NaN).
+    Incomparable,
+}
+
+impl std::fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutOfRange::Below => write!(f, "The value lies below the partition."),
+            OutOfRange::Above => write!(f, "The value lies above the partition."),
+            OutOfRange::Incomparable => write!(f, "The value is incomparable with the partition."),
         }
     }
 }
 
+/// Convention determining which subinterval owns each interior edge of a
+/// partition.
+///
+/// With [EdgePolicy::LeftClosed] the subintervals are closed on the left and
+/// open on the right, except for the last which is closed on both sides; with
+/// [EdgePolicy::RightClosed] they are open on the left and closed on the right,
+/// except for the first which is closed on both sides. Either way, both
+/// endpoints of the partitioned interval belong to some subinterval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum EdgePolicy {
+    /// Subintervals of the form `[a, b)`.
+    #[default]
+    LeftClosed,
+
+    /// Subintervals of the form `(a, b]`.
+    RightClosed,
+}
+
+impl EdgePolicy {
+    /// Returns the bounds of subinterval `k` of `n`, spanning `left` to `right`.
+    pub(crate) fn subinterval<V: PartialOrd>(
+        self,
+        k: usize,
+        n: usize,
+        left: V,
+        right: V,
+    ) -> Interval<bounds::OpenOrClosed<V>, bounds::OpenOrClosed<V>> {
+        use self::bounds::OpenOrClosed::{Open, Closed};
+
+        match self {
+            EdgePolicy::LeftClosed => Interval::new_unchecked(
+                Closed(left),
+                if k + 1 == n { Closed(right) } else { Open(right) },
+            ),
+            EdgePolicy::RightClosed => Interval::new_unchecked(
+                if k == 0 { Closed(left) } else { Open(left) },
+                Closed(right),
+            ),
+        }
+    }
+
+    /// Returns the index of the subinterval containing `value` among those
+    /// delimited by the (at least two, non-decreasing) `edges`.
+    pub(crate) fn locate<V: PartialOrd>(self, edges: &[V], value: &V) -> Result<usize, OutOfRange> {
+        let n = edges.len() - 1;
+
+        match (value.partial_cmp(&edges[0]), value.partial_cmp(&edges[n])) {
+            (None, _) | (_, None) => return Err(OutOfRange::Incomparable),
+            (Some(std::cmp::Ordering::Less), _) => return Err(OutOfRange::Below),
+            (_, Some(std::cmp::Ordering::Greater)) => return Err(OutOfRange::Above),
+            _ => {},
+        }
+
+        // Number of edges owned by subintervals at or below the value.
+        let n_below = match self {
+            EdgePolicy::LeftClosed => edges.partition_point(|e| e <= value),
+            EdgePolicy::RightClosed => edges.partition_point(|e| e < value),
+        };
+
+        Ok(n_below.saturating_sub(1).min(n - 1))
+    }
+
+    /// Returns subinterval `k` among those delimited by `edges`.
+    pub(crate) fn subinterval_of<V: PartialOrd + Clone>(
+        self,
+        edges: &[V],
+        k: usize,
+    ) -> Option<SubInterval<V>> {
+        if k + 1 >= edges.len() { return None; }
+
+        Some(SubInterval {
+            index: k,
+            interval: self.subinterval(k, edges.len() - 1, edges[k].clone(), edges[k + 1].clone()),
+        })
+    }
+
+    /// Returns subinterval `k` among those delimited by `edges`, borrowing
+    /// its bounds from `edges`.
+    pub(crate) fn subinterval_ref_of<V: PartialOrd + Clone>(
+        self,
+        edges: &[V],
+        k: usize,
+    ) -> Option<SubIntervalRef<'_, V>> {
+        if k + 1 >= edges.len() { return None; }
+
+        Some(SubIntervalRef {
+            index: k,
+            interval: self.subinterval(k, edges.len() - 1, Cow::Borrowed(&edges[k]), Cow::Borrowed(&edges[k + 1])),
+        })
+    }
+}
+
 /// Type representing a single subinterval of a partition.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(
@@ -33,20 +165,81 @@ pub struct SubInterval<V: PartialOrd> {
     pub index: usize,
 
     /// The interval corresponding to this subinterval.
-    pub interval: Interval<bounds::Closed<V>, bounds::OpenOrClosed<V>>,
+    pub interval: Interval<bounds::OpenOrClosed<V>, bounds::OpenOrClosed<V>>,
+}
+
+/// Trait for values whose difference gives the width of a [SubInterval].
+pub trait WidthValue: Clone + PartialOrd {
+    /// Type of the difference between two values.
+    type Width;
+
+    /// Returns `right - left`, or `None` if it overflows.
+    fn checked_width_between(left: &Self, right: &Self) -> Option<Self::Width>;
 }
 
+macro_rules! impl_width_int {
+    ($($t:ty),+) => {
+        $(
+            impl WidthValue for $t {
+                type Width = $t;
+
+                fn checked_width_between(left: &$t, right: &$t) -> Option<$t> { right.checked_sub(*left) }
+            }
+        )+
+    };
+}
+
+impl_width_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_width_float {
+    ($($t:ty),+) => {
+        $(
+            impl WidthValue for $t {
+                type Width = $t;
+
+                fn checked_width_between(left: &$t, right: &$t) -> Option<$t> { Some(right - left) }
+            }
+        )+
+    };
+}
+
+impl_width_float!(f32, f64);
+
 impl<V: PartialOrd + Clone> SubInterval<V> {
+    /// Returns the width of the subinterval.
+    ///
+    /// __Note__: for integer value types prefer [SubInterval::checked_width],
+    /// which fails loudly rather than wrapping on overflow.
     pub fn width(&self) -> V::Output
     where
         V: std::ops::Sub,
     {
-        let right = match self.interval.right.clone() {
-            bounds::OpenOrClosed::Open(right) => right,
-            bounds::OpenOrClosed::Closed(right) => right,
-        };
+        let left = self.interval.left.proper_value().clone();
+        let right = self.interval.right.proper_value().clone();
 
-        right - self.interval.left.0.clone()
+        right - left
+    }
+
+    /// Returns the width of the subinterval, or `None` on overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::partitions::{Edges, Partition, Uniform};
+    /// let sub = Uniform::new(2, 0i8, 100).subinterval(1).unwrap();
+    ///
+    /// assert_eq!(sub.checked_width(), Some(50));
+    ///
+    /// let edges = Edges::new(vec![i8::MIN, 0, i8::MAX]).unwrap();
+    ///
+    /// assert_eq!(edges.subinterval(0).unwrap().checked_width(), None);
+    /// assert_eq!(edges.subinterval(1).unwrap().checked_width(), Some(i8::MAX));
+    /// ```
+    pub fn checked_width(&self) -> Option<V::Width>
+    where
+        V: WidthValue,
+    {
+        V::checked_width_between(self.interval.left.proper_value(), self.interval.right.proper_value())
     }
 
     pub fn midpoint(&self) -> V
@@ -54,12 +247,56 @@ impl<V: PartialOrd + Clone> SubInterval<V> {
         V: std::ops::Add<Output = V> + std::ops::Div<Output = V> + num_traits::One,
     {
         let two = V::one() + V::one();
-        let right = match self.interval.right.clone() {
-            bounds::OpenOrClosed::Open(right) => right,
-            bounds::OpenOrClosed::Closed(right) => right,
+        let left = self.interval.left.proper_value().clone();
+        let right = self.interval.right.proper_value().clone();
+
+        (left + right) / two
+    }
+}
+
+/// Type representing a single subinterval of a partition, with bounds
+/// borrowed from the partition where possible.
+///
+/// This is the counterpart of [SubInterval] returned by
+/// [Partition::digitise_ref]: partitions that store their edges lend them out
+/// rather than cloning, while those that compute their edges on the fly (e.g.
+/// [Uniform]) yield owned values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubIntervalRef<'a, V: PartialOrd + Clone> {
+    /// The index of the subinterval.
+    pub index: usize,
+
+    /// The interval corresponding to this subinterval.
+    pub interval: Interval<bounds::OpenOrClosed<Cow<'a, V>>, bounds::OpenOrClosed<Cow<'a, V>>>,
+}
+
+impl<'a, V: PartialOrd + Clone> SubIntervalRef<'a, V> {
+    /// Converts the subinterval into an owned [SubInterval], cloning any
+    /// borrowed bounds.
+    pub fn into_owned(self) -> SubInterval<V> {
+        let owned = |b: bounds::OpenOrClosed<Cow<'a, V>>| match b {
+            bounds::OpenOrClosed::Open(x) => bounds::OpenOrClosed::Open(x.into_owned()),
+            bounds::OpenOrClosed::Closed(x) => bounds::OpenOrClosed::Closed(x.into_owned()),
+        };
+
+        SubInterval {
+            index: self.index,
+            interval: Interval::new_unchecked(owned(self.interval.left), owned(self.interval.right)),
+        }
+    }
+}
+
+impl<'a, V: PartialOrd + Clone> From<SubInterval<V>> for SubIntervalRef<'a, V> {
+    fn from(sub: SubInterval<V>) -> Self {
+        let owned = |b: bounds::OpenOrClosed<V>| match b {
+            bounds::OpenOrClosed::Open(x) => bounds::OpenOrClosed::Open(Cow::Owned(x)),
+            bounds::OpenOrClosed::Closed(x) => bounds::OpenOrClosed::Closed(Cow::Owned(x)),
         };
 
-        (self.interval.left.0.clone() + right) / two
+        SubIntervalRef {
+            index: sub.index,
+            interval: Interval::new_unchecked(owned(sub.interval.left), owned(sub.interval.right)),
+        }
     }
 }
 
@@ -71,16 +308,51 @@ pub trait Partition {
     /// Return the number of subintervals in the partition.
     fn len(&self) -> usize;
 
+    /// Return true if the partition contains no subintervals.
+    fn is_empty(&self) -> bool { self.len() == 0 }
+
     /// Compute the index of the subinterval associated with the given value.
     fn index(&self, value: &Self::Value) -> Option<usize>;
 
+    /// Compute the index of the subinterval associated with the given value,
+    /// or the reason that there is none.
+    ///
+    /// The default implementation defers to [Partition::index], comparing the
+    /// value against the left edge of the first subinterval on failure; any
+    /// comparable value not below it is taken to lie above the partition.
+    /// Empty partitions report [OutOfRange::Incomparable].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::partitions::*;
+    /// let partition = Uniform::new(4, 0.0, 1.0);
+    ///
+    /// assert_eq!(partition.try_index(&0.5), Ok(2));
+    /// assert_eq!(partition.try_index(&-0.5), Err(OutOfRange::Below));
+    /// assert_eq!(partition.try_index(&1.5), Err(OutOfRange::Above));
+    /// assert_eq!(partition.try_index(&f64::NAN), Err(OutOfRange::Incomparable));
+    /// ```
+    fn try_index(&self, value: &Self::Value) -> Result<usize, OutOfRange> {
+        if let Some(k) = self.index(value) { return Ok(k); }
+
+        let left = self.subinterval(0).and_then(|s| s.interval.left.into_value());
+
+        match left.and_then(|l| value.partial_cmp(&l)) {
+            None => Err(OutOfRange::Incomparable),
+            Some(std::cmp::Ordering::Less) => Err(OutOfRange::Below),
+            Some(_) => Err(OutOfRange::Above),
+        }
+    }
+
     /// Return the kth subinterval of the partition.
     fn subinterval(&self, k: usize) -> Option<SubInterval<Self::Value>>;
 
     /// Return the subinterval to which the given value belongs.
     ///
-    /// Note: the corresponding subintervals are taken to be closed on the left
-    /// and open on the right.
+    /// Note: which subinterval owns an edge shared by two subintervals is
+    /// determined by the partition's [EdgePolicy].
     ///
     /// # Examples
     ///
@@ -98,10 +370,105 @@ pub trait Partition {
     fn digitise(&self, value: &Self::Value) -> Option<SubInterval<Self::Value>> {
         self.index(value).and_then(|k| self.subinterval(k))
     }
+
+    /// Return the kth subinterval of the partition, borrowing its bounds from
+    /// the partition where possible.
+    ///
+    /// The default implementation wraps [Partition::subinterval]; partitions
+    /// that store their edges override it to avoid cloning them.
+    fn subinterval_ref(&self, k: usize) -> Option<SubIntervalRef<'_, Self::Value>>
+    where
+        Self::Value: Clone,
+    {
+        self.subinterval(k).map(SubIntervalRef::from)
+    }
+
+    /// Return the subinterval to which the given value belongs, borrowing its
+    /// bounds from the partition where possible; see [Partition::digitise].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate intervals;
+    /// # use intervals::{bounds::ProperBound, partitions::*};
+    /// let partition = Edges::new(vec![
+    ///     String::from("apple"), String::from("mango"), String::from("zebra"),
+    /// ]).unwrap();
+    ///
+    /// let sub = partition.digitise_ref(&String::from("kiwi")).unwrap();
+    ///
+    /// assert_eq!(sub.index, 0);
+    /// assert_eq!(sub.interval.left.proper_value().as_str(), "apple");
+    /// assert_eq!(sub.into_owned(), partition.digitise(&String::from("kiwi")).unwrap());
+    /// ```
+    fn digitise_ref(&self, value: &Self::Value) -> Option<SubIntervalRef<'_, Self::Value>>
+    where
+        Self::Value: Clone,
+    {
+        self.index(value).and_then(|k| self.subinterval_ref(k))
+    }
+}
+
+macro_rules! impl_partition_deref {
+    ($($ptr:ty),+) => {
+        $(
+            impl<P: Partition + ?Sized> Partition for $ptr {
+                type Value = P::Value;
+
+                fn len(&self) -> usize { (**self).len() }
+
+                fn is_empty(&self) -> bool { (**self).is_empty() }
+
+                fn index(&self, value: &P::Value) -> Option<usize> { (**self).index(value) }
+
+                fn try_index(&self, value: &P::Value) -> Result<usize, OutOfRange> {
+                    (**self).try_index(value)
+                }
+
+                fn subinterval(&self, k: usize) -> Option<SubInterval<P::Value>> {
+                    (**self).subinterval(k)
+                }
+
+                fn digitise(&self, value: &P::Value) -> Option<SubInterval<P::Value>> {
+                    (**self).digitise(value)
+                }
+
+                fn subinterval_ref(&self, k: usize) -> Option<SubIntervalRef<'_, P::Value>>
+                where
+                    P::Value: Clone,
+                {
+                    (**self).subinterval_ref(k)
+                }
+
+                fn digitise_ref(&self, value: &P::Value) -> Option<SubIntervalRef<'_, P::Value>>
+                where
+                    P::Value: Clone,
+                {
+                    (**self).digitise_ref(value)
+                }
+            }
+        )+
+    };
 }
 
+impl_partition_deref!(&P, &mut P, Box<P>, std::rc::Rc<P>, std::sync::Arc<P>);
+
+#[cfg(feature = "chrono")]
+mod calendar;
+#[cfg(feature = "chrono")]
+pub use self::calendar::{Calendar, CalendarUnit};
+
 mod declarative;
 pub use self::declarative::Declarative;
 
+mod edges;
+pub use self::edges::{Edges, from_cdf_inverse};
+
+mod histogram;
+pub use self::histogram::Histogram;
+
+mod natural_breaks;
+pub use self::natural_breaks::NaturalBreaks;
+
 mod uniform;
 pub use self::uniform::Uniform;
@@ -0,0 +1,270 @@
+use num_traits::ToPrimitive;
+use std::cmp::Ordering;
+use super::{EdgePolicy, Edges, OutOfRange, Partition, SubInterval, SubIntervalRef, PartitionError};
+
+/// Type representing a Jenks natural breaks partition of a sample.
+///
+/// The breaks minimise the total within-bin sum of squared deviations, as
+/// found by the exact dynamic-programming algorithm of Fisher (1958). The
+/// edges are the minimum of the sample, the greatest value of each bin, and
+/// the maximum of the sample; the partition uses [EdgePolicy::RightClosed],
+/// such that every sample value falls in the bin to which it was assigned.
+///
+/// Equal values are always assigned to the same bin, so the number of bins
+/// may not exceed the number of distinct values. Ties between equally good
+/// sets of breaks are resolved in favour of the earliest breaks, making the
+/// result deterministic.
+///
+/// # Examples
+/// ```
+/// # use intervals::partitions::{NaturalBreaks, Partition};
+/// let data = [1.3, 7.1, 7.3, 2.3, 3.9, 4.1, 7.8, 1.2, 4.3, 7.3, 5.0, 4.3];
+/// let partition = NaturalBreaks::from_sample(&data, 3).unwrap();
+///
+/// assert_eq!(partition.edges(), &[1.2, 2.3, 5.0, 7.8]);
+/// assert_eq!(partition.index(&4.1), Some(1));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(
+        crate = "serde_crate",
+        bound(deserialize = "V: PartialOrd + ::serde_crate::Deserialize<'de>")
+    )
+)]
+pub struct NaturalBreaks<V>(Edges<V>);
+
+impl<V: Clone + PartialOrd + ToPrimitive> NaturalBreaks<V> {
+    /// Construct the natural breaks partition of `data` into `n_bins` bins.
+    ///
+    /// Yields [PartitionError::IllFormedBounds] (holding the sample) if
+    /// `n_bins` is zero or exceeds the number of distinct values, or if any
+    /// value is incomparable (e.g. NaN) or not representable as an `f64`.
+    pub fn from_sample(data: &[V], n_bins: usize) -> Result<Self, PartitionError<Vec<V>>> {
+        let ill_formed = || PartitionError::IllFormedBounds(data.to_vec());
+
+        let mut sorted = data.to_vec();
+
+        if sorted.iter().any(|x| x.partial_cmp(x).is_none()) { return Err(ill_formed()); }
+
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        // Distinct values, with the number of times each occurs.
+        let mut values: Vec<(V, f64, f64)> = Vec::new();
+
+        for x in sorted {
+            match values.last_mut() {
+                Some((ref last, _, ref mut count)) if *last == x => *count += 1.0,
+                _ => {
+                    let xf = x.to_f64().ok_or_else(ill_formed)?;
+
+                    values.push((x, xf, 1.0));
+                },
+            }
+        }
+
+        let m = values.len();
+
+        if n_bins == 0 || n_bins > m { return Err(ill_formed()); }
+
+        // Prefix sums of the weights, weighted values and weighted squares.
+        let mut w = vec![0.0; m + 1];
+        let mut s1 = vec![0.0; m + 1];
+        let mut s2 = vec![0.0; m + 1];
+
+        for (i, &(_, x, c)) in values.iter().enumerate() {
+            w[i + 1] = w[i] + c;
+            s1[i + 1] = s1[i] + c * x;
+            s2[i + 1] = s2[i] + c * x * x;
+        }
+
+        // Sum of squared deviations of the distinct values i..j (exclusive).
+        let ssd = |i: usize, j: usize| {
+            let (w, s1, s2) = (w[j] - w[i], s1[j] - s1[i], s2[j] - s2[i]);
+
+            (s2 - s1 * s1 / w).max(0.0)
+        };
+
+        // cost[j] is the least cost of splitting the first j distinct values
+        // into the current number of bins; start[k][j] is the index at which
+        // the last of those bins begins.
+        let mut cost: Vec<f64> = (0..=m).map(|j| if j == 0 { 0.0 } else { ssd(0, j) }).collect();
+        let mut start = vec![vec![0; m + 1]; n_bins];
+
+        for (k, start_k) in start.iter_mut().enumerate().skip(1) {
+            let mut next = vec![f64::INFINITY; m + 1];
+
+            for j in (k + 1)..=m {
+                for (i, cost_i) in cost.iter().enumerate().take(j).skip(k) {
+                    let c = cost_i + ssd(i, j);
+
+                    if c < next[j] {
+                        next[j] = c;
+                        start_k[j] = i;
+                    }
+                }
+            }
+
+            cost = next;
+        }
+
+        // Backtrack to recover the greatest value of each bin.
+        let mut edges = vec![values[m - 1].0.clone(); n_bins + 1];
+        let mut j = m;
+
+        for k in (1..n_bins).rev() {
+            j = start[k][j];
+            edges[k] = values[j - 1].0.clone();
+        }
+
+        edges[0] = values[0].0.clone();
+
+        let edges = Edges::new(edges).map_err(|_| ill_formed())?;
+
+        Ok(NaturalBreaks(edges.with_policy(EdgePolicy::RightClosed)))
+    }
+}
+
+impl<V: PartialOrd> NaturalBreaks<V> {
+    /// Returns the edges of the partition.
+    pub fn edges(&self) -> &[V] { self.0.edges() }
+
+    /// Consumes the partition, returning the underlying [Edges] partition.
+    pub fn into_edges(self) -> Edges<V> { self.0 }
+
+    /// Map the edges of the partition through the increasing function `f`;
+    /// see [Edges::map_values].
+    pub fn map_values<W, F>(self, f: F) -> Result<NaturalBreaks<W>, PartitionError<Vec<W>>>
+    where
+        W: PartialOrd,
+        F: Fn(V) -> W,
+    {
+        self.0.map_values(f).map(NaturalBreaks)
+    }
+}
+
+impl<V: PartialOrd + Clone> Partition for NaturalBreaks<V> {
+    type Value = V;
+
+    fn len(&self) -> usize { self.0.len() }
+
+    fn index(&self, value: &V) -> Option<usize> { self.0.index(value) }
+
+    fn try_index(&self, value: &V) -> Result<usize, OutOfRange> { self.0.try_index(value) }
+
+    fn subinterval(&self, k: usize) -> Option<SubInterval<V>> { self.0.subinterval(k) }
+
+    fn subinterval_ref(&self, k: usize) -> Option<SubIntervalRef<'_, V>> { self.0.subinterval_ref(k) }
+}
+
+impl<V: std::fmt::Display> std::fmt::Display for NaturalBreaks<V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result { self.0.fmt(f) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Exhaustive search over all splits of the sorted data into three bins.
+    fn brute_force(data: &[f64]) -> [f64; 4] {
+        let mut x = data.to_vec();
+        x.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let ssd = |s: &[f64]| {
+            let mean = s.iter().sum::<f64>() / s.len() as f64;
+
+            s.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>()
+        };
+
+        let mut best = (f64::INFINITY, 0, 0);
+
+        for i in 1..x.len() {
+            for j in (i + 1)..x.len() {
+                if x[i - 1] == x[i] || x[j - 1] == x[j] { continue; }
+
+                let c = ssd(&x[..i]) + ssd(&x[i..j]) + ssd(&x[j..]);
+
+                if c < best.0 - 1e-12 { best = (c, i, j); }
+            }
+        }
+
+        [x[0], x[best.1 - 1], x[best.2 - 1], x[x.len() - 1]]
+    }
+
+    #[test]
+    fn test_published() {
+        let data = [1.3, 7.1, 7.3, 2.3, 3.9, 4.1, 7.8, 1.2, 4.3, 7.3, 5.0, 4.3];
+        let p = NaturalBreaks::from_sample(&data, 3).unwrap();
+
+        assert_eq!(p.edges(), &[1.2, 2.3, 5.0, 7.8]);
+        assert_eq!(p.edges(), &brute_force(&data));
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        let mut state = 7u64;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+
+            (state >> 40) as f64 / 1000.0
+        };
+
+        for n in 3..20 {
+            let data: Vec<f64> = (0..n).map(|_| next()).collect();
+            let p = NaturalBreaks::from_sample(&data, 3).unwrap();
+
+            assert_eq!(p.edges(), &brute_force(&data));
+        }
+    }
+
+    #[test]
+    fn test_samples_digitise() {
+        let data = [5, 1, 1, 1, 9, 2, 2, 8, 8, 8, 8, 20, 21, 1, 5];
+
+        // There are seven distinct values.
+        assert!(NaturalBreaks::from_sample(&data, 8).is_err());
+
+        for k in 1..=7 {
+            let p = NaturalBreaks::from_sample(&data, k).unwrap();
+
+            assert_eq!(p.len(), k);
+
+            for x in data.iter() {
+                let sub = p.digitise(x).unwrap();
+
+                assert!(sub.interval.contains(*x));
+            }
+        }
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let data = [3, 3, 3, 3, 3, 7, 7, 7];
+        let p = NaturalBreaks::from_sample(&data, 2).unwrap();
+
+        assert_eq!(p.edges(), &[3, 3, 7]);
+        assert_eq!(p.index(&3), Some(0));
+        assert_eq!(p.index(&7), Some(1));
+
+        let p = NaturalBreaks::from_sample(&data, 1).unwrap();
+
+        assert_eq!(p.edges(), &[3, 7]);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        // Both {0} | {1, 2} and {0, 1} | {2} are optimal; the earliest wins.
+        let p = NaturalBreaks::from_sample(&[0.0, 1.0, 2.0], 2).unwrap();
+
+        assert_eq!(p.edges(), &[0.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(NaturalBreaks::from_sample(&[1, 2, 3], 0).is_err());
+        assert!(NaturalBreaks::from_sample(&[1, 2, 2, 3], 4).is_err());
+        assert!(NaturalBreaks::<i32>::from_sample(&[], 1).is_err());
+        assert!(NaturalBreaks::from_sample(&[1.0, f64::NAN, 3.0], 2).is_err());
+    }
+}
@@ -1,6 +1,6 @@
-use crate::bounds;
 use num_traits::{Num, NumCast};
-use super::{Partition, SubInterval};
+use std::cmp::Ordering;
+use super::{EdgePolicy, Edges, OutOfRange, Partition, PartitionError, SubInterval};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(
@@ -12,15 +12,16 @@ use super::{Partition, SubInterval};
 ///
 /// # Examples
 /// ```
-/// # use intervals::partitions::{Partition, Uniform};
-/// let partition = Uniform {
-///     size: 5,
-///     left: 0.0,
-///     right: 1.0,
-/// };
+/// # use intervals::partitions::{EdgePolicy, Partition, Uniform};
+/// let partition = Uniform::new(5, 0.0, 1.0);
 ///
 /// assert_eq!(partition.index(&0.2), Some(1));
 /// assert_eq!(partition.index(&0.7), Some(3));
+///
+/// let partition = partition.with_policy(EdgePolicy::RightClosed);
+///
+/// assert_eq!(partition.index(&0.2), Some(0));
+/// assert_eq!(partition.index(&0.7), Some(3));
 /// ```
 pub struct Uniform<V> {
     /// The number of partitions in the partitioning.
@@ -31,6 +32,22 @@ pub struct Uniform<V> {
 
     /// The right side of the interval.
     pub right: V,
+
+    /// The ownership convention for edges between subintervals.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub policy: EdgePolicy,
+}
+
+impl<V> Uniform<V> {
+    /// Construct a uniform partition with the default [EdgePolicy].
+    pub fn new(size: usize, left: V, right: V) -> Self {
+        Uniform { size, left, right, policy: EdgePolicy::default(), }
+    }
+
+    /// Returns the partition with the given [EdgePolicy].
+    pub fn with_policy(self, policy: EdgePolicy) -> Self {
+        Uniform { policy, ..self }
+    }
 }
 
 impl<V: Clone + Num + NumCast> Uniform<V> {
@@ -39,6 +56,70 @@ impl<V: Clone + Num + NumCast> Uniform<V> {
 
         range / NumCast::from(self.size).unwrap()
     }
+
+    // Returns the kth edge of the partition, with the last edge exactly `right`.
+    pub(crate) fn edge(&self, k: usize) -> V {
+        if k >= self.size {
+            self.right.clone()
+        } else {
+            self.left.clone() + self.partition_width() * NumCast::from(k).unwrap()
+        }
+    }
+}
+
+impl<V: Clone + PartialOrd + Num + NumCast> Uniform<V> {
+    /// Materialise the partition into explicit edges.
+    ///
+    /// The `k`th edge is `left + k * w`, where `w` is the
+    /// [partition width](Uniform::partition_width), except for the last which
+    /// is exactly `right`. The edge policy is preserved, and both partitions
+    /// assign every value to the same subinterval.
+    ///
+    /// # Examples
+    /// ```
+    /// # use intervals::partitions::Uniform;
+    /// let edges = Uniform::new(4, 0.0, 1.0).to_declarative().unwrap();
+    ///
+    /// assert_eq!(edges.edges(), &[0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// assert!(Uniform::new(0, 0.0, 1.0).to_declarative().is_err());
+    /// ```
+    ///
+    /// A partition of size zero yields [PartitionError::IllFormedBounds], as
+    /// [Edges] partitions require at least two edges.
+    pub fn to_declarative(&self) -> Result<Edges<V>, PartitionError<Vec<V>>> {
+        let edges = (0..=self.size).map(|k| self.edge(k)).collect();
+
+        Edges::new(edges).map(|e| e.with_policy(self.policy))
+    }
+
+    /// Map the edges of the partition through the increasing function `f`.
+    ///
+    /// Only affine maps preserve uniformity, which can't be determined in
+    /// general, so the partition is first materialised into explicit edges
+    /// (see [Uniform::to_declarative]) and the result re-validated as in
+    /// [Edges::map_values]. Affine maps can be converted back with
+    /// [Edges::to_uniform].
+    ///
+    /// # Examples
+    /// ```
+    /// # use intervals::partitions::Uniform;
+    /// let seconds = Uniform::new(4, 0.0, 2.0);
+    /// let millis = seconds.map_values(|s| s * 1000.0).unwrap();
+    ///
+    /// assert_eq!(millis.edges(), &[0.0, 500.0, 1000.0, 1500.0, 2000.0]);
+    /// assert_eq!(millis.to_uniform(1e-9), Some(Uniform::new(4, 0.0, 2000.0)));
+    /// ```
+    ///
+    /// A partition of size zero yields [PartitionError::IllFormedBounds].
+    pub fn map_values<W, F>(self, f: F) -> Result<Edges<W>, PartitionError<Vec<W>>>
+    where
+        W: PartialOrd,
+        F: Fn(V) -> W,
+    {
+        let edges = (0..=self.size).map(|k| f(self.edge(k))).collect();
+
+        Edges::new(edges).map(|e| e.with_policy(self.policy))
+    }
 }
 
 impl<V: Clone + PartialOrd + Num + NumCast> Partition for Uniform<V> {
@@ -46,37 +127,37 @@ impl<V: Clone + PartialOrd + Num + NumCast> Partition for Uniform<V> {
 
     fn len(&self) -> usize { self.size }
 
-    fn index(&self, value: &V) -> Option<usize> {
-        let value = value.clone();
+    fn index(&self, value: &V) -> Option<usize> { self.try_index(value).ok() }
 
-        if value < self.left || value > self.right {
-            return None
+    fn try_index(&self, value: &V) -> Result<usize, OutOfRange> {
+        match (value.partial_cmp(&self.left), value.partial_cmp(&self.right)) {
+            (None, _) | (_, None) => return Err(OutOfRange::Incomparable),
+            (Some(Ordering::Less), _) => return Err(OutOfRange::Below),
+            (_, Some(Ordering::Greater)) => return Err(OutOfRange::Above),
+            _ => {},
         }
 
-        if value == self.right {
-            return Some(self.size - 1)
-        }
+        let diff = value.clone() - self.left.clone();
+        let k: usize = NumCast::from(diff / self.partition_width())
+            .ok_or(OutOfRange::Incomparable)?;
+        let k = k.min(self.size - 1);
 
-        let diff = value - self.left.clone();
-        let width = self.partition_width();
-
-        NumCast::from(diff / width.clone())
+        // Correct for rounding such that the index agrees with the edges used
+        // by the subintervals.
+        Ok(match self.policy {
+            EdgePolicy::LeftClosed if k > 0 && *value < self.edge(k) => k - 1,
+            EdgePolicy::LeftClosed if k + 1 < self.size && *value >= self.edge(k + 1) => k + 1,
+            EdgePolicy::RightClosed if k > 0 && *value <= self.edge(k) => k - 1,
+            EdgePolicy::RightClosed if k + 1 < self.size && *value > self.edge(k + 1) => k + 1,
+            _ => k,
+        })
     }
 
     fn subinterval(&self, k: usize) -> Option<SubInterval<V>> {
         if k < self.size {
-            let width = self.partition_width();
-
             Some(SubInterval {
                 index: k,
-                interval: crate::Interval {
-                    left: bounds::Closed(self.left.clone()),
-                    right: if k == self.size - 1 {
-                        bounds::OpenOrClosed::Closed(self.left.clone() + width)
-                    } else {
-                        bounds::OpenOrClosed::Open(self.left.clone() + width)
-                    },
-                },
+                interval: self.policy.subinterval(k, self.size, self.edge(k), self.edge(k + 1)),
             })
         } else {
             None
@@ -97,14 +178,11 @@ impl<V: std::fmt::Display> std::fmt::Display for Uniform<V> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bounds::ProperBound;
 
     #[test]
     fn test_index() {
-        let d = Uniform {
-            size: 5,
-            left: 0.0f64,
-            right: 5.0f64,
-        };
+        let d = Uniform::new(5, 0.0f64, 5.0f64);
 
         assert!(d.index(&-1.0).is_none());
         assert!(d.index(&6.0).is_none());
@@ -116,4 +194,173 @@ mod tests {
         assert_eq!(d.index(&4.0).unwrap(), 4);
         assert_eq!(d.index(&5.0).unwrap(), 4);
     }
+
+    #[test]
+    fn test_try_index() {
+        let d = Uniform::new(5, 0.0f64, 5.0f64);
+
+        assert_eq!(d.try_index(&-1.0), Err(OutOfRange::Below));
+        assert_eq!(d.try_index(&-1e-12), Err(OutOfRange::Below));
+        assert_eq!(d.try_index(&0.0), Ok(0));
+        assert_eq!(d.try_index(&5.0), Ok(4));
+        assert_eq!(d.try_index(&(5.0 + 1e-12)), Err(OutOfRange::Above));
+        assert_eq!(d.try_index(&f64::INFINITY), Err(OutOfRange::Above));
+        assert_eq!(d.try_index(&f64::NAN), Err(OutOfRange::Incomparable));
+
+        let d = Uniform::new(4, 10u32, 18u32);
+
+        assert_eq!(d.try_index(&9), Err(OutOfRange::Below));
+        assert_eq!(d.try_index(&10), Ok(0));
+        assert_eq!(d.try_index(&17), Ok(3));
+        assert_eq!(d.try_index(&18), Ok(3));
+        assert_eq!(d.try_index(&19), Err(OutOfRange::Above));
+    }
+
+    #[test]
+    fn test_subinterval() {
+        let d = Uniform::new(4, 0.0f64, 2.0f64);
+
+        assert_eq!(d.subinterval(0).unwrap().interval, crate::Interval::lcro_unchecked(0.0, 0.5));
+        assert_eq!(d.subinterval(2).unwrap().interval, crate::Interval::lcro_unchecked(1.0, 1.5));
+        assert_eq!(d.subinterval(3).unwrap().interval, crate::Interval::closed_unchecked(1.5, 2.0));
+        assert!(d.subinterval(4).is_none());
+
+        let d = d.with_policy(EdgePolicy::RightClosed);
+
+        assert_eq!(d.subinterval(0).unwrap().interval, crate::Interval::closed_unchecked(0.0, 0.5));
+        assert_eq!(d.subinterval(2).unwrap().interval, crate::Interval::lorc_unchecked(1.0, 1.5));
+        assert_eq!(d.subinterval(3).unwrap().interval, crate::Interval::lorc_unchecked(1.5, 2.0));
+    }
+
+    #[test]
+    fn test_policies_at_edges() {
+        let lc = Uniform::new(5, 0.0f64, 5.0f64);
+        let rc = lc.with_policy(EdgePolicy::RightClosed);
+
+        // The outermost cells absorb the boundary values.
+        assert_eq!(lc.index(&0.0), Some(0));
+        assert_eq!(rc.index(&0.0), Some(0));
+        assert_eq!(lc.index(&5.0), Some(4));
+        assert_eq!(rc.index(&5.0), Some(4));
+
+        // Interior edges belong to the cell on their right or left.
+        for k in 1..5 {
+            let edge = k as f64;
+
+            assert_eq!(lc.index(&edge), Some(k));
+            assert_eq!(rc.index(&edge), Some(k - 1));
+
+            assert_eq!(lc.digitise(&edge).unwrap().interval.left.proper_value(), &edge);
+            assert_eq!(rc.digitise(&edge).unwrap().interval.right.proper_value(), &edge);
+        }
+
+        // Away from the edges the policies agree.
+        for &v in [0.5, 1.5, 2.5, 3.5, 4.5].iter() {
+            assert_eq!(lc.index(&v), rc.index(&v));
+        }
+    }
+
+    #[test]
+    fn test_index_consistent_with_subinterval() {
+        for &policy in [EdgePolicy::LeftClosed, EdgePolicy::RightClosed].iter() {
+            let d = Uniform::new(7, -0.3f64, 1.1f64).with_policy(policy);
+
+            for i in 0..=1000 {
+                let v = -0.3 + 1.4 * (i as f64) / 1000.0;
+                let k = d.index(&v).unwrap();
+                let sub = d.subinterval(k).unwrap().interval;
+
+                assert!(sub.contains(v), "{} not in {}", v, sub);
+                assert!(*sub.left.proper_value() <= v);
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_declarative() {
+        for &policy in [EdgePolicy::LeftClosed, EdgePolicy::RightClosed].iter() {
+            let d = Uniform::new(7, -0.3f64, 1.1f64).with_policy(policy);
+            let e = d.to_declarative().unwrap();
+
+            assert_eq!(e.len(), 7);
+            assert_eq!(e.edges().len(), 8);
+            assert_eq!(e.edges()[0], -0.3);
+            assert_eq!(e.edges()[7], 1.1);
+            assert!(e.edges().windows(2).all(|w| w[0] < w[1]));
+
+            for i in -10..=1010 {
+                let v = -0.3 + 1.4 * (i as f64) / 1000.0;
+
+                assert_eq!(d.try_index(&v), e.try_index(&v));
+            }
+
+            for k in 0..7 {
+                assert_eq!(d.subinterval(k), e.subinterval(k));
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_declarative_int() {
+        let d = Uniform::new(3, 0u32, 10);
+        let e = d.to_declarative().unwrap();
+
+        assert_eq!(e.edges(), &[0, 3, 6, 10]);
+
+        for v in 0..=12 {
+            assert_eq!(d.index(&v), e.index(&v));
+        }
+    }
+
+    #[test]
+    fn test_uniform_round_trip() {
+        let d = Uniform::new(5, 0.0f64, 1.0f64).with_policy(EdgePolicy::RightClosed);
+        let e = d.to_declarative().unwrap();
+
+        assert!(e.is_uniform(0.0));
+        assert_eq!(e.to_uniform(1e-12), Some(d));
+
+        let e = Edges::new(vec![0.0, 0.1, 1.0]).unwrap();
+
+        assert!(!e.is_uniform(0.1));
+        assert!(e.to_uniform(0.1).is_none());
+    }
+
+    #[test]
+    fn test_map_values_affine() {
+        let d = Uniform::new(5, -1.0f64, 1.0f64).with_policy(EdgePolicy::RightClosed);
+        let e = d.map_values(|x| 3.0 * x + 2.0).unwrap();
+
+        assert!(e.is_uniform(1e-12));
+        assert_eq!(e.to_uniform(1e-12), Some(Uniform::new(5, -1.0, 5.0).with_policy(EdgePolicy::RightClosed)));
+    }
+
+    #[test]
+    fn test_map_values_log() {
+        let d = Uniform::new(3, 1.0f64, 4.0f64);
+        let e = d.map_values(f64::ln).unwrap();
+
+        assert_eq!(e.edges(), &[0.0, 2.0f64.ln(), 3.0f64.ln(), 4.0f64.ln()]);
+        assert!(!e.is_uniform(1e-3));
+
+        for &x in [1.0, 1.5, 2.0, 3.9, 4.0].iter() {
+            assert_eq!(d.index(&x), e.index(&x.ln()));
+        }
+    }
+
+    #[test]
+    fn test_map_values_empty() {
+        let d = Uniform::new(0, 0.0f64, 1.0f64);
+
+        assert!(matches!(d.map_values(|x| x * 2.0), Err(PartitionError::IllFormedBounds(_))));
+        assert!(matches!(d.to_declarative(), Err(PartitionError::IllFormedBounds(_))));
+    }
+
+    #[test]
+    fn test_map_values_decreasing() {
+        let d = Uniform::new(3, 0.0f64, 3.0f64);
+
+        assert!(matches!(d.map_values(|x| -x), Err(PartitionError::UnorderedBounds(_, 0))));
+        assert!(matches!(d.map_values(|x| (x - 1.5).powi(2)), Err(PartitionError::UnorderedBounds(_, 0))));
+    }
 }
@@ -0,0 +1,189 @@
+//! Module containing support for sampling values from intervals.
+//!
+//! Requires the `rand` feature.
+//!
+//! # Examples
+//! ```
+//! # extern crate intervals;
+//! # extern crate rand;
+//! # use intervals::Interval;
+//! use rand::{SeedableRng, rngs::SmallRng};
+//!
+//! let mut rng = SmallRng::seed_from_u64(0);
+//! let x = Interval::lcro_unchecked(0.0, 1.0).sample(&mut rng);
+//!
+//! assert!(0.0 <= x && x < 1.0);
+//! ```
+use crate::{Interval, bounds::ProperBound, float::FloatOrdinal};
+use rand::{Rng, RngCore, distributions::uniform::SampleRange};
+
+/// Trait for types that can be sampled uniformly between two bounds.
+///
+/// Implemented for the primitive integer and floating-point types.
+pub trait UniformValue: PartialOrd + Sized {
+    /// Returns true if there are no values between `low` and `high`,
+    /// excluding each of them if open.
+    fn is_empty_between(low: &Self, low_open: bool, high: &Self, high_open: bool) -> bool;
+
+    /// Draw a value uniformly from between `low` and `high`, excluding each
+    /// of them if open, or return `None` if there are no such values.
+    fn sample_between<G: Rng + ?Sized>(
+        rng: &mut G,
+        low: &Self,
+        low_open: bool,
+        high: &Self,
+        high_open: bool,
+    ) -> Option<Self>;
+}
+
+macro_rules! impl_uniform_int {
+    ($($t:ty),+) => {
+        $(
+            impl UniformValue for $t {
+                fn is_empty_between(low: &$t, low_open: bool, high: &$t, high_open: bool) -> bool {
+                    match (low_open, high_open) {
+                        (false, false) => low > high,
+                        (true, true) => low.checked_add(1).map_or(true, |next| next >= *high),
+                        _ => low >= high,
+                    }
+                }
+
+                fn sample_between<G: Rng + ?Sized>(
+                    rng: &mut G,
+                    low: &$t,
+                    low_open: bool,
+                    high: &$t,
+                    high_open: bool,
+                ) -> Option<$t> {
+                    if Self::is_empty_between(low, low_open, high, high_open) { return None; }
+
+                    let low = if low_open { low + 1 } else { *low };
+                    let high = if high_open { high - 1 } else { *high };
+
+                    Some(rng.gen_range(low..=high))
+                }
+            }
+        )+
+    };
+}
+
+impl_uniform_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+// Floats are drawn from `[low, high)` or `[low, high]`, redrawing `low` if
+// the left bound is open; emptiness is decided exactly by counting the
+// representable values first.
+macro_rules! impl_uniform_float {
+    ($($t:ty),+) => {
+        $(
+            impl UniformValue for $t {
+                fn is_empty_between(low: &$t, low_open: bool, high: &$t, high_open: bool) -> bool {
+                    if !low.is_finite() || !high.is_finite() { return true; }
+
+                    let ordinals = (low.ordinal().unwrap(), high.ordinal().unwrap());
+
+                    ordinals.1 - ordinals.0 + 1 - (low_open as i64) - (high_open as i64) <= 0
+                }
+
+                fn sample_between<G: Rng + ?Sized>(
+                    rng: &mut G,
+                    low: &$t,
+                    low_open: bool,
+                    high: &$t,
+                    high_open: bool,
+                ) -> Option<$t> {
+                    if Self::is_empty_between(low, low_open, high, high_open) { return None; }
+
+                    let (low, high) = (*low, *high);
+
+                    loop {
+                        let x = if high_open { rng.gen_range(low..high) } else { rng.gen_range(low..=high) };
+
+                        if !(low_open && x == low) { return Some(x); }
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_uniform_float!(f32, f64);
+
+impl<L, R> Interval<L, R>
+where
+    L: ProperBound,
+    R: ProperBound<Value = L::Value>,
+
+    L::Value: UniformValue,
+{
+    /// Draw a value uniformly at random from the interval.
+    ///
+    /// Open bounds are never returned. Only bounded intervals can be sampled;
+    /// for floats, both bounds must also be finite.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval contains no values, or has infinite or NaN
+    /// bounds, as does [Rng::gen_range] for an empty range. Float intervals
+    /// whose width overflows (e.g. `[f64::MIN, f64::MAX]`) also panic.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate intervals;
+    /// # extern crate rand;
+    /// # use intervals::Interval;
+    /// use rand::{SeedableRng, rngs::SmallRng};
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(1);
+    /// let dice = Interval::closed_unchecked(1, 6);
+    ///
+    /// assert!((0..100).map(|_| dice.sample(&mut rng)).all(|x| 1 <= x && x <= 6));
+    /// assert!((0..100).all(|_| Interval::open_unchecked(0, 2).sample(&mut rng) == 1));
+    /// ```
+    pub fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> L::Value {
+        let (low, high) = (self.left.proper_value(), self.right.proper_value());
+
+        L::Value::sample_between(rng, low, self.left.is_open(), high, self.right.is_open())
+            .expect("cannot sample from an empty interval")
+    }
+}
+
+/// Allows bounded intervals to be passed to [Rng::gen_range].
+///
+/// Values are drawn as by [Interval::sample], so open bounds are never
+/// produced. Unbounded intervals cannot be sampled:
+/// ```compile_fail
+/// # extern crate intervals;
+/// # extern crate rand;
+/// # use intervals::Interval;
+/// use rand::{Rng, SeedableRng, rngs::SmallRng};
+///
+/// SmallRng::seed_from_u64(0).gen_range(Interval::left_closed(0.0));
+/// ```
+///
+/// # Examples
+/// ```
+/// # extern crate intervals;
+/// # extern crate rand;
+/// # use intervals::Interval;
+/// use rand::{Rng, SeedableRng, rngs::SmallRng};
+///
+/// let mut rng = SmallRng::seed_from_u64(0);
+/// let x: f64 = rng.gen_range(Interval::lorc_unchecked(0.0, 1.0));
+///
+/// assert!(0.0 < x && x <= 1.0);
+/// ```
+impl<L, R> SampleRange<L::Value> for Interval<L, R>
+where
+    L: ProperBound,
+    R: ProperBound<Value = L::Value>,
+
+    L::Value: UniformValue,
+{
+    fn sample_single<G: RngCore + ?Sized>(self, rng: &mut G) -> L::Value { self.sample(rng) }
+
+    fn is_empty(&self) -> bool {
+        let (low, high) = (self.left.proper_value(), self.right.proper_value());
+
+        L::Value::is_empty_between(low, self.left.is_open(), high, self.right.is_open())
+    }
+}
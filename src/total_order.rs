@@ -0,0 +1,174 @@
+//! Module containing a totally-ordered wrapper for floating-point values.
+//!
+//! Under the standard `PartialOrd` implementation for `f32`/`f64`, `NaN` is
+//! incomparable to everything and `-0.0 == +0.0`. Consequently, validation,
+//! containment and pinching all silently "succeed" or "fail" for NaN
+//! endpoints depending on the order in which comparisons happen to be made.
+//! Wrapping the values in [TotalFloat] replaces this with the IEEE 754
+//! `totalOrder` predicate (as per `f64::total_cmp`), under which:
+//!
+//! - positive `NaN` sorts above `+∞` and negative `NaN` sorts below `-∞`;
+//! - `-0.0` is strictly less than `+0.0`;
+//! - every other pair of values compares as usual.
+//!
+//! # Examples
+//! ```
+//! # use intervals::{Interval, total_order::TotalFloat};
+//! let x = Interval::closed_unchecked(0.0f64, 1.0).total_order();
+//!
+//! assert!(x.contains(TotalFloat(0.0)));
+//! assert!(!x.contains(TotalFloat(-0.0)));
+//! assert!(!x.contains(TotalFloat(f64::NAN)));
+//!
+//! assert!(Interval::closed(f64::NAN, 0.0).is_ok());
+//! assert!(Interval::closed(TotalFloat(f64::NAN), TotalFloat(0.0)).is_err());
+//! ```
+use crate::{Interval, bounds};
+use num_traits::float::TotalOrder;
+use std::{cmp::Ordering, fmt};
+
+/// Wrapper for floating-point values that are ordered by IEEE 754 `totalOrder`.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct TotalFloat<F>(pub F);
+
+impl<F> TotalFloat<F> {
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> F { self.0 }
+}
+
+impl<F> From<F> for TotalFloat<F> {
+    fn from(value: F) -> TotalFloat<F> { TotalFloat(value) }
+}
+
+impl<F: TotalOrder> PartialEq for TotalFloat<F> {
+    fn eq(&self, rhs: &TotalFloat<F>) -> bool {
+        self.0.total_cmp(&rhs.0) == Ordering::Equal
+    }
+}
+
+impl<F: TotalOrder> Eq for TotalFloat<F> {}
+
+impl<F: TotalOrder> PartialOrd for TotalFloat<F> {
+    fn partial_cmp(&self, rhs: &TotalFloat<F>) -> Option<Ordering> { Some(self.cmp(rhs)) }
+}
+
+impl<F: TotalOrder> Ord for TotalFloat<F> {
+    fn cmp(&self, rhs: &TotalFloat<F>) -> Ordering { self.0.total_cmp(&rhs.0) }
+}
+
+impl<F: fmt::Display> fmt::Display for TotalFloat<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.0.fmt(f) }
+}
+
+/// Trait for bounds that can be converted to their totally-ordered form.
+pub trait TotalOrderBound: bounds::Bound {
+    /// The totally-ordered form of the bound.
+    type Output: bounds::Bound<Value = TotalFloat<Self::Value>>;
+
+    /// Returns the bound with its value wrapped in [TotalFloat].
+    fn total_order(self) -> Self::Output;
+}
+
+impl<F: PartialOrd + TotalOrder> TotalOrderBound for bounds::NoBound<F> {
+    type Output = bounds::NoBound<TotalFloat<F>>;
+
+    fn total_order(self) -> Self::Output { bounds::NoBound::new() }
+}
+
+impl<F: PartialOrd + TotalOrder> TotalOrderBound for bounds::Open<F> {
+    type Output = bounds::Open<TotalFloat<F>>;
+
+    fn total_order(self) -> Self::Output { bounds::Open(TotalFloat(self.0)) }
+}
+
+impl<F: PartialOrd + TotalOrder> TotalOrderBound for bounds::Closed<F> {
+    type Output = bounds::Closed<TotalFloat<F>>;
+
+    fn total_order(self) -> Self::Output { bounds::Closed(TotalFloat(self.0)) }
+}
+
+impl<F: PartialOrd + TotalOrder> TotalOrderBound for bounds::OpenOrClosed<F> {
+    type Output = bounds::OpenOrClosed<TotalFloat<F>>;
+
+    fn total_order(self) -> Self::Output {
+        match self {
+            bounds::OpenOrClosed::Open(x) => bounds::OpenOrClosed::Open(TotalFloat(x)),
+            bounds::OpenOrClosed::Closed(x) => bounds::OpenOrClosed::Closed(TotalFloat(x)),
+        }
+    }
+}
+
+impl<L, R> Interval<L, R>
+where
+    L: TotalOrderBound,
+    R: TotalOrderBound<Value = L::Value>,
+{
+    /// Convert to the equivalent interval over totally-ordered values.
+    ///
+    /// __Note__: no validation is performed; see the [module](self) docs for
+    /// where the semantics differ from plain floating-point comparisons.
+    pub fn total_order(self) -> Interval<L::Output, R::Output> {
+        Interval::new_unchecked(self.left.total_order(), self.right.total_order())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bounds::{validate, Closed, Open};
+
+    fn t(x: f64) -> TotalFloat<f64> { TotalFloat(x) }
+
+    #[test]
+    fn test_ordering() {
+        assert!(t(-0.0) < t(0.0));
+        assert!(t(f64::INFINITY) < t(f64::NAN));
+        assert!(t(-f64::NAN) < t(f64::NEG_INFINITY));
+        assert_eq!(t(f64::NAN), t(f64::NAN));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(Closed(f64::NAN), Closed(0.0)).is_ok());
+        assert!(validate(Closed(t(f64::NAN)), Closed(t(0.0))).is_err());
+        assert!(validate(Closed(t(0.0)), Closed(t(f64::NAN))).is_ok());
+
+        assert!(validate(Closed(0.0), Closed(-0.0)).is_ok());
+        assert!(validate(Closed(t(0.0)), Closed(t(-0.0))).is_err());
+        assert!(validate(Closed(t(-0.0)), Closed(t(0.0))).is_ok());
+
+        assert!(validate(Open(-0.0), Open(0.0)).is_err());
+        assert!(validate(Open(t(-0.0)), Open(t(0.0))).is_ok());
+    }
+
+    #[test]
+    fn test_contains() {
+        let x = Interval::closed_unchecked(0.0f64, f64::INFINITY);
+
+        assert!(!x.contains(f64::NAN));
+        assert!(x.contains(-0.0));
+
+        let x = x.total_order();
+
+        assert!(!x.contains(t(f64::NAN)));
+        assert!(!x.contains(t(-0.0)));
+        assert!(x.contains(t(0.0)));
+        assert!(x.contains(t(f64::INFINITY)));
+
+        let x = Interval::left_open(0.0f64).total_order();
+
+        assert!(x.contains(t(f64::NAN)));
+        assert!(!x.contains(t(-f64::NAN)));
+        assert!(!x.contains(t(0.0)));
+
+        let x = Interval::open_unchecked(-0.0f64, 0.0).total_order();
+
+        assert!(!x.contains(t(-0.0)));
+        assert!(!x.contains(t(0.0)));
+    }
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "approx")]
+#[macro_use]
+extern crate approx;
+extern crate intervals;
+
+use intervals::{Interval, bounds::OpenOrClosed};
+
+#[test]
+fn after_set_operations() {
+    let a = Interval::closed_unchecked(0.1 + 0.2, 3.0 * 1.1);
+    let b = Interval::closed_unchecked(0.0, 0.7 * 3.0);
+    let c = Interval::closed_unchecked(-1.0 / 3.0, 0.5);
+
+    let computed = a.intersect(b).unwrap().union_closure(c);
+    let expected = Interval::closed_unchecked(-0.333_333_333_333, 2.1);
+
+    assert_ne!(computed, Interval::closed_unchecked(-1.0 / 3.0, 2.1));
+    assert_relative_eq!(computed, Interval::closed_unchecked(-1.0 / 3.0, 2.1));
+    assert_relative_eq!(computed, expected, epsilon = 1e-12);
+    assert_relative_ne!(computed, expected, epsilon = 1e-14, max_relative = 1e-14);
+}
+
+#[test]
+fn ulps() {
+    let x = Interval::lcro_unchecked(0.1f32 + 0.2, 1.0);
+
+    assert_ulps_eq!(x, Interval::lcro_unchecked(0.3, 1.0));
+    assert_ulps_ne!(x, Interval::lcro_unchecked(0.3001, 1.0));
+}
+
+#[test]
+fn unbounded() {
+    let x = Interval::left_closed(0.1 + 0.2);
+
+    assert_abs_diff_eq!(x, Interval::left_closed(0.3));
+    assert_abs_diff_ne!(x, Interval::left_closed(0.4));
+    assert_abs_diff_eq!(x, Interval::left_closed(0.4), epsilon = 0.2);
+}
+
+#[test]
+fn kinds_must_match() {
+    let open = Interval::new_unchecked(OpenOrClosed::Open(0.3), OpenOrClosed::Closed(1.0));
+    let closed = Interval::new_unchecked(OpenOrClosed::Closed(0.1 + 0.2), OpenOrClosed::Closed(1.0));
+
+    assert_relative_ne!(open, closed, epsilon = 1.0);
+    assert_relative_eq!(closed, Interval::new_unchecked(OpenOrClosed::Closed(0.3), OpenOrClosed::Closed(1.0)));
+}
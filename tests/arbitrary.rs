@@ -0,0 +1,66 @@
+#![cfg(feature = "quickcheck")]
+extern crate intervals;
+extern crate quickcheck;
+
+use intervals::{Interval, bounds::{self, OpenOrClosed}};
+use quickcheck::{Arbitrary, Gen, quickcheck};
+
+type Mixed<V> = Interval<OpenOrClosed<V>, OpenOrClosed<V>>;
+
+fn is_valid<L, R>(x: &Interval<L, R>) -> bool
+where
+    L: bounds::Bound + Clone,
+    R: bounds::Bound<Value = L::Value> + Clone,
+
+    bounds::Validator: bounds::ValidateBounds<L, R>,
+{
+    Interval::new(x.left.clone(), x.right.clone()).is_ok()
+}
+
+#[test]
+fn generates_valid_intervals() {
+    fn prop(a: intervals::Closed<i32>, b: intervals::Open<i8>, c: intervals::LCRO<f64>, d: Mixed<f32>) -> bool {
+        is_valid(&a) && is_valid(&b) && is_valid(&c) && is_valid(&d)
+    }
+
+    quickcheck(prop as fn(_, _, _, _) -> bool);
+}
+
+#[test]
+fn generates_half_bounded_intervals() {
+    fn prop(a: intervals::LeftClosed<f64>, b: intervals::RightOpen<u16>, c: intervals::Unbounded<i64>) -> bool {
+        is_valid(&a) && is_valid(&b) && is_valid(&c)
+    }
+
+    quickcheck(prop as fn(_, _, _) -> bool);
+}
+
+#[test]
+fn shrinks_to_valid_intervals() {
+    let mut g = Gen::new(100);
+
+    for _ in 0..100 {
+        let x = Mixed::<i32>::arbitrary(&mut g);
+
+        assert!(x.shrink().all(|y| is_valid(&y) && y != x));
+    }
+}
+
+#[test]
+fn shrinks_towards_degenerate() {
+    let x = intervals::Closed::closed_unchecked(3i32, 10);
+
+    assert_eq!(x.shrink().next(), Some(intervals::Closed::degenerate(3)));
+}
+
+#[test]
+fn intersection_is_subset() {
+    fn prop(x: intervals::Closed<i32>, y: intervals::LCRO<i32>) -> bool {
+        match x.intersect(y) {
+            Some(z) => x.contains_interval(&z) && y.contains_interval(&z),
+            None => true,
+        }
+    }
+
+    quickcheck(prop as fn(_, _) -> bool);
+}
@@ -0,0 +1,658 @@
+extern crate intervals;
+
+#[macro_use]
+mod common;
+
+use intervals::{Interval, arithmetic::{PadError, Quotient}, bounds::{self, Bound, OpenOrClosed}};
+
+#[test]
+fn scale_about_fixed_point() {
+    for &anchor in &[-1.0, 0.0, 0.5, 2.0] {
+        for &k in &[-2.0, -0.5, 0.5, 1.0, 3.0] {
+            let x = i!(Degenerate[anchor]).scale_about(anchor, k);
+
+            assert_eq!(x, i!(Degenerate[anchor]));
+        }
+    }
+}
+
+#[test]
+fn scale_about_collapse() {
+    assert_eq!(i!(Closed[0.0, 4.0]).scale_about(2.0, 0.0), i!(Degenerate[2.0]));
+    assert_eq!(i!(Closed[-3, 7]).scale_about(2, 0), i!(Degenerate[2]));
+}
+
+#[test]
+fn scale_about_preserves_openness() {
+    assert_eq!(i!(Open[0.0, 1.0]).scale_about(0.5, 2.0), i!(Open[-0.5, 1.5]));
+    assert_eq!(i!(Open[0.0, 1.0]).scale_about(0.5, -2.0), i!(Open[-0.5, 1.5]));
+
+    let x = i!(LCRO[0.0, 1.0]).scale_about(1.0, 2.0);
+
+    assert_eq!(x.left, bounds::Closed(-1.0));
+    assert_eq!(x.right, bounds::Open(1.0));
+
+    let x = i!(LORC[0.0, 1.0]).scale_about(1.0, -2.0);
+
+    assert_eq!(x.left, bounds::Closed(1.0));
+    assert_eq!(x.right, bounds::Open(3.0));
+}
+
+#[test]
+fn scale_about_unbounded() {
+    let x: intervals::Unbounded<f64> = Interval::unbounded();
+
+    assert_eq!(x.scale_about(1.0, -2.0), x);
+}
+
+#[test]
+fn scale_about_half_bounded() {
+    let x = i!(LC[1.0]);
+
+    assert_eq!(x.scale_about(0.0, 2.0), i!(LC[2.0]).into());
+    assert_eq!(x.scale_about(0.0, -2.0), i!(RC[-2.0]).into());
+    assert_eq!(x.scale_about(1.0, 3.0), x.into());
+    assert_eq!(x.scale_about(3.0, 0.0), i!(LC[3.0]).into());
+
+    let y = i!(RO[4]);
+
+    assert_eq!(y.scale_about(2, 3), i!(RO[8]).into());
+    assert_eq!(y.scale_about(2, -1), i!(LO[0]).into());
+    assert_eq!(y * -1, i!(LO[-4]).into());
+}
+
+#[test]
+fn clamp_interval_inside() {
+    let viewport = i!(Closed[0, 10]);
+
+    assert_eq!(viewport.clamp_interval(i!(Open[2, 5])), i!(Open[2, 5]));
+    assert_eq!(viewport.clamp_interval(i!(Closed[0, 10])), viewport);
+    assert_eq!(i!(Open[0, 10]).clamp_interval(i!(Open[0, 10])), i!(Open[0, 10]));
+}
+
+#[test]
+fn clamp_interval_partially_outside() {
+    let viewport = i!(Closed[0, 10]);
+
+    let x = viewport.clamp_interval(i!(Open[-3, 2]));
+
+    assert_eq!(x.left, OpenOrClosed::Closed(0));
+    assert_eq!(x.right, OpenOrClosed::Open(5));
+
+    let x = viewport.clamp_interval(i!(LCRO[8, 14]));
+
+    assert_eq!(x.left, OpenOrClosed::Closed(4));
+    assert_eq!(x.right, OpenOrClosed::Closed(10));
+
+    // A closed endpoint touching an open bound is pinned to it.
+    let x = i!(Open[0, 10]).clamp_interval(i!(Closed[0, 4]));
+
+    assert_eq!(x.left, OpenOrClosed::Open(0));
+    assert_eq!(x.right, OpenOrClosed::Closed(4));
+}
+
+#[test]
+fn clamp_interval_wider_than_viewport() {
+    let viewport = i!(LCRO[0.0, 1.0]);
+
+    assert_eq!(viewport.clamp_interval(i!(Closed[-1.0, 3.0])), viewport);
+    assert_eq!(viewport.clamp_interval(i!(Closed[0.5, 3.0])), viewport);
+    assert_eq!(viewport.clamp_interval(i!(Closed[-3.0, 0.5])), viewport);
+
+    // Equal widths only fit if the openness allows it.
+    assert_eq!(viewport.clamp_interval(i!(Closed[2.0, 3.0])), viewport);
+    assert_eq!(viewport.clamp_interval(i!(LCRO[2.0, 3.0])), viewport);
+}
+
+#[test]
+fn checked_translate_at_limits() {
+    assert_eq!(i!(Closed[0i8, 126]).checked_translate(1), Some(i!(Closed[1, i8::MAX])));
+    assert_eq!(i!(Closed[0i8, i8::MAX]).checked_translate(1), None);
+    assert_eq!(i!(Closed[-127i8, 0]).checked_translate(-1), Some(i!(Closed[i8::MIN, -1])));
+    assert_eq!(i!(Closed[i8::MIN, 0]).checked_translate(-1), None);
+
+    assert_eq!(i!(LO[i8::MAX]).checked_translate(1), None);
+    assert_eq!(i!(RC[i8::MIN]).checked_translate(i8::MAX), Some(i!(RC[-1])));
+}
+
+#[test]
+fn checked_translate_unsigned_underflow() {
+    assert_eq!(i!(LCRO[3u8, 10]).checked_translate_down(3), Some(i!(LCRO[0, 7])));
+    assert_eq!(i!(LCRO[3u8, 10]).checked_translate_down(4), None);
+    assert_eq!(i!(Degenerate[0u8]).checked_translate_down(1), None);
+}
+
+#[test]
+fn checked_translate_unbounded() {
+    let x: intervals::Unbounded<i8> = Interval::unbounded();
+
+    assert_eq!(x.checked_translate(i8::MAX), Some(x));
+    assert_eq!(x.checked_translate_down(i8::MIN), Some(x));
+}
+
+#[test]
+fn checked_scale_at_limits() {
+    assert_eq!(i!(Closed[-64i8, 63]).checked_scale(2).unwrap().ok(), Some(i!(Closed[i8::MIN, 126])));
+    assert!(i!(Closed[-65i8, 0]).checked_scale(2).is_none());
+    assert!(i!(Closed[i8::MIN, 0]).checked_scale(-1).is_none());
+
+    assert!(i!(Closed[1i8, 2]).checked_scale(-1).unwrap().is_err());
+    assert!(i!(Open[1i8, 2]).checked_scale(0).unwrap().is_err());
+    assert_eq!(i!(Closed[1i8, 2]).checked_scale(0).unwrap().ok(), Some(i!(Degenerate[0])));
+}
+
+#[test]
+fn saturating_translate_and_scale() {
+    assert_eq!(i!(Closed[100i8, 120]).saturating_translate(20), i!(Closed[120, i8::MAX]));
+    assert_eq!(i!(Closed[1u8, 10]).saturating_translate_down(5), i!(Closed[0, 5]));
+    assert_eq!(i!(LC[10u8]).saturating_translate_down(50), i!(LC[0]));
+
+    assert_eq!(i!(Closed[-100i8, 100]).saturating_scale(2).ok(), Some(i!(Closed[i8::MIN, i8::MAX])));
+    assert!(i!(Closed[1i8, 2]).saturating_scale(-1).is_err());
+}
+
+#[test]
+fn saturating_translate_corners() {
+    assert_eq!(i!(Closed[254u8, 255]).saturating_translate(5), i!(Degenerate[u8::MAX]));
+    assert_eq!(i!(Closed[0u8, 1]).saturating_translate_down(5), i!(Degenerate[0]));
+    assert_eq!(i!(Closed[126i8, 127]).saturating_translate(5), i!(Degenerate[i8::MAX]));
+    assert_eq!(i!(Closed[i8::MIN, -127]).saturating_translate(-5), i!(Degenerate[i8::MIN]));
+    assert_eq!(i!(Closed[i8::MIN, i8::MAX]).saturating_translate(1), i!(Closed[-127, i8::MAX]));
+
+    for d in [-128i8, -1, 0, 1, 127] {
+        for (a, b) in [(i8::MIN, i8::MIN), (-1, 1), (i8::MAX - 1, i8::MAX), (i8::MIN, i8::MAX)] {
+            let x = i!(Closed[a, b]).saturating_translate(d);
+
+            assert!(x.left.0 <= x.right.0);
+        }
+    }
+}
+
+#[test]
+fn saturating_pad_corners() {
+    assert_eq!(i!(Closed[0u8, 255]).saturating_expand(1), Ok(i!(Closed[0, u8::MAX])));
+    assert_eq!(i!(LCRO[1u8, 254]).saturating_expand(5), Ok(i!(LCRO[0, u8::MAX])));
+    assert_eq!(i!(Closed[i8::MIN, i8::MAX]).saturating_expand(i8::MAX), Ok(i!(Closed[i8::MIN, i8::MAX])));
+    assert_eq!(i!(Closed[-1i8, 1]).saturating_pad(0, 127), Ok(i!(Closed[-1, i8::MAX])));
+
+    assert_eq!(i!(LC[3u8]).saturating_expand(10), Ok(i!(LC[0])));
+    assert_eq!(i!(RO[250u8]).saturating_pad(1, 10), Ok(i!(RO[u8::MAX])));
+}
+
+#[test]
+fn saturating_pad_negative() {
+    assert_eq!(i!(Closed[-1i8, 1]).saturating_pad(-1, 0), Err(PadError::Negative));
+    assert_eq!(i!(Closed[-1i8, 1]).saturating_pad(0, -1), Err(PadError::Negative));
+    assert_eq!(i!(Closed[-1i8, 1]).saturating_expand(-1), Err(PadError::Negative));
+}
+
+#[test]
+fn expand_preserves_bound_types() {
+    assert_eq!(i!(Closed[0.0, 1.0]).expand(0.5), Ok(i!(Closed[-0.5, 1.5])));
+    assert_eq!(i!(LCRO[0, 1]).expand(2), Ok(i!(LCRO[-2, 3])));
+    assert_eq!(i!(Open[0.0, 1.0]).expand(0.0), Ok(i!(Open[0.0, 1.0])));
+    assert_eq!(i!(Degenerate[5u8]).expand(5), Ok(i!(Closed[0, 10])));
+
+    assert_eq!(i!(LC[3]).expand(1), Ok(i!(LC[2])));
+    assert_eq!(i!(RO[3.0]).expand(1.0), Ok(i!(RO[4.0])));
+    assert_eq!(intervals::Unbounded::<u8>::unbounded().expand(1), Ok(Interval::unbounded()));
+}
+
+#[test]
+fn expand_invalid() {
+    assert_eq!(i!(Closed[0.0, 1.0]).expand(-0.5), Err(PadError::Negative));
+    assert_eq!(i!(Closed[0.0, 1.0]).expand(f64::NAN), Err(PadError::Negative));
+
+    assert_eq!(i!(Closed[1u8, 2]).expand(2), Err(PadError::Overflow));
+    assert_eq!(i!(LC[0u32]).expand(1), Err(PadError::Overflow));
+    assert_eq!(i!(RO[250u8]).expand(6), Err(PadError::Overflow));
+    assert_eq!(i!(Closed[i8::MIN, 0]).expand(1), Err(PadError::Overflow));
+    assert_eq!(i!(Closed[-1.0, f64::MAX]).expand(f64::MAX), Err(PadError::Overflow));
+}
+
+#[test]
+fn contract_bounded() {
+    assert_eq!(i!(Closed[0.0, 2.0]).contract(0.5), Ok(i!(Closed[0.5, 1.5])));
+    assert_eq!(i!(Open[0, 10]).contract(3), Ok(i!(Open[3, 7])));
+    assert_eq!(i!(LCRO[0, 10]).contract(0), Ok(i!(LCRO[0, 10])));
+
+    // Exactly to a point, and past it.
+    assert_eq!(i!(Closed[0.0, 2.0]).contract(1.0), Ok(i!(Degenerate[1.0])));
+    assert_eq!(i!(Closed[0.0, 2.0]).contract(1.5), Err(PadError::Empty));
+    assert_eq!(i!(Open[0.0, 2.0]).contract(1.0), Err(PadError::Empty));
+}
+
+#[test]
+fn contract_half_bounded() {
+    assert_eq!(i!(LC[3]).contract(1), Ok(i!(LC[4])));
+    assert_eq!(i!(RO[3.0]).contract(1.0), Ok(i!(RO[2.0])));
+    assert_eq!(intervals::Unbounded::<u8>::unbounded().contract(1), Ok(Interval::unbounded()));
+}
+
+#[test]
+fn contract_integers_past_width() {
+    assert_eq!(i!(Closed[0i32, 10]).contract(20), Err(PadError::Empty));
+
+    for &m in &[6u8, 11, 200, u8::MAX] {
+        assert_eq!(i!(Closed[0u8, 10]).contract(m), Err(PadError::Empty), "{}", m);
+        assert_eq!(i!(Closed[245u8, 255]).contract(m), Err(PadError::Empty), "{}", m);
+    }
+
+    assert_eq!(i!(LC[250u8]).contract(10), Err(PadError::Empty));
+    assert_eq!(i!(RC[5u8]).contract(10), Err(PadError::Empty));
+    assert!(i!(Closed[i8::MIN, i8::MAX]).contract(i8::MAX).is_ok());
+}
+
+#[test]
+fn contract_negative() {
+    assert_eq!(i!(Closed[-1i8, 1]).contract(-1), Err(PadError::Negative));
+    assert_eq!(i!(Closed[0.0, 1.0]).contract(f64::NAN), Err(PadError::Negative));
+}
+
+#[test]
+fn translate_preserves_bound_types() {
+    let x: intervals::LCRO<f64> = i!(LCRO[0.0, 1.0]) + 2.5;
+
+    assert_eq!(x, i!(LCRO[2.5, 3.5]));
+    assert_eq!(i!(Open[0.0, 1.0]) - 0.5, i!(Open[-0.5, 0.5]));
+    assert_eq!(i!(LORC[1u8, 2]) + 3, i!(LORC[4, 5]));
+    assert_eq!(i!(Degenerate[3i64]) - 4i64, i!(Degenerate[-1]));
+
+    let y = Interval::new_unchecked(OpenOrClosed::Open(0), OpenOrClosed::Closed(1)) + 1i32;
+
+    assert_eq!(y.left, OpenOrClosed::Open(1));
+    assert_eq!(y.right, OpenOrClosed::Closed(2));
+}
+
+#[test]
+fn translate_half_bounded() {
+    let lo: intervals::LeftOpen<f64> = i!(LO[0.0]) + 1.0;
+    let lc: intervals::LeftClosed<i32> = i!(LC[0]) - 1;
+    let ro: intervals::RightOpen<f32> = i!(RO[0.0f32]) + 1.0;
+    let rc: intervals::RightClosed<i32> = i!(RC[0]) - 1;
+
+    assert_eq!(lo, i!(LO[1.0]));
+    assert_eq!(lc, i!(LC[-1]));
+    assert_eq!(ro, i!(RO[1.0]));
+    assert_eq!(rc, i!(RC[-1]));
+
+    assert_eq!(intervals::Unbounded::<f64>::unbounded() + 1.0, Interval::unbounded());
+}
+
+#[test]
+fn translate_generic() {
+    // A value type without primitive operators uses the methods directly.
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Metres(f64);
+
+    impl std::ops::Add for Metres {
+        type Output = Metres;
+
+        fn add(self, other: Metres) -> Metres { Metres(self.0 + other.0) }
+    }
+
+    impl std::ops::Sub for Metres {
+        type Output = Metres;
+
+        fn sub(self, other: Metres) -> Metres { Metres(self.0 - other.0) }
+    }
+
+    let x = i!(Closed[Metres(1.0), Metres(2.0)]);
+
+    assert_eq!(x.translate(Metres(1.0)), i!(Closed[Metres(2.0), Metres(3.0)]));
+    assert_eq!(x.translate_down(Metres(1.0)), i!(Closed[Metres(0.0), Metres(1.0)]));
+}
+
+#[test]
+fn scale_positive() {
+    assert_eq!(i!(Closed[1.0, 2.0]) * 2.0, i!(Closed[2.0, 4.0]));
+    assert_eq!(i!(Open[-1, 3]) * 3, i!(Open[-3, 9]));
+    assert_eq!(i!(Closed[2u32, 8]) / 2, i!(Closed[1, 4]));
+
+    let x = i!(LCRO[0.0f64, 1.0]) * 2.0;
+
+    assert_eq!(x.left, OpenOrClosed::Closed(0.0));
+    assert_eq!(x.right, OpenOrClosed::Open(2.0));
+}
+
+#[test]
+fn scale_negative_lcro() {
+    let x = i!(LCRO[1.0f64, 2.0]) * -1.0;
+
+    assert_eq!(x.left, OpenOrClosed::Open(-2.0));
+    assert_eq!(x.right, OpenOrClosed::Closed(-1.0));
+
+    let x = i!(LCRO[-4i32, 8]) / -4;
+
+    assert_eq!(x.left, OpenOrClosed::Open(-2));
+    assert_eq!(x.right, OpenOrClosed::Closed(1));
+
+    assert_eq!(i!(Closed[1.0, 2.0]) * -0.5, i!(Closed[-1.0, -0.5]));
+    assert_eq!(i!(Open[1, 2]).scale(-1), i!(Open[-2, -1]));
+}
+
+#[test]
+fn scale_zero() {
+    assert_eq!(i!(Closed[1.0, 2.0]) * 0.0, i!(Degenerate[0.0]));
+    assert_eq!(intervals::Unbounded::<f64>::unbounded() * 0.0, Interval::unbounded());
+}
+
+#[test]
+#[should_panic(expected = "Division by zero.")]
+fn scale_down_zero() {
+    let _ = i!(Closed[1.0, 2.0]) / 0.0;
+}
+
+#[test]
+fn neg_bounded() {
+    assert_eq!(-i!(Closed[0.0, 1.0]), i!(Closed[-1.0, 0.0]));
+    assert_eq!(-i!(Open[-2, 1]), i!(Open[-1, 2]));
+    assert_eq!(-i!(LCRO[0, 1]), i!(LORC[-1, 0]));
+    assert_eq!(-i!(LORC[0, 1]), i!(LCRO[-1, 0]));
+    assert_eq!(-i!(Degenerate[3]), i!(Degenerate[-3]));
+
+    let x = -Interval::new_unchecked(OpenOrClosed::Closed(0), OpenOrClosed::Open(1));
+
+    assert_eq!(x.left, OpenOrClosed::Open(-1));
+    assert_eq!(x.right, OpenOrClosed::Closed(0));
+}
+
+#[test]
+fn neg_half_bounded() {
+    let lo: intervals::RightOpen<f64> = -i!(LO[2.0]);
+    let lc: intervals::RightClosed<f64> = -i!(LC[2.0]);
+    let ro: intervals::LeftOpen<i32> = -i!(RO[2]);
+    let rc: intervals::LeftClosed<i32> = -i!(RC[2]);
+
+    assert_eq!(lo, i!(RO[-2.0]));
+    assert_eq!(lc, i!(RC[-2.0]));
+    assert_eq!(ro, i!(LO[-2]));
+    assert_eq!(rc, i!(LC[-2]));
+
+    assert_eq!(-intervals::Unbounded::<f64>::unbounded(), Interval::unbounded());
+}
+
+#[test]
+fn neg_involution() {
+    assert_eq!(-(-i!(LCRO[-3, 7])), i!(LCRO[-3, 7]));
+    assert_eq!(-(-i!(RC[1.5])), i!(RC[1.5]));
+}
+
+#[test]
+fn minkowski_sum_bounded() {
+    assert_eq!(i!(Closed[0, 1]) + i!(Closed[2, 3]), i!(Closed[2, 4]));
+    assert_eq!(i!(Open[0, 1]) + i!(Closed[2, 3]), i!(Open[2, 4]));
+    assert_eq!(i!(Closed[0, 1]) + i!(Open[2, 3]), i!(Open[2, 4]));
+    assert_eq!(i!(LCRO[0, 1]) + i!(LORC[2, 3]), i!(Open[2, 4]));
+    assert_eq!(i!(LCRO[0, 1]) + i!(LCRO[-2, 3]), i!(LCRO[-2, 4]));
+    assert_eq!(i!(Degenerate[1.5]) + i!(LORC[0.0, 1.0]), i!(LORC[1.5, 2.5]));
+}
+
+#[test]
+fn minkowski_sum_mixed() {
+    let x = Interval::new_unchecked(OpenOrClosed::Closed(0), OpenOrClosed::Open(1));
+
+    let y = x + i!(Closed[1, 2]);
+
+    assert_eq!(y.left, OpenOrClosed::Closed(1));
+    assert_eq!(y.right, OpenOrClosed::Open(3));
+
+    let z = x + i!(Open[1, 2]);
+
+    assert_eq!(z, i!(Open[1, 3]));
+
+    let w = x + x;
+
+    assert_eq!(w.left, OpenOrClosed::Closed(0));
+    assert_eq!(w.right, OpenOrClosed::Open(2));
+}
+
+#[test]
+fn minkowski_sum_unbounded() {
+    assert_eq!(i!(LC[0]) + i!(Closed[1, 2]), i!(LC[1]));
+    assert_eq!(i!(Closed[1, 2]) + i!(LO[0]), i!(LO[1]));
+    assert_eq!(i!(RC[0.0]) + i!(LCRO[1.0, 2.0]), i!(RO[2.0]));
+    assert_eq!(i!(LC[0]) + i!(RC[0]), Interval::unbounded());
+    assert_eq!(intervals::Unbounded::<f64>::unbounded() + i!(Degenerate[1.0]), Interval::unbounded());
+}
+
+#[test]
+fn minkowski_sum_commutes() {
+    assert_eq!(i!(LCRO[0.5, 1.0]) + i!(Open[-1.0, 2.0]), i!(Open[-1.0, 2.0]) + i!(LCRO[0.5, 1.0]));
+    assert_eq!(i!(LC[0]) + i!(LORC[1, 2]), i!(LORC[1, 2]) + i!(LC[0]));
+}
+
+#[test]
+fn minkowski_difference() {
+    assert_eq!(i!(Closed[0, 1]) - i!(Closed[2, 3]), i!(Closed[-3, -1]));
+    assert_eq!(i!(LCRO[0, 1]) - i!(LCRO[2, 3]), i!(Open[-3, -1]));
+    assert_eq!(i!(LCRO[0, 1]) - i!(LORC[2, 3]), i!(LCRO[-3, -1]));
+    assert_eq!(i!(LC[0.0]) - i!(Closed[1.0, 2.0]), i!(LC[-2.0]));
+    assert_eq!(i!(LC[0.0]) - i!(RO[1.0]), i!(LO[-1.0]));
+    assert_eq!(i!(LC[0]) - i!(LC[0]), Interval::unbounded());
+}
+
+#[test]
+fn minkowski_difference_is_sum_of_negation() {
+    let endpoints = [-1.5, 0.0, 2.0];
+
+    for &a in endpoints.iter() {
+        for &b in endpoints.iter().filter(|&&b| b > a) {
+            for &c in endpoints.iter() {
+                for &d in endpoints.iter().filter(|&&d| d > c) {
+                    for &(lo, ro, lo2, ro2) in [
+                        (false, false, false, false), (true, false, false, true),
+                        (false, true, true, true), (true, true, false, false),
+                    ].iter() {
+                        let x = Interval::new_unchecked(oc(lo, a), oc(ro, b));
+                        let y = Interval::new_unchecked(oc(lo2, c), oc(ro2, d));
+
+                        assert_eq!(x - y, x + (-y), "{:?} - {:?}", x, y);
+                    }
+
+                    assert_eq!(i!(LCRO[a, b]) - i!(Open[c, d]), i!(LCRO[a, b]) + (-i!(Open[c, d])));
+                    assert_eq!(i!(Closed[a, b]) - i!(LORC[c, d]), i!(Closed[a, b]) + (-i!(LORC[c, d])));
+                    assert_eq!(i!(LC[a]) - i!(LCRO[c, d]), i!(LC[a]) + (-i!(LCRO[c, d])));
+                    assert_eq!(i!(RO[b]) - i!(LO[c]), i!(RO[b]) + (-i!(LO[c])));
+                }
+            }
+        }
+    }
+}
+
+fn oc(open: bool, x: f64) -> OpenOrClosed<f64> {
+    if open { OpenOrClosed::Open(x) } else { OpenOrClosed::Closed(x) }
+}
+
+fn product_is(z: Interval<OpenOrClosed<f64>, OpenOrClosed<f64>>, lo: bool, l: f64, r: f64, ro: bool) {
+    assert_eq!(z.left, oc(lo, l), "{:?}", z);
+    assert_eq!(z.right, oc(ro, r), "{:?}", z);
+}
+
+#[test]
+fn product_positive() {
+    product_is(i!(Closed[1.0, 2.0]) * i!(Closed[3.0, 4.0]), false, 3.0, 8.0, false);
+    product_is(i!(Open[1.0, 2.0]) * i!(Closed[3.0, 4.0]), true, 3.0, 8.0, true);
+    product_is(i!(LCRO[1.0, 2.0]) * i!(LORC[3.0, 4.0]), true, 3.0, 8.0, true);
+    product_is(i!(LCRO[1.0, 2.0]) * i!(LCRO[3.0, 4.0]), false, 3.0, 8.0, true);
+}
+
+#[test]
+fn product_negative() {
+    // Negative times negative: the extrema swap ends.
+    product_is(i!(Closed[-2.0, -1.0]) * i!(LCRO[-4.0, -3.0]), true, 3.0, 8.0, false);
+    product_is(i!(LORC[-2.0, -1.0]) * i!(Closed[-4.0, -3.0]), false, 3.0, 8.0, true);
+
+    // Mixed signs.
+    product_is(i!(Closed[-2.0, -1.0]) * i!(LCRO[3.0, 4.0]), true, -8.0, -3.0, false);
+}
+
+#[test]
+fn product_straddling_zero() {
+    product_is(i!(Closed[-1.0, 2.0]) * i!(Closed[-3.0, 1.0]), false, -6.0, 3.0, false);
+    product_is(i!(Closed[-1.0, 2.0]) * i!(LORC[-3.0, 1.0]), true, -6.0, 3.0, true);
+
+    // The greatest product is attained by the two negative endpoints.
+    product_is(i!(Closed[-3.0, 1.0]) * i!(Closed[-3.0, 2.0]), false, -6.0, 9.0, false);
+
+    // -1 is only approached, from both pairings.
+    product_is(i!(LCRO[-1.0, 1.0]) * i!(LCRO[-1.0, 1.0]), true, -1.0, 1.0, false);
+}
+
+#[test]
+fn product_zero() {
+    product_is(i!(Degenerate[0.0]) * i!(Open[1.0, 2.0]), false, 0.0, 0.0, false);
+    product_is(i!(Open[-1.0, 2.0]) * i!(Degenerate[0.0]), false, 0.0, 0.0, false);
+
+    // An open zero endpoint is not attained, a closed one is.
+    product_is(i!(Open[0.0, 1.0]) * i!(Closed[2.0, 3.0]), true, 0.0, 3.0, true);
+    product_is(i!(Open[0.0, 1.0]) * i!(LORC[-1.0, 0.0]), true, -1.0, 0.0, false);
+}
+
+#[test]
+fn product_integers() {
+    let z = i!(Closed[-2, 3]) * i!(Open[-1, 4]);
+
+    assert_eq!(z.left, OpenOrClosed::Open(-8));
+    assert_eq!(z.right, OpenOrClosed::Open(12));
+}
+
+#[test]
+fn product_against_samples() {
+    let grid: Vec<f64> = (-16..=16).map(|k| k as f64 / 4.0).collect();
+    let endpoints = [-2.0, -1.0, 0.0, 1.0, 2.0];
+
+    for &a in endpoints.iter() {
+        for &b in endpoints.iter().filter(|&&b| b >= a) {
+            for &c in endpoints.iter() {
+                for &d in endpoints.iter().filter(|&&d| d >= c) {
+                    for &(lo, ro, lo2, ro2) in [
+                        (false, false, false, false), (true, false, false, true),
+                        (false, true, true, true), (true, true, false, false),
+                    ].iter() {
+                        if (lo || ro) && a == b || (lo2 || ro2) && c == d { continue; }
+
+                        let x = Interval::new_unchecked(oc(lo, a), oc(ro, b));
+                        let y = Interval::new_unchecked(oc(lo2, c), oc(ro2, d));
+                        let z = x * y;
+
+                        let samples: Vec<f64> = grid.iter()
+                            .filter(|v| x.contains_ref(v))
+                            .flat_map(|u| grid.iter().filter(|v| y.contains_ref(v)).map(move |v| u * v))
+                            .collect();
+
+                        for p in samples.iter() {
+                            assert!(z.contains_ref(p), "{:?} * {:?} = {:?} misses {}", x, y, z, p);
+                        }
+
+                        let (l, r) = (z.left.unwrap(), z.right.unwrap());
+
+                        assert_eq!(samples.contains(&l), z.left.is_closed(), "{:?} * {:?}", x, y);
+                        assert_eq!(samples.contains(&r), z.right.is_closed(), "{:?} * {:?}", x, y);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn quotient_contains(q: &Quotient<f64>, x: f64) -> bool {
+    match q {
+        Quotient::Bounded(z) => z.contains_ref(&x),
+        Quotient::LeftBounded(z) => z.contains_ref(&x),
+        Quotient::RightBounded(z) => z.contains_ref(&x),
+        Quotient::Split(a, b) => a.contains_ref(&x) || b.contains_ref(&x),
+        Quotient::Unbounded => true,
+        Quotient::Empty => false,
+    }
+}
+
+fn show(q: Quotient<f64>) -> String {
+    match q {
+        Quotient::Bounded(z) => z.to_string(),
+        Quotient::LeftBounded(z) => z.to_string(),
+        Quotient::RightBounded(z) => z.to_string(),
+        Quotient::Split(a, b) => format!("{} \u{222A} {}", a, b),
+        Quotient::Unbounded => "(-\u{221E}, \u{221E})".to_owned(),
+        Quotient::Empty => "\u{2205}".to_owned(),
+    }
+}
+
+#[test]
+fn quotient_definite() {
+    assert_eq!(show(i!(Closed[1.0, 2.0]) / i!(Closed[2.0, 4.0])), "[0.25, 1]");
+
+    match i!(Closed[1.0, 2.0]) / i!(LORC[-2.0, -1.0]) {
+        Quotient::Bounded(z) => product_is(z, false, -2.0, -0.5, true),
+        q => panic!("{:?}", q),
+    }
+}
+
+#[test]
+fn quotient_zero_endpoint() {
+    // Open and closed zero endpoints yield the same ray.
+    assert_eq!(show(i!(Closed[1.0, 2.0]) / i!(LORC[0.0, 2.0])), "[0.5, \u{221E})");
+    assert_eq!(show(i!(Closed[1.0, 2.0]) / i!(Closed[0.0, 2.0])), "[0.5, \u{221E})");
+    assert_eq!(show(i!(Closed[1.0, 2.0]) / i!(Closed[-2.0, 0.0])), "(-\u{221E}, -0.5]");
+
+    assert_eq!(show(i!(Closed[0.0, 1.0]) / i!(Closed[0.0, 2.0])), "[0, \u{221E})");
+    assert_eq!(show(i!(Open[0.0, 1.0]) / i!(LORC[0.0, 2.0])), "(0, \u{221E})");
+    assert_eq!(show(i!(Closed[-1.0, 1.0]) / i!(Closed[0.0, 2.0])), "(-\u{221E}, \u{221E})");
+}
+
+#[test]
+fn quotient_straddling_zero() {
+    assert_eq!(show(i!(Closed[1.0, 2.0]) / i!(Closed[-1.0, 1.0])), "(-\u{221E}, -1] \u{222A} [1, \u{221E})");
+    assert_eq!(show(i!(LORC[0.0, 1.0]) / i!(Closed[-1.0, 1.0])), "(-\u{221E}, 0) \u{222A} (0, \u{221E})");
+
+    // A closed zero numerator joins the two rays.
+    assert_eq!(show(i!(Closed[0.0, 1.0]) / i!(Closed[-1.0, 1.0])), "(-\u{221E}, \u{221E})");
+    assert_eq!(show(i!(Closed[-1.0, 1.0]) / i!(Closed[-1.0, 1.0])), "(-\u{221E}, \u{221E})");
+    assert_eq!(show(i!(Degenerate[0.0]) / i!(Closed[-1.0, 1.0])), "{0}");
+}
+
+#[test]
+fn quotient_empty() {
+    assert_eq!(show(i!(Closed[1.0, 2.0]) / i!(Degenerate[0.0])), "\u{2205}");
+}
+
+#[test]
+fn quotient_against_samples() {
+    let grid: Vec<f64> = (-16..=16).map(|k| k as f64 / 4.0).collect();
+    let endpoints = [-2.0, -1.0, 0.0, 1.0, 2.0];
+
+    for &a in endpoints.iter() {
+        for &b in endpoints.iter().filter(|&&b| b >= a) {
+            for &c in endpoints.iter() {
+                for &d in endpoints.iter().filter(|&&d| d >= c) {
+                    for &(lo, ro, lo2, ro2) in [
+                        (false, false, false, false), (true, false, false, true),
+                        (false, true, true, true), (true, true, false, false),
+                    ].iter() {
+                        if (lo || ro) && a == b || (lo2 || ro2) && c == d { continue; }
+
+                        let x = Interval::new_unchecked(oc(lo, a), oc(ro, b));
+                        let y = Interval::new_unchecked(oc(lo2, c), oc(ro2, d));
+                        let q = x / y;
+
+                        let samples: Vec<f64> = grid.iter()
+                            .filter(|v| x.contains_ref(v))
+                            .flat_map(|u| grid.iter().filter(|v| **v != 0.0 && y.contains_ref(v)).map(move |v| u / v))
+                            .collect();
+
+                        for p in samples.iter() {
+                            assert!(quotient_contains(&q, *p), "{:?} / {:?} = {:?} misses {}", x, y, q, p);
+                        }
+
+                        if let Quotient::Bounded(z) = q {
+                            let (l, r) = (z.left.unwrap(), z.right.unwrap());
+
+                            assert_eq!(samples.contains(&l), z.left.is_closed(), "{:?} / {:?}", x, y);
+                            assert_eq!(samples.contains(&r), z.right.is_closed(), "{:?} / {:?}", x, y);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
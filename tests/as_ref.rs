@@ -0,0 +1,83 @@
+extern crate intervals;
+
+use intervals::{Interval, bounds::{self, Bound}};
+use std::{fmt, sync::atomic::{AtomicUsize, Ordering}};
+
+static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, PartialEq, PartialOrd)]
+struct Counted(String);
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        CLONES.fetch_add(1, Ordering::SeqCst);
+
+        Counted(self.0.clone())
+    }
+}
+
+impl fmt::Display for Counted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.0.fmt(f) }
+}
+
+fn counted(s: &str) -> Counted { Counted(s.to_owned()) }
+
+#[test]
+fn test_bounds() {
+    let s = String::from("a");
+
+    assert_eq!(bounds::Open(s.clone()).as_ref(), bounds::Open(&s));
+    assert_eq!(bounds::Closed(s.clone()).as_ref(), bounds::Closed(&s));
+    assert_eq!(bounds::OpenOrClosed::Open(s.clone()).as_ref(), bounds::OpenOrClosed::Open(&s));
+    assert_eq!(bounds::OpenOrClosed::Closed(s.clone()).as_ref(), bounds::OpenOrClosed::Closed(&s));
+    assert_eq!(bounds::NoBound::<String>::new().as_ref(), bounds::NoBound::new());
+}
+
+#[test]
+fn test_string_view() {
+    let x = Interval::closed_unchecked(String::from("b"), String::from("d"));
+    let y = Interval::left_open(String::from("c"));
+
+    let (xv, yv) = (x.as_ref(), y.as_ref());
+
+    assert!(xv.contains(&String::from("c")));
+    assert!(!xv.contains(&String::from("e")));
+    assert!(!yv.contains(&String::from("c")));
+
+    assert_eq!(xv.intersect(yv).unwrap(), Interval::lorc_unchecked(&y.left.0, &x.right.0));
+    assert_eq!(xv.to_string(), "[b, d]");
+    assert_eq!(yv.to_string(), "(c, \u{221E})");
+
+    assert_eq!(x, Interval::closed_unchecked(String::from("b"), String::from("d")));
+}
+
+#[test]
+fn test_no_clones() {
+    let x = Interval::lcro_unchecked(counted("apple"), counted("cherry"));
+    let y = Interval::open_unchecked(counted("banana"), counted("date"));
+    let before = CLONES.load(Ordering::SeqCst);
+
+    {
+        let (xv, yv) = (x.as_ref(), y.as_ref());
+
+        assert!(xv.contains(&counted("apple")));
+        assert!(!xv.contains(&counted("cherry")));
+        assert!(xv.intersect(yv).is_some());
+        assert!(xv.span(yv).contains(&counted("cranberry")));
+        assert_eq!(xv.to_string(), "[apple, cherry)");
+        assert_eq!(yv.to_string(), "(banana, date)");
+    }
+
+    assert_eq!(CLONES.load(Ordering::SeqCst), before);
+}
+
+#[test]
+fn test_str_view() {
+    let x = Interval::lcro_unchecked(String::from("apple"), String::from("cherry"));
+    let view = x.as_ref().map(String::as_str);
+
+    assert!(view.contains("banana"));
+    assert!(!view.contains("cherry"));
+    assert!(!view.contains("aardvark"));
+    assert_eq!(view, Interval::lcro_unchecked("apple", "cherry"));
+}
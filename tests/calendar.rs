@@ -0,0 +1,140 @@
+#![cfg(feature = "chrono")]
+extern crate chrono;
+extern crate chrono_tz;
+extern crate intervals;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_tz::{Europe::London, Tz};
+use intervals::{Interval, bounds::ProperBound, partitions::{Calendar, Partition}};
+
+fn london(y: i32, m: u32, d: u32, h: u32) -> DateTime<Tz> {
+    London.with_ymd_and_hms(y, m, d, h, 0, 0).single().unwrap()
+}
+
+// Asserts that the cells tile the interval with no gaps or overlaps, and
+// that indices agree with containment at regular instants.
+fn assert_tiles<Tz: TimeZone>(p: &Calendar<Tz>, start: DateTime<Tz>, end: DateTime<Tz>, step: Duration) {
+    let n = p.len();
+    let cells: Vec<_> = (0..n).map(|k| p.subinterval(k).unwrap().interval).collect();
+
+    assert!(p.subinterval(n).is_none());
+    assert_eq!(cells[0].left.proper_value(), &start);
+    assert_eq!(cells[n - 1].right.proper_value(), &end);
+
+    for w in cells.windows(2) {
+        assert_eq!(w[0].right.proper_value(), w[1].left.proper_value());
+        assert!(w[0].left.proper_value() < w[0].right.proper_value());
+    }
+
+    let mut t = start.clone();
+
+    while t <= end {
+        let k = p.index(&t).unwrap();
+
+        for (j, cell) in cells.iter().enumerate() {
+            assert_eq!(cell.contains(t.clone()), j == k, "{:?} in cell {}", t, j);
+        }
+
+        t += step;
+    }
+
+    assert!(p.index(&(start - Duration::seconds(1))).is_none());
+    assert!(p.index(&(end + Duration::seconds(1))).is_none());
+}
+
+#[test]
+fn test_days_over_spring_forward() {
+    let (start, end) = (london(2024, 3, 30, 12), london(2024, 4, 1, 12));
+    let p = Calendar::days(Interval::closed_unchecked(start, end));
+
+    assert_eq!(p.len(), 3);
+
+    let day = p.subinterval(1).unwrap();
+
+    assert_eq!(day.interval.left.proper_value(), &london(2024, 3, 31, 0));
+    assert_eq!(day.interval.right.proper_value(), &london(2024, 4, 1, 0));
+    assert_eq!(day.width(), Duration::hours(23));
+    assert_eq!(day.checked_width(), Some(Duration::hours(23)));
+
+    assert_tiles(&p, start, end, Duration::minutes(20));
+}
+
+#[test]
+fn test_days_over_fall_back() {
+    let (start, end) = (london(2024, 10, 26, 0), london(2024, 10, 28, 0));
+    let p = Calendar::days(Interval::closed_unchecked(start, end));
+
+    // The right bound lies on a day boundary, so there's no trailing cell.
+    assert_eq!(p.len(), 2);
+    assert_eq!(p.subinterval(1).unwrap().width(), Duration::hours(25));
+    assert_eq!(p.index(&end), Some(1));
+
+    assert_tiles(&p, start, end, Duration::minutes(20));
+}
+
+#[test]
+fn test_hours_over_fall_back() {
+    let start = london(2024, 10, 27, 0);
+    let end = start + Duration::hours(4);
+    let p = Calendar::hours(Interval::closed_unchecked(start, end));
+
+    // Local 01:00-02:00 occurs twice, and both occurrences are cells.
+    assert_eq!(p.len(), 4);
+
+    for k in 0..4 {
+        assert_eq!(p.subinterval(k).unwrap().width(), Duration::hours(1));
+    }
+
+    assert_tiles(&p, start, end, Duration::minutes(7));
+}
+
+#[test]
+fn test_hours_partial() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 10, 15, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2024, 1, 1, 12, 30, 0).unwrap();
+    let p = Calendar::hours(Interval::closed_unchecked(start, end));
+
+    assert_eq!(p.len(), 3);
+    assert_eq!(p.subinterval(0).unwrap().width(), Duration::minutes(45));
+    assert_eq!(p.subinterval(2).unwrap().width(), Duration::minutes(30));
+
+    assert_tiles(&p, start, end, Duration::minutes(5));
+}
+
+#[test]
+fn test_months_over_leap_day() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2024, 4, 10, 0, 0, 0).unwrap();
+    let p = Calendar::months(Interval::closed_unchecked(start, end));
+
+    assert_eq!(p.len(), 4);
+    assert_eq!(p.subinterval(1).unwrap().width(), Duration::days(29));
+    assert_eq!(p.subinterval(2).unwrap().width(), Duration::days(31));
+
+    let leap_day = Utc.with_ymd_and_hms(2024, 2, 29, 12, 0, 0).unwrap();
+
+    assert_eq!(p.index(&leap_day), Some(1));
+
+    assert_tiles(&p, start, end, Duration::hours(5));
+}
+
+#[test]
+fn test_days_over_leap_day() {
+    let start = Utc.with_ymd_and_hms(2024, 2, 28, 6, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2024, 3, 1, 6, 0, 0).unwrap();
+    let p = Calendar::days(Interval::closed_unchecked(start, end));
+
+    assert_eq!(p.len(), 3);
+    assert_eq!(p.index(&Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap()), Some(1));
+
+    assert_tiles(&p, start, end, Duration::minutes(30));
+}
+
+#[test]
+fn test_degenerate() {
+    let t = london(2024, 6, 1, 12);
+    let p = Calendar::days(Interval::degenerate(t));
+
+    assert_eq!(p.len(), 1);
+    assert_eq!(p.index(&t), Some(0));
+}
@@ -0,0 +1,18 @@
+//! Helpers shared by the integration tests.
+
+/// Shorthand for constructing intervals of each shape without validation.
+macro_rules! i {
+    (Open[$left:expr, $right:expr]) => { Interval::open_unchecked($left, $right) };
+    (Closed[$left:expr, $right:expr]) => { Interval::closed_unchecked($left, $right) };
+
+    (LCRO[$left:expr, $right:expr]) => { Interval::lcro_unchecked($left, $right) };
+    (LORC[$left:expr, $right:expr]) => { Interval::lorc_unchecked($left, $right) };
+
+    (LO[$left:expr]) => { Interval::left_open($left) };
+    (LC[$left:expr]) => { Interval::left_closed($left) };
+
+    (RO[$right:expr]) => { Interval::right_open($right) };
+    (RC[$right:expr]) => { Interval::right_closed($right) };
+
+    (Degenerate[$x:expr]) => { Interval::degenerate($x) };
+}
@@ -0,0 +1,317 @@
+extern crate intervals;
+
+#[macro_use]
+mod common;
+
+use intervals::{Interval, Position, bounds::{self, OpenOrClosed}};
+
+const GRID: [f64; 11] = [
+    f64::NEG_INFINITY, -2.0, -1.0, -0.5, 0.0, 0.5, 1.0, 1.5, 2.0, f64::INFINITY, f64::NAN,
+];
+
+macro_rules! test_consistency {
+    ($($x:expr),+) => {{
+        $({
+            let x = $x;
+
+            for v in GRID.iter() {
+                assert_eq!(
+                    x.classify(v) == Position::Inside, x.contains(*v),
+                    "{:?} classified as {:?}", v, x.classify(v),
+                );
+                assert_eq!(x.contains_ref(v), x.contains(*v), "{:?}", v);
+            }
+        })+
+    }};
+}
+
+macro_rules! test_zero_eps {
+    ($($x:expr),+) => {{
+        $({
+            let x = $x;
+
+            for v in GRID.iter() {
+                assert_eq!(x.contains_approx(*v, 0.0), x.contains(*v), "{:?}", v);
+            }
+        })+
+    }};
+}
+
+fn oc(open: bool, x: f64) -> OpenOrClosed<f64> {
+    if open { OpenOrClosed::Open(x) } else { OpenOrClosed::Closed(x) }
+}
+
+#[test]
+fn classify_consistent_with_contains() {
+    test_consistency!(
+        Interval::<_, _>::unbounded(),
+        i!(Open[-1.0, 1.0]), i!(Closed[-1.0, 1.0]),
+        i!(LCRO[-1.0, 1.0]), i!(LORC[-1.0, 1.0]),
+        i!(LO[-1.0]), i!(LC[-1.0]),
+        i!(RO[1.0]), i!(RC[1.0]),
+        i!(Degenerate[0.0])
+    );
+
+    for &(lo, ro) in [(true, true), (true, false), (false, true), (false, false)].iter() {
+        test_consistency!(
+            Interval::new_unchecked(bounds::NoBound::new(), oc(ro, 1.0)),
+            Interval::new_unchecked(oc(lo, -1.0), bounds::NoBound::new()),
+            Interval::new_unchecked(bounds::Open(-1.0), oc(ro, 1.0)),
+            Interval::new_unchecked(bounds::Closed(-1.0), oc(ro, 1.0)),
+            Interval::new_unchecked(oc(lo, -1.0), bounds::Open(1.0)),
+            Interval::new_unchecked(oc(lo, -1.0), bounds::Closed(1.0)),
+            Interval::new_unchecked(oc(lo, -1.0), oc(ro, 1.0))
+        );
+    }
+}
+
+#[test]
+fn classify_bounded() {
+    let x = i!(Open[-1.0, 1.0]);
+
+    assert_eq!(x.classify(&-2.0), Position::Below);
+    assert_eq!(x.classify(&-1.0), Position::OnLeftBoundary);
+    assert_eq!(x.classify(&0.0), Position::Inside);
+    assert_eq!(x.classify(&1.0), Position::OnRightBoundary);
+    assert_eq!(x.classify(&2.0), Position::Above);
+
+    let x = i!(Closed[-1.0, 1.0]);
+
+    assert_eq!(x.classify(&-1.0), Position::Inside);
+    assert_eq!(x.classify(&1.0), Position::Inside);
+    assert_eq!(x.classify(&f64::NAN), Position::Incomparable);
+}
+
+#[test]
+fn classify_half_bounded() {
+    for v in GRID.iter().filter(|v| !v.is_nan()) {
+        assert_ne!(i!(LO[0.0]).classify(v), Position::Above);
+        assert_ne!(i!(LC[0.0]).classify(v), Position::Above);
+        assert_ne!(i!(RO[0.0]).classify(v), Position::Below);
+        assert_ne!(i!(RC[0.0]).classify(v), Position::Below);
+    }
+
+    assert_eq!(i!(LO[0.0]).classify(&0.0), Position::OnLeftBoundary);
+    assert_eq!(i!(RC[0.0]).classify(&0.0), Position::Inside);
+    assert_eq!(i!(RC[0.0]).classify(&1.0), Position::Above);
+}
+
+#[test]
+fn contains_approx_at_eps() {
+    let eps = 0.25;
+
+    assert!(i!(Open[-1.0, 1.0]).contains_approx(-1.25, eps));
+    assert!(i!(Open[-1.0, 1.0]).contains_approx(1.25, eps));
+    assert!(!i!(Open[-1.0, 1.0]).contains_approx(-1.5, eps));
+    assert!(!i!(Open[-1.0, 1.0]).contains_approx(1.5, eps));
+
+    assert!(i!(Closed[-1.0, 1.0]).contains_approx(-1.25, eps));
+    assert!(i!(Closed[-1.0, 1.0]).contains_approx(1.25, eps));
+    assert!(!i!(Closed[-1.0, 1.0]).contains_approx(-1.5, eps));
+    assert!(!i!(Closed[-1.0, 1.0]).contains_approx(1.5, eps));
+
+    assert!(i!(LCRO[-1.0, 1.0]).contains_approx(1.25, eps));
+    assert!(i!(LORC[-1.0, 1.0]).contains_approx(-1.25, eps));
+
+    assert!(i!(LO[-1.0]).contains_approx(-1.25, eps));
+    assert!(!i!(LC[-1.0]).contains_approx(-1.5, eps));
+    assert!(i!(LC[-1.0]).contains_approx(1e300, eps));
+    assert!(i!(RO[1.0]).contains_approx(1.25, eps));
+    assert!(!i!(RC[1.0]).contains_approx(1.5, eps));
+    assert!(i!(RC[1.0]).contains_approx(-1e300, eps));
+}
+
+#[test]
+fn contains_approx_at_type_limits() {
+    assert!(i!(Closed[0u8, 5]).contains_approx(0, 1));
+    assert!(i!(Closed[0u8, 5]).contains_approx(6, 1));
+    assert!(!i!(Closed[0u8, 5]).contains_approx(7, 1));
+    assert!(i!(Closed[250u8, u8::MAX]).contains_approx(u8::MAX, 10));
+    assert!(i!(Closed[250u8, u8::MAX]).contains_approx(0, u8::MAX));
+    assert!(!i!(Closed[250u8, u8::MAX]).contains_approx(0, 249));
+
+    assert!(i!(Closed[i8::MIN, 0]).contains_approx(i8::MIN, 1));
+    assert!(i!(Closed[0i8, i8::MAX]).contains_approx(i8::MAX, i8::MAX));
+    assert!(i!(Closed[0i8, i8::MAX]).contains_approx(-1, 1));
+    assert!(!i!(Closed[100i8, i8::MAX]).contains_approx(i8::MIN, i8::MAX));
+    assert!(i!(Closed[i8::MIN, -100]).contains_approx(27, i8::MAX));
+    assert!(!i!(Closed[i8::MIN, -100]).contains_approx(i8::MAX, i8::MAX));
+}
+
+#[test]
+fn contains_approx_nan() {
+    let x = i!(Closed[-1.0, 1.0]);
+
+    assert!(!x.contains_approx(f64::NAN, 0.1));
+    assert!(!x.contains_approx(0.0, f64::NAN));
+    assert!(!x.contains_approx(0.0, -0.1));
+
+    let x: intervals::Unbounded<f64> = Interval::unbounded();
+
+    assert!(x.contains_approx(0.0, 0.1));
+    assert!(!x.contains_approx(f64::NAN, 0.1));
+    assert!(!x.contains_approx(0.0, f64::NAN));
+}
+
+#[test]
+fn contains_approx_zero_eps() {
+    test_zero_eps!(
+        i!(Closed[-1.0, 1.0]), i!(LC[-1.0]), i!(RC[1.0]), i!(Degenerate[0.0])
+    );
+
+    // Open bounds are accepted at zero tolerance.
+    assert!(i!(Open[-1.0, 1.0]).contains_approx(-1.0, 0.0));
+    assert!(i!(Open[-1.0, 1.0]).contains_approx(1.0, 0.0));
+    assert!(!i!(Open[-1.0, 1.0]).contains_approx(1.0 + 1e-12, 0.0));
+    assert!(i!(LO[-1.0]).contains_approx(-1.0, 0.0));
+    assert!(i!(RO[1.0]).contains_approx(1.0, 0.0));
+}
+
+// Deterministic pseudo-random values in [-2, 2), mixed with the grid values.
+fn random_values(n: usize) -> Vec<f64> {
+    let mut state = 12345u64;
+
+    (0..n).map(|k| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+
+        if k % 7 == 0 {
+            GRID[(state >> 33) as usize % GRID.len()]
+        } else {
+            ((state >> 11) as f64 / (1u64 << 53) as f64) * 4.0 - 2.0
+        }
+    }).collect()
+}
+
+macro_rules! test_slice {
+    ($values:expr; $($x:expr),+) => {{
+        $({
+            let x = $x;
+            let mask = x.contains_slice($values);
+
+            for (v, m) in $values.iter().zip(mask) {
+                assert_eq!(m, x.contains(*v), "{:?}", v);
+            }
+        })+
+    }};
+}
+
+#[test]
+fn contains_slice_consistent_with_contains() {
+    let values = random_values(1000);
+
+    test_slice!(&values;
+        Interval::<_, _>::unbounded(),
+        i!(Open[-1.0, 1.0]), i!(Closed[-1.0, 1.0]),
+        i!(LCRO[-1.0, 1.0]), i!(LORC[-1.0, 1.0]),
+        i!(LO[-1.0]), i!(LC[-1.0]),
+        i!(RO[1.0]), i!(RC[1.0]),
+        i!(Degenerate[0.0])
+    );
+
+    for &(lo, ro) in [(true, true), (true, false), (false, true), (false, false)].iter() {
+        test_slice!(&values;
+            Interval::new_unchecked(bounds::NoBound::new(), oc(ro, 1.0)),
+            Interval::new_unchecked(oc(lo, -1.0), bounds::NoBound::new()),
+            Interval::new_unchecked(bounds::Open(-1.0), oc(ro, 1.0)),
+            Interval::new_unchecked(oc(lo, -1.0), oc(ro, 1.0))
+        );
+    }
+}
+
+#[test]
+fn contains_slice_large() {
+    let values = random_values(1 << 20);
+    let mut out = vec![false; values.len()];
+
+    i!(Closed[-1.0, 1.0]).contains_slice_into(&values, &mut out);
+
+    let n = out.iter().filter(|&&m| m).count();
+
+    assert_eq!(n, values.iter().filter(|&&v| (-1.0..=1.0).contains(&v)).count());
+    assert!(n > values.len() / 3 && n < 2 * values.len() / 3);
+}
+
+#[test]
+#[should_panic]
+fn contains_slice_into_mismatched() {
+    i!(Closed[-1.0, 1.0]).contains_slice_into(&[0.0, 1.0], &mut [false]);
+}
+
+// A value type that can be compared but neither copied nor cloned.
+#[derive(Debug, PartialEq, PartialOrd)]
+struct Key(String);
+
+#[test]
+fn contains_ref_without_clone() {
+    let k = |s: &str| Key(s.to_string());
+
+    let x = i!(LCRO[k("b"), k("d")]);
+
+    assert!(!x.contains_ref(&k("a")));
+    assert!(x.contains_ref(&k("b")));
+    assert!(x.contains_ref(&k("c")));
+    assert!(!x.contains_ref(&k("d")));
+
+    let y = Interval::new_unchecked(OpenOrClosed::Open(k("b")), bounds::NoBound::new());
+
+    assert!(!y.contains_ref(&k("b")));
+    assert!(y.contains_ref(&k("z")));
+
+    let mut out = [false; 3];
+
+    y.contains_slice_into(&[k("a"), k("c"), k("e")], &mut out);
+
+    assert_eq!(out, [false, true, true]);
+}
+
+#[test]
+fn clamp_bounded() {
+    let x = i!(Closed[-1.0, 1.0]);
+
+    assert_eq!(x.clamp_value(-2.0), -1.0);
+    assert_eq!(x.clamp_value(0.5), 0.5);
+    assert_eq!(x.clamp_value(f64::INFINITY), 1.0);
+    assert!(x.clamp_value(f64::NAN).is_nan());
+
+    // Open endpoints are returned as-is.
+    assert_eq!(i!(Open[0, 10]).clamp_value(-5), 0);
+    assert_eq!(i!(LCRO[0, 10]).clamp_value(10), 10);
+    assert_eq!(i!(Degenerate[3]).clamp_value(7), 3);
+}
+
+#[test]
+fn clamp_half_bounded() {
+    assert_eq!(i!(LC[0.0]).clamp_value(-1.0), 0.0);
+    assert_eq!(i!(LO[0.0]).clamp_value(1e300), 1e300);
+    assert_eq!(i!(RC[0.0]).clamp_value(1.0), 0.0);
+    assert_eq!(i!(RO[0.0]).clamp_value(f64::NEG_INFINITY), f64::NEG_INFINITY);
+
+    for v in GRID.iter().filter(|v| !v.is_nan()) {
+        assert_eq!(intervals::Unbounded::<f64>::unbounded().clamp_value(*v), *v);
+    }
+}
+
+#[test]
+fn clamp_into_closure() {
+    for x in [i!(Open[-1.0, 1.0]).closure(), i!(LCRO[-1.0, 1.0]).closure(), i!(Degenerate[0.5])].iter() {
+        for v in GRID.iter().filter(|v| !v.is_nan()) {
+            let c = x.clamp_value(*v);
+
+            assert!(x.contains(c), "{} clamped to {}", v, c);
+
+            if x.contains(*v) { assert_eq!(c, *v); }
+        }
+    }
+}
+
+#[test]
+fn clamp_ref_without_clone() {
+    let k = |s: &str| Key(s.to_string());
+
+    let x = i!(LORC[k("b"), k("d")]);
+    let (a, c, e) = (k("a"), k("c"), k("e"));
+
+    assert_eq!(x.clamp_ref(&a), &k("b"));
+    assert_eq!(x.clamp_ref(&c), &c);
+    assert_eq!(x.clamp_ref(&e), &k("d"));
+}
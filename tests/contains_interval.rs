@@ -0,0 +1,197 @@
+extern crate intervals;
+
+#[macro_use]
+mod common;
+
+use intervals::{bounds::{Bound, NoBound, OpenOrClosed}, Interval, Position};
+use std::cmp::Ordering;
+
+// Checks every shape in the first list against every shape in the second.
+macro_rules! cross {
+    ($($x:expr),+; $ys:tt) => {{ $( cross!(@one $x; $ys); )+ }};
+    (@one $x:expr; [$($y:expr),+]) => {{ $( check(&$x, &$y); )+ }};
+}
+
+const ENDPOINTS: [f64; 5] = [-1.0, 0.0, 0.5, 1.0, 2.0];
+
+// Compares against containment of a grid of points fine enough to separate
+// open and closed bounds at the endpoints, and far enough to reach past them.
+fn check<L, R, LL, RR>(x: &Interval<L, R>, y: &Interval<LL, RR>)
+where
+    L: Bound<Value = f64> + std::fmt::Debug,
+    R: Bound<Value = f64> + std::fmt::Debug,
+    LL: Bound<Value = f64> + std::fmt::Debug,
+    RR: Bound<Value = f64> + std::fmt::Debug,
+{
+    let grid = (-16..=16).map(|k| k as f64 / 8.0).chain(vec![-1e9, 1e9]);
+    let expected = grid
+        .filter(|v| y.classify(v) == Position::Inside)
+        .all(|v| x.classify(&v) == Position::Inside);
+
+    assert_eq!(x.contains_interval(y), expected, "{:?} vs {:?}", x, y);
+    assert_eq!(x.is_superset(y), expected);
+    assert_eq!(y.is_subset(x), expected);
+
+    let reverse = y.contains_interval(x);
+
+    assert_eq!(y.is_proper_subset(x), expected && !reverse);
+    assert_eq!(x.is_proper_superset(y), expected && !reverse);
+
+    let inclusion = match (expected, reverse) {
+        (true, true) => Some(Ordering::Equal),
+        (true, false) => Some(Ordering::Greater),
+        (false, true) => Some(Ordering::Less),
+        (false, false) => None,
+    };
+
+    assert_eq!(x.partial_cmp_inclusion(y), inclusion, "{:?} vs {:?}", x, y);
+    assert_eq!(y.partial_cmp_inclusion(x), inclusion.map(Ordering::reverse));
+}
+
+#[test]
+fn shared_endpoints() {
+    assert!(i!(Closed[0, 1]).contains_interval(&i!(Open[0, 1])));
+    assert!(i!(Closed[0, 1]).contains_interval(&i!(LCRO[0, 1])));
+    assert!(i!(Closed[0, 1]).contains_interval(&i!(LORC[0, 1])));
+    assert!(i!(Closed[0, 1]).contains_interval(&i!(Closed[0, 1])));
+
+    assert!(!i!(Open[0, 1]).contains_interval(&i!(Closed[0, 1])));
+    assert!(!i!(LCRO[0, 1]).contains_interval(&i!(LORC[0, 1])));
+    assert!(!i!(LORC[0, 1]).contains_interval(&i!(LCRO[0, 1])));
+    assert!(!i!(Open[0, 1]).contains_interval(&i!(Degenerate[0])));
+
+    assert!(i!(Open[0, 1]).contains_interval(&i!(Open[0, 1])));
+    assert!(i!(LCRO[0, 1]).contains_interval(&i!(Degenerate[0])));
+}
+
+#[test]
+fn one_sided() {
+    assert!(i!(LC[0]).contains_interval(&i!(LO[0])));
+    assert!(!i!(LO[0]).contains_interval(&i!(LC[0])));
+    assert!(i!(LO[0]).contains_interval(&i!(Closed[1, 1000])));
+    assert!(!i!(Closed[1, 1000]).contains_interval(&i!(LO[0])));
+    assert!(!i!(LC[0]).contains_interval(&i!(RC[1])));
+
+    assert!(Interval::unbounded().contains_interval(&i!(RO[0])));
+    assert!(!i!(RO[0]).contains_interval(&Interval::unbounded()));
+    assert!(Interval::<_, _>::unbounded().contains_interval(&intervals::Unbounded::<f64>::unbounded()));
+}
+
+#[test]
+fn incomparable() {
+    assert!(!i!(Closed[0.0, 1.0]).contains_interval(&i!(Closed[f64::NAN, 0.5])));
+    assert!(!i!(Closed[f64::NAN, 1.0]).contains_interval(&i!(Closed[0.0, 0.5])));
+}
+
+#[test]
+fn proper_subsets() {
+    assert!(!i!(Closed[0, 1]).is_proper_subset(&i!(Closed[0, 1])));
+    assert!(i!(Open[0, 1]).is_proper_subset(&i!(Closed[0, 1])));
+    assert!(i!(LCRO[0, 1]).is_proper_subset(&i!(Closed[0, 1])));
+    assert!(!i!(Closed[0, 1]).is_proper_subset(&i!(Open[0, 1])));
+    assert!(!i!(LCRO[0, 1]).is_proper_subset(&i!(LORC[0, 1])));
+
+    assert!(!i!(LO[0]).is_proper_subset(&i!(LO[0])));
+    assert!(i!(LO[0]).is_proper_subset(&i!(LC[0])));
+    assert!(i!(LC[0]).is_proper_subset(&Interval::unbounded()));
+    assert!(!intervals::Unbounded::<i32>::unbounded().is_proper_subset(&Interval::unbounded()));
+
+    // Set-equal intervals with differently typed bounds.
+    let x = Interval::new_unchecked(OpenOrClosed::Closed(0), OpenOrClosed::Open(1));
+
+    assert!(x.is_subset(&i!(LCRO[0, 1])));
+    assert!(!x.is_proper_subset(&i!(LCRO[0, 1])));
+    assert!(!i!(LCRO[0, 1]).is_proper_superset(&x));
+}
+
+#[test]
+fn inclusion_order() {
+    assert_eq!(i!(Open[0, 1]).partial_cmp_inclusion(&i!(Closed[0, 1])), Some(Ordering::Less));
+    assert_eq!(i!(Closed[0, 1]).partial_cmp_inclusion(&i!(LORC[0, 1])), Some(Ordering::Greater));
+    assert_eq!(i!(LCRO[0, 1]).partial_cmp_inclusion(&i!(LORC[0, 1])), None);
+    assert_eq!(i!(Closed[0, 1]).partial_cmp_inclusion(&i!(Closed[2, 3])), None);
+    assert_eq!(i!(Closed[0, 2]).partial_cmp_inclusion(&i!(Closed[1, 3])), None);
+    assert_eq!(i!(Degenerate[0]).partial_cmp_inclusion(&i!(RC[0])), Some(Ordering::Less));
+    assert_eq!(i!(Closed[0.0, 1.0]).partial_cmp_inclusion(&i!(Closed[f64::NAN, 1.0])), None);
+
+    let x = Interval::new_unchecked(OpenOrClosed::Open(0), NoBound::new());
+
+    assert_eq!(x.partial_cmp_inclusion(&i!(LO[0])), Some(Ordering::Equal));
+    assert_eq!(x.partial_cmp_inclusion(&Interval::unbounded()), Some(Ordering::Less));
+
+    // Deduplicating nested ranges: keep only the maximal ones.
+    let xs = [i!(Closed[0, 10]), i!(Closed[2, 3]), i!(Closed[8, 12]), i!(Closed[9, 12]), i!(Closed[0, 10])];
+    let mut maximal: Vec<_> = Vec::new();
+
+    for x in xs.iter() {
+        if maximal.iter().any(|m: &intervals::Closed<i32>| m.partial_cmp_inclusion(x).is_some_and(|o| o.is_ge())) {
+            continue;
+        }
+
+        maximal.retain(|m| x.partial_cmp_inclusion(m) != Some(Ordering::Greater));
+        maximal.push(*x);
+    }
+
+    assert_eq!(maximal, vec![i!(Closed[0, 10]), i!(Closed[8, 12])]);
+}
+
+// Every combination of unbounded, open and closed sides, for each pair of
+// endpoints.
+#[test]
+fn shapes() {
+    fn side(k: usize, x: f64) -> Option<OpenOrClosed<f64>> {
+        match k {
+            0 => None,
+            1 => Some(OpenOrClosed::Open(x)),
+            _ => Some(OpenOrClosed::Closed(x)),
+        }
+    }
+
+    macro_rules! shape {
+        ($l:expr, $r:expr; |$x:ident| $body:expr) => {
+            match ($l, $r) {
+                (None, None) => { let $x = Interval::<_, _>::unbounded(); $body },
+                (Some(l), None) => { let $x = Interval::new_unchecked(l, NoBound::new()); $body },
+                (None, Some(r)) => { let $x = Interval::new_unchecked(NoBound::new(), r); $body },
+                (Some(l), Some(r)) => { let $x = Interval::new_unchecked(l, r); $body },
+            }
+        };
+    }
+
+    for &(a, b, c, d) in [(0.0, 1.0, 0.0, 1.0), (0.0, 1.0, 0.5, 2.0), (0.0, 2.0, 0.5, 1.0)].iter() {
+        for xl in 0..3 {
+            for xr in 0..3 {
+                for yl in 0..3 {
+                    for yr in 0..3 {
+                        shape!(side(xl, a), side(xr, b); |x| shape!(side(yl, c), side(yr, d); |y| {
+                            check(&x, &y);
+                            check(&y, &x);
+                        }));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn matrix() {
+    for &a in ENDPOINTS.iter() {
+        for &b in ENDPOINTS.iter().filter(|&&b| b > a) {
+            for &c in ENDPOINTS.iter() {
+                for &d in ENDPOINTS.iter().filter(|&&d| d > c) {
+                    cross!(
+                        i!(Open[a, b]), i!(Closed[a, b]), i!(LCRO[a, b]), i!(LORC[a, b]),
+                        i!(LO[a]), i!(LC[a]), i!(RO[b]), i!(RC[b]),
+                        i!(Degenerate[a]), Interval::unbounded();
+                        [
+                            i!(Open[c, d]), i!(Closed[c, d]), i!(LCRO[c, d]), i!(LORC[c, d]),
+                            i!(LO[c]), i!(LC[c]), i!(RO[d]), i!(RC[d]),
+                            i!(Degenerate[c]), Interval::unbounded()
+                        ]
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+extern crate intervals;
+
+use intervals::Interval;
+
+#[test]
+fn complement_within_contained() {
+    let pieces = Interval::open_unchecked(2.0, 5.0).complement_within(Interval::closed_unchecked(0.0, 10.0));
+
+    assert_eq!(pieces.len(), 2);
+    assert_eq!(pieces[0], Interval::closed_unchecked(0.0, 2.0));
+    assert_eq!(pieces[1], Interval::closed_unchecked(5.0, 10.0));
+}
+
+#[test]
+fn complement_within_overlap_left() {
+    let pieces = Interval::closed_unchecked(-5.0, 3.0).complement_within(Interval::lcro_unchecked(0.0, 10.0));
+
+    assert_eq!(pieces.len(), 1);
+    assert_eq!(pieces[0], Interval::open_unchecked(3.0, 10.0));
+}
+
+#[test]
+fn complement_within_overlap_right() {
+    let pieces = Interval::lcro_unchecked(7.0, 20.0).complement_within(Interval::closed_unchecked(0.0, 10.0));
+
+    assert_eq!(pieces.len(), 1);
+    assert_eq!(pieces[0], Interval::lcro_unchecked(0.0, 7.0));
+}
+
+#[test]
+fn complement_within_disjoint() {
+    let outer = Interval::closed_unchecked(0, 10);
+
+    let pieces = Interval::closed_unchecked(20, 30).complement_within(outer);
+
+    assert_eq!(pieces.len(), 1);
+    assert_eq!(pieces[0], outer);
+
+    let pieces = Interval::closed_unchecked(-30, -20).complement_within(outer);
+
+    assert_eq!(pieces.len(), 1);
+    assert_eq!(pieces[0], outer);
+
+    // Touching at a closed endpoint leaves the rest, open at the cut.
+    let pieces = Interval::closed_unchecked(10, 20).complement_within(outer);
+
+    assert_eq!(pieces.len(), 1);
+    assert_eq!(pieces[0], Interval::lcro_unchecked(0, 10));
+}
+
+#[test]
+fn complement_within_equal() {
+    let x = Interval::closed_unchecked(0.0, 1.0);
+
+    assert!(x.complement_within(x).is_empty());
+    assert!(Interval::closed_unchecked(-1.0, 2.0).complement_within(x).is_empty());
+}
+
+#[test]
+fn complement_within_openness() {
+    // Only the endpoints of the outer interval remain.
+    let pieces = Interval::open_unchecked(0, 10).complement_within(Interval::closed_unchecked(0, 10));
+
+    assert_eq!(pieces.len(), 2);
+    assert_eq!(pieces[0], Interval::degenerate(0));
+    assert_eq!(pieces[1], Interval::degenerate(10));
+
+    // Open on both sides, the outer interval is fully covered.
+    assert!(Interval::closed_unchecked(0, 10).complement_within(Interval::open_unchecked(0, 10)).is_empty());
+}
@@ -0,0 +1,195 @@
+extern crate intervals;
+
+use intervals::{Interval, discrete::DiscreteValue};
+
+/// Identifier newtype with a reserved zero value.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Id(u16);
+
+impl DiscreteValue for Id {
+    fn next_value(&self) -> Option<Id> {
+        self.0.checked_add(1).map(Id)
+    }
+
+    fn prev_value(&self) -> Option<Id> {
+        match self.0 {
+            0 | 1 => None,
+            x => Some(Id(x - 1)),
+        }
+    }
+}
+
+#[test]
+fn newtype_iter() {
+    let x = Interval::lorc_unchecked(Id(3), Id(6));
+
+    assert_eq!(x.iter().collect::<Vec<_>>(), vec![Id(4), Id(5), Id(6)]);
+    assert_eq!(x.min_element(), Some(Id(4)));
+    assert_eq!(x.max_element(), Some(Id(6)));
+}
+
+#[test]
+fn newtype_edges() {
+    assert_eq!(Interval::lcro_unchecked(Id(1), Id(1)).max_element(), None);
+    assert_eq!(Interval::lcro_unchecked(Id(1), Id(2)).max_element(), Some(Id(1)));
+
+    let x = Interval::closed_unchecked(Id(u16::MAX - 1), Id(u16::MAX));
+
+    assert_eq!(x.iter().collect::<Vec<_>>(), vec![Id(u16::MAX - 1), Id(u16::MAX)]);
+}
+
+#[test]
+fn char_iter() {
+    let x = Interval::closed_unchecked('a', 'z');
+
+    assert_eq!(x.iter().count(), 26);
+    assert_eq!(Interval::open_unchecked('a', 'd').iter().collect::<String>(), "bc");
+
+    let x = Interval::open_unchecked('\u{D7FD}', '\u{E002}');
+
+    assert_eq!(x.min_element(), Some('\u{D7FE}'));
+    assert_eq!(x.max_element(), Some('\u{E001}'));
+    assert_eq!(x.iter().count(), 4);
+}
+
+#[test]
+fn into_iter() {
+    let mut seen = Vec::new();
+
+    for i in Interval::closed_unchecked(3i64, 7) {
+        seen.push(i);
+    }
+
+    assert_eq!(seen, (3..=7).collect::<Vec<_>>());
+
+    let x = Interval::lcro_unchecked(3u32, 7);
+
+    assert_eq!((&x).into_iter().collect::<Vec<_>>(), vec![3, 4, 5, 6]);
+    assert_eq!(x.into_iter().sum::<u32>(), 18);
+    assert_eq!(Interval::lorc_unchecked(3, 7).into_iter().collect::<Vec<_>>(), vec![4, 5, 6, 7]);
+    assert_eq!(Interval::open_unchecked(3, 7).into_iter().collect::<Vec<_>>(), vec![4, 5, 6]);
+    assert_eq!(Interval::open_unchecked(-2, -1).into_iter().next(), None);
+}
+
+#[test]
+fn exact_size() {
+    let x = Interval::closed_unchecked(-10i16, 10);
+    let mut it = x.iter();
+
+    assert_eq!(it.len(), 21);
+
+    it.next();
+
+    assert_eq!(it.len(), 20);
+    assert_eq!(it.by_ref().count(), 20);
+    assert_eq!(it.len(), 0);
+
+    assert_eq!(Interval::closed_unchecked(u8::MIN, u8::MAX).iter().len(), 256);
+    assert_eq!(Interval::closed_unchecked(i16::MIN, i16::MAX).iter().len(), 65536);
+    assert_eq!(Interval::open_unchecked(0u16, 1).iter().len(), 0);
+    assert_eq!(Interval::lcro_unchecked('a', 'z').iter().size_hint(), (25, Some(25)));
+    assert_eq!(Interval::lorc_unchecked(Id(3), Id(6)).iter().size_hint(), (1, None));
+}
+
+#[test]
+fn full_range() {
+    let x = Interval::closed_unchecked(i64::MIN, i64::MAX);
+    let mut it = x.iter();
+
+    assert_eq!(it.size_hint(), (usize::MAX, None));
+    assert_eq!(it.next(), Some(i64::MIN));
+    assert_eq!(it.size_hint(), (usize::MAX, Some(usize::MAX)));
+
+    let mut it = Interval::closed_unchecked(i64::MAX - 1, i64::MAX).iter();
+
+    assert_eq!(it.next(), Some(i64::MAX - 1));
+    assert_eq!(it.next(), Some(i64::MAX));
+    assert_eq!(it.next(), None);
+
+    assert_eq!(Interval::closed_unchecked(u32::MIN, u32::MAX).iter().size_hint(), (1 << 32, Some(1 << 32)));
+}
+
+#[test]
+fn reversed() {
+    assert!(Interval::closed_unchecked(-3i64, 7).iter_rev().eq((-3..=7).rev()));
+    assert!(Interval::open_unchecked(-3i64, 7).iter_rev().eq((-2..7).rev()));
+    assert_eq!(Interval::lorc_unchecked(Id(3), Id(6)).iter_rev().collect::<Vec<_>>(), vec![Id(6), Id(5), Id(4)]);
+    assert_eq!(Interval::lcro_unchecked(Id(1), Id(3)).iter_rev().collect::<Vec<_>>(), vec![Id(2), Id(1)]);
+    assert_eq!(Interval::open_unchecked(0, 1).iter_rev().next(), None);
+}
+
+#[test]
+fn interleaved() {
+    for n in 0..6 {
+        let x = Interval::closed_unchecked(0u32, n);
+
+        // Every pattern of calls from the front (0) and back (1).
+        for pattern in 0..(1u32 << (n + 2)) {
+            let mut it = x.iter();
+            let mut range = 0..=n;
+
+            for bit in 0..(n + 2) {
+                if pattern >> bit & 1 == 0 {
+                    assert_eq!(it.next(), range.next());
+                } else {
+                    assert_eq!(it.next_back(), range.next_back());
+                }
+
+                assert_eq!(it.size_hint(), range.size_hint());
+            }
+
+            assert_eq!(it.next(), None);
+            assert_eq!(it.next_back(), None);
+        }
+    }
+}
+
+#[test]
+fn interleaved_at_limits() {
+    let mut it = Interval::closed_unchecked(i64::MAX - 1, i64::MAX).iter();
+
+    assert_eq!(it.next_back(), Some(i64::MAX));
+    assert_eq!(it.next(), Some(i64::MAX - 1));
+    assert_eq!(it.next_back(), None);
+    assert_eq!(it.next(), None);
+
+    let mut it = Interval::closed_unchecked(i64::MIN, i64::MAX).iter();
+
+    assert_eq!((it.next(), it.next_back()), (Some(i64::MIN), Some(i64::MAX)));
+    assert_eq!((it.next(), it.next_back()), (Some(i64::MIN + 1), Some(i64::MAX - 1)));
+}
+
+#[test]
+fn count_integers() {
+    assert_eq!(Interval::closed_unchecked(-5, -1).count_integers(), Some(5));
+    assert_eq!(Interval::lcro_unchecked(-5, 5).count_integers(), Some(10));
+    assert_eq!(Interval::open_unchecked(-5i8, 5).count_integers(), Some(9));
+    assert_eq!(Interval::degenerate(-7i16).count_integers(), Some(1));
+    assert_eq!(Interval::lcro_unchecked(3u8, 3).count_integers(), Some(0));
+    assert_eq!(Interval::closed_unchecked(3u8, 2).count_integers(), Some(0));
+    assert_eq!(Interval::open_unchecked(0u64, 1).count_integers(), Some(0));
+
+    assert_eq!(Interval::right_open(0).count_integers(), None);
+    assert_eq!(intervals::Unbounded::<i32>::unbounded().count_integers(), None);
+
+    // Agrees with the length of the iterator.
+    for &(a, b) in [(-3, 4), (0, 0), (10, 20)].iter() {
+        let x = Interval::lorc_unchecked(a, b);
+
+        assert_eq!(x.count_integers(), Some(x.iter().count() as u128));
+    }
+}
+
+#[test]
+fn count_integers_extremes() {
+    assert_eq!(Interval::closed_unchecked(i64::MIN, i64::MAX).count_integers(), Some(1 << 64));
+    assert_eq!(Interval::open_unchecked(i64::MIN, i64::MAX).count_integers(), Some((1 << 64) - 2));
+    assert_eq!(Interval::closed_unchecked(u64::MIN, u64::MAX).count_integers(), Some(1 << 64));
+    assert_eq!(Interval::closed_unchecked(u128::MIN, u128::MAX - 1).count_integers(), Some(u128::MAX));
+    assert_eq!(Interval::closed_unchecked(i128::MIN + 1, i128::MAX).count_integers(), Some(u128::MAX));
+    assert_eq!(Interval::closed_unchecked(u128::MIN, u128::MAX).count_integers(), None);
+    assert_eq!(Interval::lorc_unchecked(u128::MIN, u128::MAX).count_integers(), Some(u128::MAX));
+    assert_eq!(Interval::left_closed(0u128).count_integers(), None);
+    assert_eq!(Interval::closed_unchecked(i128::MIN, i128::MAX).count_integers(), None);
+    assert_eq!(Interval::lcro_unchecked(i128::MIN, 0).count_integers(), Some(1 << 127));
+}
@@ -0,0 +1,163 @@
+extern crate intervals;
+
+use intervals::Interval;
+
+#[test]
+fn degenerate() {
+    assert_eq!(Interval::degenerate(3.0).to_string(), "{3}");
+    assert_eq!(Interval::degenerate(-1).to_string(), "{-1}");
+    assert_eq!(Interval::closed_unchecked(0.5, 0.5).to_string(), "{0.5}");
+
+    let x = Interval::closed_unchecked(-1.0, 0.0).intersect(Interval::lcro_unchecked(0.0, 1.0));
+
+    assert_eq!(x.unwrap().to_string(), "{0}");
+}
+
+#[test]
+fn degenerate_bracketed() {
+    assert_eq!(Interval::degenerate(3.0).display_bracketed().to_string(), "[3, 3]");
+    assert_eq!(Interval::degenerate(-1).display_bracketed().to_string(), "[-1, -1]");
+}
+
+#[test]
+fn near_degenerate() {
+    assert_eq!(Interval::open_unchecked(3.0, 3.0).to_string(), "(3, 3)");
+    assert_eq!(Interval::lcro_unchecked(3.0, 3.0).to_string(), "[3, 3)");
+    assert_eq!(Interval::lorc_unchecked(3.0, 3.0).to_string(), "(3, 3]");
+    assert_eq!(Interval::closed_unchecked(3.0, 3.5).to_string(), "[3, 3.5]");
+}
+
+#[test]
+fn normal() {
+    assert_eq!(Interval::closed_unchecked(0, 1).to_string(), "[0, 1]");
+    assert_eq!(Interval::open_unchecked(0, 1).to_string(), "(0, 1)");
+    assert_eq!(Interval::lcro_unchecked(0, 1).to_string(), "[0, 1)");
+    assert_eq!(Interval::lorc_unchecked(0, 1).to_string(), "(0, 1]");
+
+    assert_eq!(Interval::closed_unchecked(0, 1).display_bracketed().to_string(), "[0, 1]");
+}
+
+#[test]
+fn unbounded() {
+    assert_eq!(intervals::Unbounded::<f64>::unbounded().to_string(), "(-\u{221E}, \u{221E})");
+    assert_eq!(Interval::left_closed(0).to_string(), "[0, \u{221E})");
+    assert_eq!(Interval::right_open(0).to_string(), "(-\u{221E}, 0)");
+}
+
+#[test]
+fn alternate() {
+    let cases = [
+        (Interval::open_unchecked(0, 1).to_string(), format!("{:#}", Interval::open_unchecked(0, 1)), "]0, 1["),
+        (Interval::closed_unchecked(0, 1).to_string(), format!("{:#}", Interval::closed_unchecked(0, 1)), "[0, 1]"),
+        (Interval::lcro_unchecked(0, 1).to_string(), format!("{:#}", Interval::lcro_unchecked(0, 1)), "[0, 1["),
+        (Interval::lorc_unchecked(0, 1).to_string(), format!("{:#}", Interval::lorc_unchecked(0, 1)), "]0, 1]"),
+        (Interval::left_open(0).to_string(), format!("{:#}", Interval::left_open(0)), "]0, \u{221E}["),
+        (Interval::left_closed(0).to_string(), format!("{:#}", Interval::left_closed(0)), "[0, \u{221E}["),
+        (Interval::right_open(1).to_string(), format!("{:#}", Interval::right_open(1)), "]-\u{221E}, 1["),
+        (Interval::right_closed(1).to_string(), format!("{:#}", Interval::right_closed(1)), "]-\u{221E}, 1]"),
+        (
+            intervals::Unbounded::<i32>::unbounded().to_string(),
+            format!("{:#}", intervals::Unbounded::<i32>::unbounded()),
+            "]-\u{221E}, \u{221E}[",
+        ),
+    ];
+    let standard = [
+        "(0, 1)", "[0, 1]", "[0, 1)", "(0, 1]",
+        "(0, \u{221E})", "[0, \u{221E})", "(-\u{221E}, 1)", "(-\u{221E}, 1]", "(-\u{221E}, \u{221E})",
+    ];
+
+    for (&(ref default, ref alt, expected), &std) in cases.iter().zip(standard.iter()) {
+        assert_eq!(default, std);
+        assert_eq!(alt, expected);
+    }
+
+    assert_eq!(format!("{:#}", Interval::degenerate(3)), "{3}");
+    assert_eq!(format!("{:#}", Interval::degenerate(3).display_bracketed()), "[3, 3]");
+}
+
+#[test]
+fn alternate_mixed() {
+    use intervals::bounds::{NoBound, OpenOrClosed};
+
+    let x = Interval::new_unchecked(OpenOrClosed::Open(0), OpenOrClosed::Closed(1));
+    let y = Interval::new_unchecked(OpenOrClosed::Closed(0), NoBound::new());
+
+    assert_eq!(format!("{:#}", x), "]0, 1]");
+    assert_eq!(format!("{:#}", y), "[0, \u{221E}[");
+    assert_eq!(format!("{:#}", x.as_ref()), "]0, 1]");
+}
+
+#[test]
+fn precision() {
+    assert_eq!(format!("{:.3}", Interval::closed_unchecked(0.123456, 1.0)), "[0.123, 1.000]");
+    assert_eq!(format!("{:.1}", Interval::lcro_unchecked(-0.25, 2.0)), "[-0.2, 2.0)");
+    assert_eq!(format!("{:.2}", Interval::left_open(0.5)), "(0.50, \u{221E})");
+    assert_eq!(format!("{:.2}", Interval::degenerate(1.0)), "{1.00}");
+    assert_eq!(format!("{:#.1}", Interval::open_unchecked(0.0, 1.0)), "]0.0, 1.0[");
+
+    let x = Interval::new_unchecked(
+        intervals::bounds::OpenOrClosed::Open(1.0 / 3.0),
+        intervals::bounds::OpenOrClosed::Closed(2.0 / 3.0),
+    );
+
+    assert_eq!(format!("{:.4}", x), "(0.3333, 0.6667]");
+}
+
+#[test]
+fn width_and_alignment() {
+    assert_eq!(format!("{:3}", Interval::closed_unchecked(1, 20)), "[  1,  20]");
+    assert_eq!(format!("{:<3}", Interval::open_unchecked(1, 20)), "(1  , 20 )");
+    assert_eq!(format!("{:*^5.1}", Interval::lorc_unchecked(0.0, 1.0)), "(*0.0*, *1.0*]");
+    assert_eq!(format!("{:04}", Interval::right_closed(7)), "(-\u{221E}, 0007]");
+}
+
+macro_rules! snapshots {
+    ($notation:expr; $($x:expr => $expected:expr),* $(,)?) => {
+        $(assert_eq!($x.display_with($notation).to_string(), $expected);)*
+    };
+}
+
+#[test]
+fn latex() {
+    use intervals::{Notation, bounds::{NoBound, OpenOrClosed}};
+
+    snapshots!(Notation::LATEX;
+        Interval::open_unchecked(0, 1) => r"\left(0, 1\right)",
+        Interval::closed_unchecked(0, 1) => r"\left[0, 1\right]",
+        Interval::lcro_unchecked(0, 1) => r"\left[0, 1\right)",
+        Interval::lorc_unchecked(0, 1) => r"\left(0, 1\right]",
+        Interval::left_open(0) => r"\left(0, \infty\right)",
+        Interval::left_closed(0) => r"\left[0, \infty\right)",
+        Interval::right_open(1) => r"\left(-\infty, 1\right)",
+        Interval::right_closed(1) => r"\left(-\infty, 1\right]",
+        intervals::Unbounded::<i32>::unbounded() => r"\left(-\infty, \infty\right)",
+        Interval::degenerate(2) => r"\left[2, 2\right]",
+        Interval::new_unchecked(OpenOrClosed::Closed(0), OpenOrClosed::Open(1)) => r"\left[0, 1\right)",
+        Interval::new_unchecked(NoBound::new(), OpenOrClosed::Closed(1)) => r"\left(-\infty, 1\right]",
+    );
+
+    assert_eq!(Interval::closed_unchecked(0, 1).display_latex().to_string(), r"\left[0, 1\right]");
+    assert_eq!(format!("{:.1}", Interval::lcro_unchecked(0.0, 0.25).display_latex()), r"\left[0.0, 0.2\right)");
+}
+
+#[test]
+fn notations() {
+    use intervals::Notation;
+
+    let x = Interval::lcro_unchecked(0, 1);
+    let y = Interval::left_open(0);
+
+    snapshots!(Notation::STANDARD; x => x.to_string(), y => y.to_string());
+    snapshots!(Notation::EUROPEAN; x => format!("{:#}", x), y => format!("{:#}", y));
+    snapshots!(Notation::ASCII;
+        x => "[0, 1)",
+        y => "(0, inf)",
+        Interval::right_closed(1) => "(-inf, 1]",
+        intervals::Unbounded::<i32>::unbounded() => "(-inf, inf)",
+    );
+
+    let custom = Notation { infinity: "oo", separator: " .. ", ..Notation::EUROPEAN };
+
+    snapshots!(custom; x => "[0 .. 1[", y => "]0 .. oo[");
+    assert_eq!(Notation::default(), Notation::STANDARD);
+}
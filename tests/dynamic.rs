@@ -0,0 +1,114 @@
+extern crate intervals;
+#[cfg(feature = "serde")]
+extern crate serde_test;
+
+use intervals::{Interval, dynamic::IntervalEnum};
+
+fn all_shapes() -> Vec<IntervalEnum<i32>> {
+    vec![
+        Interval::unbounded().into(),
+        Interval::open_unchecked(0, 2).into(),
+        Interval::left_open(0).into(),
+        Interval::right_open(2).into(),
+        Interval::closed_unchecked(0, 2).into(),
+        Interval::left_closed(0).into(),
+        Interval::right_closed(2).into(),
+        Interval::lcro_unchecked(0, 2).into(),
+        Interval::lorc_unchecked(0, 2).into(),
+    ]
+}
+
+#[test]
+fn contains() {
+    let contains = |v| all_shapes().iter().map(|x| x.contains(v)).collect::<Vec<_>>();
+
+    assert_eq!(contains(-1), [true, false, false, true, false, false, true, false, false]);
+    assert_eq!(contains(0), [true, false, false, true, true, true, true, true, false]);
+    assert_eq!(contains(1), [true; 9]);
+    assert_eq!(contains(2), [true, false, true, false, true, true, true, false, true]);
+}
+
+#[test]
+fn display() {
+    let strs: Vec<String> = all_shapes().iter().map(|x| x.to_string()).collect();
+
+    assert_eq!(strs, [
+        "(-\u{221E}, \u{221E})", "(0, 2)", "(0, \u{221E})", "(-\u{221E}, 2)", "[0, 2]",
+        "[0, \u{221E})", "(-\u{221E}, 2]", "[0, 2)", "(0, 2]",
+    ]);
+    assert_eq!(IntervalEnum::from(Interval::degenerate(1)).to_string(), "{1}");
+}
+
+#[test]
+fn std_bounds() {
+    use std::ops::Bound::*;
+
+    for x in all_shapes() {
+        let (left, right) = x.to_std_bounds();
+
+        assert_eq!((left.cloned(), right.cloned()), x.into_std_bounds());
+    }
+
+    assert_eq!(IntervalEnum::from(Interval::lorc_unchecked(0, 2)).into_std_bounds(), (Excluded(0), Included(2)));
+}
+
+#[test]
+fn intersect() {
+    let x = IntervalEnum::from(Interval::closed_unchecked(0, 2));
+
+    assert_eq!(x.intersect(Interval::unbounded().into()), Some(x));
+    assert_eq!(x.intersect(Interval::left_open(0).into()), Some(Interval::lorc_unchecked(0, 2).into()));
+    assert_eq!(x.intersect(Interval::right_open(1).into()), Some(Interval::lcro_unchecked(0, 1).into()));
+    assert_eq!(x.intersect(Interval::open_unchecked(-1, 3).into()), Some(x));
+    assert_eq!(x.intersect(Interval::lorc_unchecked(2, 3).into()), None);
+    assert_eq!(x.intersect(Interval::right_closed(0).into()), Some(Interval::degenerate(0).into()));
+
+    let y = IntervalEnum::from(Interval::left_closed(1)).intersect(Interval::right_closed(4).into()).unwrap();
+
+    assert_eq!(y, Interval::closed_unchecked(1, 4).into());
+    assert_eq!(y.intersect(Interval::right_open(1).into()), None);
+}
+
+#[test]
+fn intersect_all_shapes() {
+    for x in all_shapes() {
+        for y in all_shapes() {
+            let z = x.intersect(y).unwrap();
+
+            assert_eq!(z, y.intersect(x).unwrap());
+            assert!((-2..=4).all(|v| z.contains(v) == (x.contains(v) && y.contains(v))));
+        }
+    }
+}
+
+#[test]
+fn union_closure() {
+    let x = IntervalEnum::from(Interval::open_unchecked(0, 1));
+
+    assert_eq!(x.union_closure(x), Interval::closed_unchecked(0, 1).into());
+    assert_eq!(x.union_closure(Interval::lorc_unchecked(3, 4).into()), Interval::closed_unchecked(0, 4).into());
+    assert_eq!(x.union_closure(Interval::right_open(-1).into()), Interval::right_closed(1).into());
+    assert_eq!(x.union_closure(Interval::unbounded().into()), Interval::unbounded().into());
+
+    for y in all_shapes() {
+        assert_eq!(x.union_closure(y), y.union_closure(x));
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use serde_test::{Token, assert_tokens};
+
+    assert_tokens(&IntervalEnum::from(Interval::lcro_unchecked(0, 2)), &[
+        Token::NewtypeVariant { name: "IntervalEnum", variant: "LCRO" },
+        Token::Struct { name: "Interval", len: 2 },
+        Token::Str("left"),
+        Token::NewtypeStruct { name: "Closed" },
+        Token::I32(0),
+        Token::Str("right"),
+        Token::NewtypeStruct { name: "Open" },
+        Token::I32(2),
+        Token::StructEnd,
+    ]);
+}
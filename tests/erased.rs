@@ -0,0 +1,55 @@
+extern crate intervals;
+
+use intervals::{Interval, bounds::{NoBound, OpenOrClosed}};
+
+type Erased<V> = Interval<OpenOrClosed<V>, OpenOrClosed<V>>;
+
+#[test]
+fn bounded() {
+    let xs: Vec<Erased<i32>> = vec![
+        Interval::open_unchecked(0, 2).into(),
+        Interval::closed_unchecked(0, 2).into(),
+        Interval::lcro_unchecked(0, 2).into(),
+        Interval::lorc_unchecked(0, 2).into(),
+        Interval::degenerate(1).into(),
+    ];
+
+    let strs: Vec<String> = xs.iter().map(|x| x.to_string()).collect();
+
+    assert_eq!(strs, ["(0, 2)", "[0, 2]", "[0, 2)", "(0, 2]", "{1}"]);
+
+    assert_eq!(xs.iter().filter(|x| x.contains(0)).count(), 2);
+    assert_eq!(xs.iter().filter(|x| x.contains(1)).count(), 5);
+    assert_eq!(xs.iter().filter(|x| x.contains(2)).count(), 2);
+
+    assert_eq!(xs[2], Interval::lcro_unchecked(0, 2));
+}
+
+#[test]
+fn half_bounded() {
+    let lefts: Vec<Interval<OpenOrClosed<f64>, NoBound<f64>>> = vec![
+        Interval::left_open(0.0).into(),
+        Interval::left_closed(0.0).into(),
+    ];
+    let rights: Vec<Interval<NoBound<f64>, OpenOrClosed<f64>>> = vec![
+        Interval::right_open(0.0).into(),
+        Interval::right_closed(0.0).into(),
+    ];
+
+    assert_eq!(lefts.iter().map(|x| x.contains(0.0)).collect::<Vec<_>>(), [false, true]);
+    assert_eq!(rights.iter().map(|x| x.contains(0.0)).collect::<Vec<_>>(), [false, true]);
+
+    assert_eq!(lefts[1].to_string(), "[0, \u{221E})");
+    assert_eq!(rights[0].to_string(), "(-\u{221E}, 0)");
+}
+
+#[test]
+fn hull_of_mixed_shapes() {
+    let hull = Interval::hull_of(vec![
+        Erased::from(Interval::lorc_unchecked(-1.0, 0.0)),
+        Erased::from(Interval::closed_unchecked(0.5, 1.0)),
+        Erased::from(Interval::open_unchecked(0.0, 2.0)),
+    ]);
+
+    assert_eq!(hull, Some(Interval::open_unchecked(-1.0, 2.0).into()));
+}
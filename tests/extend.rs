@@ -0,0 +1,71 @@
+extern crate intervals;
+
+#[macro_use]
+mod common;
+
+use intervals::{Interval, bounds::{self, OpenOrClosed}};
+
+#[test]
+fn extend_to_inside() {
+    assert_eq!(i!(Closed[0, 4]).extend_to(2), i!(Closed[0, 4]));
+    assert_eq!(i!(Open[0, 4]).extend_to(2), i!(Open[0, 4]));
+    assert_eq!(i!(LCRO[0, 4]).extend_to(0), i!(LCRO[0, 4]));
+    assert_eq!(i!(LORC[0, 4]).extend_to(4), i!(LORC[0, 4]));
+}
+
+#[test]
+fn extend_to_outside() {
+    assert_eq!(i!(Closed[0, 4]).extend_to(-2), i!(Closed[-2, 4]));
+    assert_eq!(i!(Closed[0, 4]).extend_to(6), i!(Closed[0, 6]));
+
+    let x = i!(Open[0, 4]).extend_to(6);
+
+    assert_eq!(x.left, bounds::Open(0));
+    assert_eq!(x.right, bounds::Closed(6));
+}
+
+#[test]
+fn extend_to_open_endpoint() {
+    let x = i!(Open[0, 4]).extend_to(0);
+
+    assert_eq!(x.left, bounds::Closed(0));
+    assert_eq!(x.right, bounds::Open(4));
+
+    let x = i!(LCRO[0, 4]).extend_to(4);
+
+    assert_eq!(x, i!(Closed[0, 4]));
+}
+
+#[test]
+fn extend_to_unbounded() {
+    assert_eq!(i!(LO[0]).extend_to(10), i!(LO[0]));
+    assert_eq!(i!(LC[0]).extend_to(-10), i!(LC[-10]));
+    assert_eq!(i!(RO[0]).extend_to(-10), i!(RO[0]));
+    assert_eq!(i!(RC[0]).extend_to(10), i!(RC[10]));
+
+    let x = i!(LO[0]).extend_to(0);
+
+    assert_eq!(x.left, bounds::Closed(0));
+    assert_eq!(x.right, bounds::NoBound::new());
+
+    let x: intervals::Unbounded<i32> = Interval::unbounded();
+
+    assert_eq!(x.extend_to(5), x);
+}
+
+#[test]
+fn extend_to_include() {
+    let mut x = Interval::new_unchecked(OpenOrClosed::Open(0.0), OpenOrClosed::Open(1.0));
+
+    x.extend_to_include(0.5);
+    assert_eq!(x, i!(Open[0.0, 1.0]));
+
+    x.extend_to_include(0.0);
+    assert_eq!(x, i!(LCRO[0.0, 1.0]));
+
+    x.extend_to_include(3.0);
+    assert_eq!(x, i!(Closed[0.0, 3.0]));
+
+    x.extend_to_include(-1.0);
+    assert_eq!(x, i!(Closed[-1.0, 3.0]));
+}
@@ -0,0 +1,96 @@
+extern crate intervals;
+
+use intervals::{Interval, bounds::{self, NoBound, OpenOrClosed}};
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+fn hash_of<T: Hash>(x: &T) -> u64 {
+    let mut state = std::collections::hash_map::DefaultHasher::new();
+
+    x.hash(&mut state);
+    state.finish()
+}
+
+#[test]
+fn equal_intervals_hash_equally() {
+    assert_eq!(hash_of(&Interval::closed_unchecked(0i64, 5)), hash_of(&Interval::closed_unchecked(0i64, 5)));
+    assert_eq!(hash_of(&Interval::left_open(3u8)), hash_of(&Interval::left_open(3u8)));
+    assert_eq!(hash_of(&Interval::right_closed(-2i32)), hash_of(&Interval::right_closed(-2i32)));
+
+    let x = Interval::new_unchecked(OpenOrClosed::Open(1), OpenOrClosed::Closed(2));
+
+    assert_eq!(hash_of(&x), hash_of(&x.clone()));
+    assert_eq!(hash_of(&NoBound::<f64>::new()), hash_of(&NoBound::<f64>::default()));
+    assert_eq!(hash_of(&bounds::Open(4)), hash_of(&bounds::Open(4)));
+}
+
+#[test]
+fn distinct_intervals() {
+    let xs = [
+        Interval::new_unchecked(OpenOrClosed::Closed(0), OpenOrClosed::Closed(1)),
+        Interval::new_unchecked(OpenOrClosed::Open(0), OpenOrClosed::Closed(1)),
+        Interval::new_unchecked(OpenOrClosed::Closed(0), OpenOrClosed::Open(1)),
+        Interval::new_unchecked(OpenOrClosed::Open(0), OpenOrClosed::Open(1)),
+        Interval::new_unchecked(OpenOrClosed::Closed(0), OpenOrClosed::Closed(2)),
+    ];
+
+    for (i, x) in xs.iter().enumerate() {
+        for y in xs[..i].iter() {
+            assert_ne!(hash_of(x), hash_of(y), "{} vs {}", x, y);
+        }
+    }
+}
+
+fn dedup<T: Eq + Clone>(xs: &[T]) -> Vec<T> {
+    let mut out: Vec<T> = Vec::new();
+
+    for x in xs {
+        if !out.contains(x) { out.push(x.clone()); }
+    }
+
+    out
+}
+
+#[test]
+fn eq_in_generic_context() {
+    let xs = [
+        Interval::lcro_unchecked(0u32, 4),
+        Interval::lcro_unchecked(1, 4),
+        Interval::lcro_unchecked(0, 4),
+    ];
+
+    assert_eq!(dedup(&xs), vec![xs[0], xs[1]]);
+    assert_eq!(dedup(&[Interval::left_open('a'), Interval::left_open('a')]).len(), 1);
+    assert_eq!(dedup(&[bounds::Closed(1), bounds::Closed(1), bounds::Closed(2)]).len(), 2);
+    assert_eq!(dedup(&[NoBound::<i8>::new(), NoBound::new()]).len(), 1);
+
+    let ys = [
+        Interval::new_unchecked(OpenOrClosed::Open(0), NoBound::new()),
+        Interval::new_unchecked(OpenOrClosed::Closed(0), NoBound::new()),
+        Interval::new_unchecked(OpenOrClosed::Open(0), NoBound::new()),
+    ];
+
+    assert_eq!(dedup(&ys).len(), 2);
+}
+
+#[test]
+fn hash_map_keys() {
+    let mut map: HashMap<intervals::Closed<i64>, &str> = HashMap::new();
+
+    map.insert(Interval::closed_unchecked(0, 9), "digits");
+    map.insert(Interval::closed_unchecked(10, 99), "tens");
+
+    assert_eq!(map.insert(Interval::closed_unchecked(0, 9), "units"), Some("digits"));
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&Interval::closed_unchecked(10, 99)), Some(&"tens"));
+    assert_eq!(map.get(&Interval::closed_unchecked(10, 98)), None);
+
+    let set: HashSet<_> = [
+        Interval::new_unchecked(OpenOrClosed::Closed(0), OpenOrClosed::Closed(1)),
+        Interval::new_unchecked(OpenOrClosed::Open(0), OpenOrClosed::Closed(1)),
+        Interval::new_unchecked(OpenOrClosed::Closed(0), OpenOrClosed::Closed(1)),
+    ].iter().cloned().collect();
+
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&Interval::new_unchecked(OpenOrClosed::Open(0), OpenOrClosed::Closed(1))));
+}
@@ -0,0 +1,124 @@
+extern crate intervals;
+
+use intervals::{Interval, bounds::{self, OpenOrClosed}};
+
+type Erased = Interval<OpenOrClosed<i32>, OpenOrClosed<i32>>;
+
+fn x(lo: bool, l: i32, r: i32, ro: bool) -> Erased {
+    let bound = |open, v| if open { OpenOrClosed::Open(v) } else { OpenOrClosed::Closed(v) };
+
+    Interval::new_unchecked(bound(lo, l), bound(ro, r))
+}
+
+#[test]
+fn empty() {
+    assert_eq!(Interval::hull_of(Vec::<Erased>::new()), None);
+}
+
+#[test]
+fn single() {
+    for &(lo, ro) in [(true, true), (true, false), (false, true), (false, false)].iter() {
+        assert_eq!(Interval::hull_of(vec![x(lo, 0, 1, ro)]), Some(x(lo, 0, 1, ro)));
+    }
+}
+
+#[test]
+fn disjoint() {
+    let hull = Interval::hull_of(vec![x(false, 5, 6, true), x(true, -1, 0, true), x(true, 2, 3, false)]);
+
+    assert_eq!(hull, Some(x(true, -1, 6, true)));
+}
+
+#[test]
+fn shared_endpoints() {
+    assert_eq!(Interval::hull_of(vec![x(true, 0, 1, true), x(false, 0, 1, true)]), Some(x(false, 0, 1, true)));
+    assert_eq!(Interval::hull_of(vec![x(true, 0, 1, true), x(true, 0, 1, false)]), Some(x(true, 0, 1, false)));
+    assert_eq!(Interval::hull_of(vec![x(true, 0, 1, true), x(true, 0, 1, true)]), Some(x(true, 0, 1, true)));
+}
+
+#[test]
+fn order_independent() {
+    let xs = vec![x(false, 3, 4, true), x(true, 1, 9, true), x(false, 0, 2, false), x(true, 7, 9, false)];
+
+    let expected = Some(x(false, 0, 9, false));
+
+    for k in 0..xs.len() {
+        let mut ys = xs.clone();
+
+        ys.rotate_left(k);
+
+        assert_eq!(Interval::hull_of(ys.iter().cloned()), expected);
+
+        ys.reverse();
+
+        assert_eq!(Interval::hull_of(ys), expected);
+    }
+}
+
+#[test]
+fn spanning() {
+    assert_eq!(Interval::spanning(vec![3, -1, 4, 1, 5, -9, 2, 6]), Some(Interval::closed_unchecked(-9, 6)));
+    assert_eq!(Interval::spanning(Some(2.5)), Some(Interval::degenerate(2.5)));
+    assert_eq!(Interval::spanning(std::iter::empty::<u8>()), None);
+
+    // Lazily evaluated, without collecting.
+    assert_eq!(Interval::spanning((1..1_000_000u64).map(|k| k * k % 1009)), Some(Interval::closed_unchecked(0, 1008)));
+}
+
+#[test]
+fn spanning_nan() {
+    let data = [f64::NAN, 2.0, f64::NAN, -3.0, 1.0];
+
+    assert_eq!(Interval::spanning(data.iter().cloned()), Some(Interval::closed_unchecked(-3.0, 2.0)));
+    assert_eq!(Interval::spanning(vec![f64::NAN, f64::NAN]), None);
+    assert_eq!(
+        Interval::spanning(vec![f64::INFINITY, 0.0, f64::NEG_INFINITY]),
+        Some(Interval::closed_unchecked(f64::NEG_INFINITY, f64::INFINITY))
+    );
+}
+
+#[test]
+fn from_sample() {
+    assert_eq!(Interval::from_sample(&[5u8, 1, 9, 3]), Some(Interval::closed_unchecked(1, 9)));
+    assert_eq!(Interval::from_sample(&[0.5]), Some(Interval::degenerate(0.5)));
+    assert_eq!(Interval::<bounds::Closed<i32>, _>::from_sample(&[]), None);
+
+    assert_eq!(Interval::from_sample(&[f64::NAN, 1.0, -2.0]), Some(Interval::closed_unchecked(-2.0, 1.0)));
+    assert_eq!(Interval::from_sample(&[f64::NAN]), None);
+}
+
+#[test]
+fn from_sample_trimmed() {
+    let data: Vec<i32> = (0..=100).rev().collect();
+
+    assert_eq!(Interval::from_sample_trimmed(&data, 0.0, 1.0), Interval::from_sample(&data));
+    assert_eq!(Interval::from_sample_trimmed(&data, 0.05, 0.95), Some(Interval::closed_unchecked(5, 95)));
+    assert_eq!(Interval::from_sample_trimmed(&data, 0.5, 0.5), Some(Interval::degenerate(50)));
+
+    // Four values: positions 0.75 and 2.25 round to the second and third.
+    assert_eq!(Interval::from_sample_trimmed(&[4, 1, 3, 2], 0.25, 0.75), Some(Interval::closed_unchecked(2, 3)));
+
+    assert_eq!(Interval::<bounds::Closed<i32>, _>::from_sample_trimmed(&[], 0.1, 0.9), None);
+}
+
+#[test]
+fn from_sample_trimmed_nan() {
+    // NaN is skipped before ranking, so only the five numbers count.
+    let data = [f64::NAN, 10.0, f64::NAN, 0.0, 5.0, 2.5, 7.5];
+
+    assert_eq!(Interval::from_sample_trimmed(&data, 0.25, 0.75), Some(Interval::closed_unchecked(2.5, 7.5)));
+    assert_eq!(Interval::from_sample_trimmed(&[f64::NAN, f64::NAN], 0.0, 1.0), None);
+}
+
+#[test]
+fn from_sample_trimmed_inverted() {
+    assert_eq!(Interval::from_sample_trimmed(&[1, 2, 3], 0.9, 0.1), None);
+}
+
+#[test]
+fn from_sample_trimmed_out_of_range() {
+    assert_eq!(Interval::from_sample_trimmed(&[1.0, 2.0, 3.0], 0.0, f64::NAN), None);
+    assert_eq!(Interval::from_sample_trimmed(&[1.0, 2.0, 3.0], f64::NAN, 1.0), None);
+    assert_eq!(Interval::from_sample_trimmed(&[1.0, 2.0, 3.0], -0.1, 0.5), None);
+    assert_eq!(Interval::from_sample_trimmed(&[1.0, 2.0, 3.0], 0.5, 1.1), None);
+}
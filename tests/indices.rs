@@ -0,0 +1,38 @@
+extern crate intervals;
+
+use intervals::Interval;
+
+#[test]
+fn indices_of() {
+    assert_eq!(Interval::indices_of(&[1, 2, 3]), Interval::lcro_unchecked(0, 3));
+    assert_eq!(Interval::indices_inclusive(&[1, 2, 3]), Some(Interval::closed_unchecked(0, 2)));
+}
+
+#[test]
+fn indices_of_empty() {
+    let empty: [f64; 0] = [];
+    let x = Interval::indices_of(&empty);
+
+    assert_eq!(x, Interval::lcro_unchecked(0, 0));
+    assert!(!x.contains(0));
+    assert_eq!(Interval::indices_inclusive(&empty), None);
+}
+
+#[test]
+fn indices_of_huge() {
+    let units = vec![(); usize::MAX];
+
+    assert_eq!(Interval::indices_of(&units), Interval::lcro_unchecked(0, usize::MAX));
+    assert_eq!(Interval::indices_inclusive(&units), Some(Interval::closed_unchecked(0, usize::MAX - 1)));
+}
+
+#[test]
+fn indices_of_clip() {
+    let buffer = [0u8; 8];
+
+    let clip = |a, b| Interval::closed_unchecked(a, b).intersect(Interval::indices_of(&buffer));
+
+    assert_eq!(clip(2, 5).unwrap(), Interval::closed_unchecked(2, 5));
+    assert_eq!(clip(6, 20).unwrap(), Interval::lcro_unchecked(6, 8));
+    assert!(clip(8, 20).is_none());
+}
@@ -0,0 +1,74 @@
+extern crate intervals;
+
+use intervals::Interval;
+
+#[test]
+fn lerp_endpoints() {
+    let x = Interval::closed_unchecked(-2.0, 6.0);
+
+    assert_eq!(x.lerp(0.0), -2.0);
+    assert_eq!(x.lerp(1.0), 6.0);
+    assert_eq!(x.lerp(0.5), 2.0);
+    assert_eq!(x.lerp(0.5), x.midpoint().unwrap());
+
+    // Openness makes no difference.
+    assert_eq!(Interval::open_unchecked(-2.0, 6.0).lerp(0.0), -2.0);
+    assert_eq!(Interval::lcro_unchecked(-2.0, 6.0).lerp(1.0), 6.0);
+}
+
+#[test]
+fn lerp_extrapolated() {
+    let x = Interval::lorc_unchecked(1.0, 3.0);
+
+    assert_eq!(x.lerp(-1.0), -1.0);
+    assert_eq!(x.lerp(2.0), 5.0);
+    assert_eq!(Interval::degenerate(4.0).lerp(10.0), 4.0);
+}
+
+#[test]
+fn lerp_f32() {
+    let x = Interval::closed_unchecked(0.0f32, 10.0);
+
+    assert_eq!(x.lerp(0.0), 0.0);
+    assert_eq!(x.lerp(0.5), 5.0);
+    assert_eq!(x.lerp(1.0), 10.0);
+    assert_eq!(Interval::<_, _>::unit().lerp(0.25f32), 0.25);
+}
+
+#[test]
+fn relative_position() {
+    let x = Interval::lcro_unchecked(-2.0, 6.0);
+
+    assert_eq!(x.relative_position(&-2.0), Some(0.0));
+    assert_eq!(x.relative_position(&6.0), Some(1.0));
+    assert_eq!(x.relative_position(&0.0), Some(0.25));
+    assert_eq!(x.relative_position(&-10.0), Some(-1.0));
+
+    for &t in &[-1.0, 0.0, 0.25, 0.5, 1.0, 3.0] {
+        assert_eq!(x.relative_position(&x.lerp(t)), Some(t));
+    }
+}
+
+#[test]
+fn relative_position_undefined() {
+    assert_eq!(Interval::degenerate(1.0).relative_position(&1.0), None);
+    assert_eq!(Interval::open_unchecked(1.0, 1.0).relative_position(&0.0), None);
+    assert_eq!(Interval::left_closed(0.0).relative_position(&1.0), None);
+    assert_eq!(Interval::right_open(0.0).relative_position(&-1.0), None);
+    assert_eq!(intervals::Unbounded::<f64>::unbounded().relative_position(&0.0), None);
+}
+
+#[test]
+fn rescale() {
+    let source = Interval::closed_unchecked(0.0, 10.0);
+    let target = Interval::open_unchecked(-1.0, 1.0);
+
+    assert_eq!(source.rescale(&target, &0.0), Some(-1.0));
+    assert_eq!(source.rescale(&target, &5.0), Some(0.0));
+    assert_eq!(source.rescale(&target, &10.0), Some(1.0));
+    assert_eq!(source.rescale(&target, &20.0), Some(3.0));
+    assert_eq!(target.rescale(&source, &0.5), Some(7.5));
+
+    assert_eq!(Interval::degenerate(0.0).rescale(&target, &0.0), None);
+    assert_eq!(Interval::left_closed(0.0).rescale(&target, &1.0), None);
+}
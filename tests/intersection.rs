@@ -1,21 +1,20 @@
 extern crate intervals;
 
-use intervals::Interval;
-
-macro_rules! i {
-    (Open[$left:expr, $right:expr]) => { Interval::open_unchecked($left, $right) };
-    (Closed[$left:expr, $right:expr]) => { Interval::closed_unchecked($left, $right) };
-
-    (LCRO[$left:expr, $right:expr]) => { Interval::lcro_unchecked($left, $right) };
-    (LORC[$left:expr, $right:expr]) => { Interval::lorc_unchecked($left, $right) };
-
-    (LO[$left:expr]) => { Interval::left_open($left) };
-    (LC[$left:expr]) => { Interval::left_closed($left) };
-
-    (RO[$right:expr]) => { Interval::right_open($right) };
-    (RC[$right:expr]) => { Interval::right_closed($right) };
-
-    (Degenerate[$x:expr]) => { Interval::degenerate($x) };
+#[macro_use]
+mod common;
+
+use intervals::{Interval, bounds::Bound};
+
+// Width of an intersection, which is zero when it is empty.
+fn width<L, R>(z: Option<Interval<L, R>>) -> Option<f64>
+where
+    L: Bound<Value = f64>,
+    R: Bound<Value = f64>,
+{
+    match z {
+        Some(z) => z.left.value().zip(z.right.value()).map(|(l, r)| r - l),
+        None => Some(0.0),
+    }
 }
 
 macro_rules! test_intersects {
@@ -23,6 +22,14 @@ macro_rules! test_intersects {
         let x = $x;
         $({
             let y = $y;
+
+            assert_eq!(x.intersects(&y), x.intersect(y).is_some(), "{:?} vs {:?}", x, y);
+            assert_eq!(y.intersects(&x), y.intersect(x).is_some(), "{:?} vs {:?}", y, x);
+            assert_eq!(x.intersect_ref(&y), x.intersect(y));
+            assert_eq!(y.intersect_ref(&x), y.intersect(x));
+            assert_eq!(x.overlap(&y), width(x.intersect(y)), "{:?} vs {:?}", x, y);
+            assert_eq!(y.overlap(&x), width(y.intersect(x)), "{:?} vs {:?}", y, x);
+
             let $z = x.intersect(y);
 
             { $test }
@@ -549,3 +556,141 @@ fn rc() {
         ]
     );
 }
+
+macro_rules! test_overlaps_within {
+    ($x:expr, $eps:expr; [$(($y:expr, $expected:expr)),+]) => {{
+        let x = $x;
+        $({
+            let y = $y;
+
+            assert_eq!(x.overlaps_within(&y, $eps), $expected);
+            assert_eq!(y.overlaps_within(&x, $eps), $expected);
+        })+
+    }};
+}
+
+#[test]
+fn overlaps_within_zero_eps() {
+    // With zero tolerance this is overlap-or-adjacency, regardless of openness.
+    test_overlaps_within!(
+        i!(Closed[0.0, 1.0]), 0.0;
+        [
+            (i!(Closed[1.0, 2.0]), true),
+            (i!(Open[1.0, 2.0]), true),
+            (i!(Closed[0.5, 2.0]), true),
+            (i!(Closed[1.0 + 1e-9, 2.0]), false),
+            (i!(Closed[-2.0, -1e-9]), false)
+        ]
+    );
+
+    test_overlaps_within!(
+        i!(Open[0.0, 1.0]), 0.0;
+        [
+            (i!(Open[1.0, 2.0]), true),
+            (i!(LCRO[1.0, 2.0]), true),
+            (i!(LORC[-1.0, 0.0]), true),
+            (i!(Open[-2.0, -1.0]), false)
+        ]
+    );
+}
+
+#[test]
+fn overlaps_within_eps() {
+    test_overlaps_within!(
+        i!(LCRO[0.0, 1.0]), 0.5;
+        [
+            (i!(Closed[1.5, 2.0]), true),
+            (i!(Open[1.5, 2.0]), true),
+            (i!(Closed[1.75, 2.0]), false),
+            (i!(Closed[-2.0, -0.5]), true),
+            (i!(Closed[-2.0, -0.75]), false)
+        ]
+    );
+}
+
+#[test]
+fn overlaps_within_at_type_limits() {
+    test_overlaps_within!(
+        i!(Closed[250u8, u8::MAX]), 10;
+        [
+            (i!(Closed[u8::MAX, u8::MAX]), true),
+            (i!(Closed[0, 240]), true),
+            (i!(Closed[0, 239]), false),
+            (i!(LC[0]), true)
+        ]
+    );
+
+    test_overlaps_within!(
+        i!(Closed[i8::MIN, -100]), i8::MAX;
+        [
+            (i!(Closed[27, i8::MAX]), true),
+            (i!(Closed[28, i8::MAX]), false),
+            (i!(Closed[i8::MIN, i8::MIN]), true)
+        ]
+    );
+}
+
+#[test]
+fn overlaps_within_half_bounded() {
+    test_overlaps_within!(
+        i!(Closed[0.0, 1.0]), 0.5;
+        [
+            (i!(LO[1.5]), true),
+            (i!(LC[1.75]), false),
+            (i!(RO[-0.5]), true),
+            (i!(RC[-0.75]), false),
+            (i!(LC[-10.0]), true),
+            (i!(RC[10.0]), true)
+        ]
+    );
+
+    test_overlaps_within!(
+        i!(LO[0.0]), 0.0;
+        [
+            (i!(RO[0.0]), true),
+            (i!(RO[-1e-9]), false),
+            (i!(LC[-10.0]), true)
+        ]
+    );
+}
+
+#[test]
+fn overlaps_within_nan() {
+    let x = i!(Closed[0.0, 1.0]);
+
+    assert!(!x.overlaps_within(&x, f64::NAN));
+    assert!(!x.overlaps_within(&x, -1.0));
+    assert!(!x.overlaps_within(&i!(Closed[f64::NAN, 2.0]), 1.0));
+}
+
+#[test]
+fn intersects_touching() {
+    assert!(i!(Closed[0.0, 1.0]).intersects(&i!(Closed[1.0, 2.0])));
+    assert!(!i!(Closed[0.0, 1.0]).intersects(&i!(Open[1.0, 2.0])));
+    assert!(!i!(LCRO[0.0, 1.0]).intersects(&i!(Closed[1.0, 2.0])));
+    assert!(!i!(LCRO[0.0, 1.0]).intersects(&i!(LCRO[1.0, 2.0])));
+    assert!(i!(LORC[0.0, 1.0]).intersects(&i!(LCRO[1.0, 2.0])));
+
+    assert!(i!(RC[0.0]).intersects(&i!(LC[0.0])));
+    assert!(!i!(RO[0.0]).intersects(&i!(LC[0.0])));
+    assert!(!i!(Closed[0.0, 1.0]).intersects(&i!(Closed[f64::NAN, 2.0])));
+}
+
+#[test]
+fn overlap_integers() {
+    assert_eq!(i!(Closed[0, 10]).overlap(&i!(Closed[3, 5])), Some(2));
+    assert_eq!(i!(LCRO[0u8, 10]).overlap(&i!(LORC[5, 20])), Some(5));
+    assert_eq!(i!(Closed[0u8, 10]).overlap(&i!(Closed[20, 30])), Some(0));
+    assert_eq!(i!(LCRO[0, 10]).overlap(&i!(Closed[10, 20])), Some(0));
+    assert_eq!(i!(LC[0i64]).overlap(&i!(RO[7])), Some(7));
+}
+
+#[test]
+fn overlap_unbounded() {
+    assert_eq!(i!(LC[0.0]).overlap(&i!(LO[1.0])), None);
+    assert_eq!(i!(RC[0]).overlap(&Interval::unbounded()), None);
+    assert_eq!(intervals::Unbounded::<f64>::unbounded().overlap(&Interval::unbounded()), None);
+
+    // Disjoint intervals overlap by zero, however far they extend.
+    assert_eq!(i!(LO[1.0]).overlap(&i!(RO[1.0])), Some(0.0));
+}
@@ -0,0 +1,168 @@
+extern crate intervals;
+
+use intervals::{Interval, bounds::OpenOrClosed, interval_map::IntervalMap, ops::is_sorted_disjoint};
+
+fn hours() -> IntervalMap<u32, &'static str> {
+    let mut map = IntervalMap::new();
+
+    map.insert(Interval::lcro_unchecked(9, 12), "morning").unwrap();
+    map.insert(Interval::closed_unchecked(13, 17), "afternoon").unwrap();
+
+    map
+}
+
+#[test]
+fn insert_rejects_overlap() {
+    let mut map = hours();
+
+    assert!(map.insert(Interval::closed_unchecked(11, 13), "lunch").is_err());
+    assert!(map.insert(Interval::open_unchecked(12, 12), "empty").is_err());
+    assert_eq!(map.insert(Interval::open_unchecked(12, 13), "lunch"), Ok(1));
+
+    assert_eq!(map.len(), 3);
+    assert!(is_sorted_disjoint(map.keys()));
+}
+
+#[test]
+fn insert_hands_back_empty_key() {
+    let mut map = hours();
+    let (key, value) = map.insert(Interval::lorc_unchecked(20, 18), "backwards").unwrap_err();
+
+    assert_eq!(key, Interval::new_unchecked(OpenOrClosed::Open(20), OpenOrClosed::Closed(18)));
+    assert_eq!(value, "backwards");
+    assert_eq!(map.len(), 2);
+}
+
+#[test]
+fn split_entry_at_interior() {
+    let mut map = hours();
+
+    assert_eq!(map.split_entry_at(15), Some(2));
+    assert_eq!(map.len(), 3);
+    assert!(is_sorted_disjoint(map.keys()));
+
+    assert_eq!(map.keys()[1], Interval::lcro_unchecked(13, 15));
+    assert_eq!(map.keys()[2], Interval::closed_unchecked(15, 17));
+    assert_eq!(map.get(&14), Some(&"afternoon"));
+    assert_eq!(map.get(&15), Some(&"afternoon"));
+}
+
+#[test]
+fn split_entry_at_boundary() {
+    let mut map = hours();
+
+    // Already a boundary: a no-op returning the entry starting there.
+    assert_eq!(map.split_entry_at(13), Some(1));
+    assert_eq!(map, hours());
+
+    // Not covered by any entry.
+    assert_eq!(map.split_entry_at(12), None);
+    assert_eq!(map, hours());
+
+    // A closed right bound yields a degenerate trailing entry.
+    assert_eq!(map.split_entry_at(17), Some(2));
+    assert_eq!(map.keys()[2], Interval::degenerate(17));
+    assert!(is_sorted_disjoint(map.keys()));
+}
+
+#[test]
+fn range_overlapping_mut() {
+    let mut map = IntervalMap::new();
+
+    for k in 0..10 {
+        map.insert(Interval::lcro_unchecked(k * 10, (k + 1) * 10), 0).unwrap();
+    }
+
+    for (_, v) in map.range_overlapping_mut(&Interval::open_unchecked(20, 50)) {
+        *v += 1;
+    }
+
+    let values: Vec<i32> = map.iter().map(|(_, &v)| v).collect();
+
+    assert_eq!(values, vec![0, 0, 1, 1, 1, 0, 0, 0, 0, 0]);
+    assert_eq!(map.range_overlapping(&Interval::closed_unchecked(50, 50)).count(), 1);
+    assert_eq!(map.range_overlapping(&Interval::left_closed(100)).count(), 0);
+}
+
+#[test]
+fn book_part_of_window() {
+    let mut map = IntervalMap::new();
+
+    map.insert(Interval::closed_unchecked(0.0, 8.0), true).unwrap();
+    map.split_entry_at(2.5);
+    map.split_entry_at(4.0);
+
+    for (_, free) in map.range_overlapping_mut(&Interval::lcro_unchecked(2.5, 4.0)) {
+        *free = false;
+    }
+
+    assert_eq!(map.get(&2.0), Some(&true));
+    assert_eq!(map.get(&2.5), Some(&false));
+    assert_eq!(map.get(&3.9), Some(&false));
+    assert_eq!(map.get(&4.0), Some(&true));
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    extern crate serde_test;
+
+    use self::serde_test::{Token, assert_de_tokens_error, assert_tokens};
+    use intervals::{Interval, interval_map::IntervalMap};
+
+    fn tokens(keys: &[(i32, i32)], values: &[i32]) -> Vec<Token> {
+        let mut tokens = vec![
+            Token::Struct { name: "IntervalMap", len: 2 },
+            Token::Str("keys"),
+            Token::Seq { len: Some(keys.len()) },
+        ];
+
+        for &(l, r) in keys {
+            tokens.extend_from_slice(&[
+                Token::Struct { name: "Interval", len: 2 },
+                Token::Str("left"),
+                Token::NewtypeVariant { name: "OpenOrClosed", variant: "Closed" },
+                Token::I32(l),
+                Token::Str("right"),
+                Token::NewtypeVariant { name: "OpenOrClosed", variant: "Open" },
+                Token::I32(r),
+                Token::StructEnd,
+            ]);
+        }
+
+        tokens.extend_from_slice(&[Token::SeqEnd, Token::Str("values"), Token::Seq { len: Some(values.len()) }]);
+        tokens.extend(values.iter().map(|&v| Token::I32(v)));
+        tokens.extend_from_slice(&[Token::SeqEnd, Token::StructEnd]);
+
+        tokens
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut map = IntervalMap::new();
+
+        map.insert(Interval::lcro_unchecked(0, 5), 1).unwrap();
+        map.insert(Interval::lcro_unchecked(5, 9), 2).unwrap();
+
+        assert_tokens(&map, &tokens(&[(0, 5), (5, 9)], &[1, 2]));
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        assert_de_tokens_error::<IntervalMap<i32, i32>>(
+            &tokens(&[(0, 5)], &[1, 2]),
+            "the numbers of keys and values differ",
+        );
+        assert_de_tokens_error::<IntervalMap<i32, i32>>(
+            &tokens(&[(5, 9), (0, 5)], &[1, 2]),
+            "the keys are not sorted and disjoint",
+        );
+        assert_de_tokens_error::<IntervalMap<i32, i32>>(
+            &tokens(&[(0, 6), (5, 9)], &[1, 2]),
+            "the keys are not sorted and disjoint",
+        );
+        assert_de_tokens_error::<IntervalMap<i32, i32>>(
+            &tokens(&[(5, 5)], &[1]),
+            "a key is empty",
+        );
+    }
+}
@@ -0,0 +1,118 @@
+extern crate intervals;
+
+#[macro_use]
+mod common;
+
+use intervals::{Interval, bounds::{self, OpenOrClosed}, iter::IntervalFilterExt};
+
+macro_rules! test_filter {
+    ($($x:expr => $expected:expr),+) => {{
+        $({
+            let x = $x;
+            let filtered: Vec<i32> = (-3..=3).filter_interval(&x).collect();
+
+            assert_eq!(filtered, $expected);
+
+            let (inside, outside): (Vec<i32>, Vec<i32>) = (-3..=3).partition_interval(&x);
+
+            assert_eq!(inside, $expected);
+            assert_eq!(inside.len() + outside.len(), 7);
+            assert!(outside.iter().all(|v| !x.contains(*v)));
+        })+
+    }};
+}
+
+#[test]
+fn filter_aliases() {
+    test_filter!(
+        Interval::unbounded() => vec![-3, -2, -1, 0, 1, 2, 3],
+        i!(Open[-1, 1]) => vec![0],
+        i!(Closed[-1, 1]) => vec![-1, 0, 1],
+        i!(LCRO[-1, 1]) => vec![-1, 0],
+        i!(LORC[-1, 1]) => vec![0, 1],
+        i!(LO[1]) => vec![2, 3],
+        i!(LC[1]) => vec![1, 2, 3],
+        i!(RO[-1]) => vec![-3, -2],
+        i!(RC[-1]) => vec![-3, -2, -1],
+        i!(Degenerate[0]) => vec![0]
+    );
+}
+
+#[test]
+fn filter_mixed() {
+    test_filter!(
+        Interval::new_unchecked(OpenOrClosed::Open(-1), OpenOrClosed::Closed(1)) => vec![0, 1],
+        Interval::new_unchecked(bounds::Closed(-1), OpenOrClosed::Open(1)) => vec![-1, 0],
+        Interval::new_unchecked(OpenOrClosed::Closed(2), bounds::NoBound::new()) => vec![2, 3]
+    );
+}
+
+#[test]
+fn filter_nan() {
+    let values = [f64::NAN, 0.5, f64::NAN];
+
+    assert_eq!(values.iter().cloned().filter_interval(&i!(Closed[0.0, 1.0])).count(), 1);
+    assert_eq!(values.iter().cloned().filter_interval(&Interval::unbounded()).count(), 3);
+}
+
+#[test]
+fn iter_step_floats() {
+    let x = i!(Closed[0.0, 1.0]);
+    let points: Vec<f64> = x.iter_step(0.1).collect();
+
+    assert_eq!(points.len(), 11);
+    assert_eq!(points[3], 0.1 * 3.0);
+    assert_eq!(points[10], 1.0);
+
+    // Repeated addition would drift from the exact multiples.
+    let sum = (0..10).fold(0.0, |acc, _| acc + 0.1);
+
+    assert_ne!(sum, 1.0);
+
+    let y = i!(LCRO[0.0, 1.0]);
+
+    assert_eq!(y.iter_step(0.25).collect::<Vec<_>>(), vec![0.0, 0.25, 0.5, 0.75]);
+    assert_eq!(i!(LORC[0.0, 1.0]).iter_step(0.25).collect::<Vec<_>>(), vec![0.25, 0.5, 0.75, 1.0]);
+    assert_eq!(i!(Open[0.0, 1.0]).iter_step(0.5).collect::<Vec<_>>(), vec![0.5]);
+    assert_eq!(i!(Degenerate[2.0]).iter_step(1.0).collect::<Vec<_>>(), vec![2.0]);
+    assert_eq!(i!(Closed[0.0, 1.0]).iter_step(2.0).collect::<Vec<_>>(), vec![0.0]);
+}
+
+#[test]
+fn iter_step_invalid() {
+    let x = i!(Closed[0.0, 1.0]);
+
+    assert_eq!(x.iter_step(0.0).next(), None);
+    assert_eq!(x.iter_step(-1.0).next(), None);
+    assert_eq!(x.iter_step(f64::NAN).next(), None);
+    assert_eq!(i!(Closed[0, 10]).iter_step(0).next(), None);
+    assert_eq!(i!(Open[0.0, 0.5]).iter_step(0.5).next(), None);
+}
+
+#[test]
+fn iter_step_integers() {
+    assert_eq!(i!(Closed[1, 10]).iter_step(3).collect::<Vec<_>>(), vec![1, 4, 7, 10]);
+    assert_eq!(i!(LCRO[1, 10]).iter_step(3).collect::<Vec<_>>(), vec![1, 4, 7]);
+    assert_eq!(i!(Closed[-10i8, 10]).iter_step(7).collect::<Vec<_>>(), vec![-10, -3, 4]);
+
+    // Stops before overflowing the value type.
+    assert_eq!(i!(Closed[0u8, 250]).iter_step(100).collect::<Vec<_>>(), vec![0, 100, 200]);
+    assert_eq!(i!(Closed[-100i8, 100]).iter_step(50).collect::<Vec<_>>(), vec![-100, -50, 0, 50, 100]);
+    assert_eq!(i!(LC[250u8]).iter_step(3).collect::<Vec<_>>(), vec![250, 253]);
+    assert_eq!(i!(LO[i64::MAX - 2]).iter_step(1).collect::<Vec<_>>(), vec![i64::MAX - 1, i64::MAX]);
+}
+
+#[test]
+fn iter_step_mixed() {
+    let x = Interval::new_unchecked(OpenOrClosed::Open(0.0), OpenOrClosed::Closed(1.0));
+
+    assert_eq!(x.iter_step(0.5).collect::<Vec<_>>(), vec![0.5, 1.0]);
+
+    let y = Interval::new_unchecked(OpenOrClosed::Closed(0.0), bounds::NoBound::new());
+
+    assert_eq!(y.iter_step(1.5).take(3).collect::<Vec<_>>(), vec![0.0, 1.5, 3.0]);
+
+    let mut it = i!(Closed[0, 1]).iter_step(1);
+
+    assert_eq!((it.next(), it.next(), it.next(), it.next()), (Some(0), Some(1), None, None));
+}
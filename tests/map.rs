@@ -0,0 +1,115 @@
+extern crate intervals;
+
+#[macro_use]
+mod common;
+
+use intervals::{Interval, bounds};
+
+#[test]
+fn map_one_side() {
+    assert_eq!(i!(Closed[0.0, 1.0]).map_left(|x| x - 1.0), i!(Closed[-1.0, 1.0]));
+    assert_eq!(i!(Closed[0.0, 1.0]).map_right(|x| x + 1.0), i!(Closed[0.0, 2.0]));
+
+    assert_eq!(i!(LORC[0.0, 1.0]).map_left(|x| x - 1.0), i!(LORC[-1.0, 1.0]));
+    assert_eq!(i!(LORC[0.0, 1.0]).map_right(|x| x + 1.0), i!(LORC[0.0, 2.0]));
+}
+
+#[test]
+fn map_across_other_side() {
+    assert_eq!(i!(Closed[0.0, 1.0]).map_left(|x| x + 2.0), i!(Closed[2.0, 1.0]));
+
+    assert!(i!(Closed[0.0, 1.0]).try_map_left(|x| x + 1.0).is_ok());
+    assert!(i!(Closed[0.0, 1.0]).try_map_left(|x| x + 2.0).is_err());
+    assert!(i!(Open[0.0, 1.0]).try_map_left(|x| x + 1.0).is_err());
+    assert!(i!(LCRO[0.0, 1.0]).try_map_right(|x| x - 1.0).is_err());
+    assert!(i!(Degenerate[1.0]).try_map_right(|x| x - 1.0).is_err());
+}
+
+#[test]
+fn map_open_or_closed() {
+    let x = i!(Closed[0.0, 2.0]).intersect(i!(Open[1.0, 3.0])).unwrap();
+
+    let y = x.map_left(|x| x - 1.0);
+
+    assert_eq!(y.left, bounds::Open(0.0));
+    assert_eq!(y.right, bounds::Closed(2.0));
+
+    let y = x.try_map_right(|x| x + 1.0).unwrap();
+
+    assert_eq!(y.left, bounds::Open(1.0));
+    assert_eq!(y.right, bounds::Closed(3.0));
+
+    assert!(x.try_map_right(|x| x - 1.0).is_err());
+}
+
+#[test]
+fn map_unbounded_side() {
+    assert_eq!(i!(LO[0.0]).map_right(|x| x + 1.0), i!(LO[0.0]));
+    assert_eq!(i!(LC[0.0]).map_left(|x| x + 1.0), i!(LC[1.0]));
+    assert_eq!(i!(RO[0.0]).map_left(|x| x + 1.0), i!(RO[0.0]));
+    assert_eq!(i!(RC[0.0]).map_right(|x| x + 1.0), i!(RC[1.0]));
+
+    assert!(i!(LC[0.0]).try_map_right(|_| f64::NEG_INFINITY).is_ok());
+    assert!(i!(RC[0.0]).try_map_left(|_| f64::INFINITY).is_ok());
+
+    let x: intervals::Unbounded<f64> = Interval::unbounded();
+
+    assert_eq!(x.map_left(|x| x + 1.0).map_right(|x| x - 1.0), x);
+}
+
+#[test]
+fn map_value_type() {
+    assert_eq!(i!(Closed[1, 2]).map(f64::from), i!(Closed[1.0, 2.0]));
+    assert_eq!(i!(LORC[1.5, 2.0]).map(|x| (x * 1000.0) as u64), i!(LORC[1500, 2000]));
+    assert_eq!(i!(RO[3u8]).map(i32::from), i!(RO[3]));
+    assert_eq!(i!(Degenerate["a"]).map(String::from), i!(Degenerate[String::from("a")]));
+
+    let x = i!(Closed[0.0, 2.0]).intersect(i!(Open[1.0, 3.0])).unwrap().map(|x| x as i32);
+
+    assert_eq!(x.left, bounds::OpenOrClosed::Open(1));
+    assert_eq!(x.right, bounds::OpenOrClosed::Closed(2));
+}
+
+#[test]
+fn map_monotone() {
+    assert_eq!(i!(LCRO[1, 4]).try_map_monotone(|x| x * x).unwrap(), i!(LCRO[1, 16]));
+    assert_eq!(i!(LC[-2]).try_map_monotone(f64::from).unwrap(), i!(LC[-2.0]));
+
+    assert!(i!(LCRO[-4, 1]).try_map_monotone(|x| x * x).is_err());
+    assert!(i!(Closed[0.0, 1.0]).try_map_monotone(|x| -x).is_err());
+    assert!(i!(Open[1.0f64, 1.5]).try_map_monotone(|x| x.floor()).is_err());
+}
+
+#[test]
+fn cast_ints() {
+    assert_eq!(i!(Closed[1i32, 5]).cast::<u8>(), Some(i!(Closed[1, 5])));
+    assert_eq!(i!(LCRO[1u64, 5]).cast::<f32>(), Some(i!(LCRO[1.0, 5.0])));
+
+    assert_eq!(i!(Closed[-1i32, 5]).cast::<u32>(), None);
+    assert_eq!(i!(Open[0i32, 256]).cast::<u8>(), None);
+    assert_eq!(i!(LC[i64::MIN]).cast::<i32>(), None);
+}
+
+#[test]
+fn cast_floats() {
+    assert_eq!(i!(Closed[-0.5, 2.7]).cast::<i32>(), Some(i!(Closed[0, 2])));
+    assert_eq!(i!(LORC[-1.9, 255.9]).cast::<u8>(), None);
+    assert_eq!(i!(LORC[-0.9, 255.9]).cast::<u8>(), Some(i!(LORC[0, 255])));
+
+    assert_eq!(i!(Closed[0.0, 1e10]).cast::<i32>(), None);
+    assert_eq!(i!(Closed[f64::NAN, 0.0]).cast::<i32>(), None);
+    assert_eq!(i!(RO[f64::INFINITY]).cast::<i64>(), None);
+
+    assert_eq!(i!(Open[0.25, 0.75]).cast::<i32>(), Some(i!(Open[0, 0])));
+}
+
+#[test]
+fn cast_unbounded_side() {
+    assert_eq!(i!(LO[1u16]).cast::<i8>(), Some(i!(LO[1])));
+    assert_eq!(i!(RC[-3.5f64]).cast::<i16>(), Some(i!(RC[-3])));
+    assert_eq!(i!(RC[-3.5f64]).cast::<u16>(), None);
+
+    let x: intervals::Unbounded<f64> = Interval::unbounded();
+
+    assert_eq!(x.cast::<u8>(), Some(Interval::unbounded()));
+}
@@ -0,0 +1,215 @@
+extern crate intervals;
+
+use intervals::{
+    Interval,
+    bounds::OpenOrClosed,
+    ops::{find_containing, find_overlapping, is_sorted_disjoint, narrowest, widest},
+};
+
+// Minimal linear congruential generator, so the property tests are
+// deterministic and dependency-free.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        self.0 >> 33
+    }
+
+    fn interval(&mut self) -> intervals::Closed<i64> {
+        let a = (self.next() % 50) as i64;
+        let b = a + (self.next() % 20) as i64;
+
+        Interval::closed_unchecked(a, b)
+    }
+
+    fn bound(&mut self, x: i64) -> OpenOrClosed<i64> {
+        if self.next().is_multiple_of(2) { OpenOrClosed::Open(x) } else { OpenOrClosed::Closed(x) }
+    }
+
+    // Sorted, disjoint intervals with random openness, possibly touching.
+    fn sorted(&mut self) -> Vec<Interval<OpenOrClosed<i64>, OpenOrClosed<i64>>> {
+        let mut out: Vec<Interval<OpenOrClosed<i64>, OpenOrClosed<i64>>> = Vec::new();
+        let mut x = (self.next() % 5) as i64;
+
+        while x < 60 {
+            let width = 1 + (self.next() % 6) as i64;
+            let mut left = self.bound(x);
+
+            // Touching a closed right bound requires an open left bound.
+            if let Some(prev) = out.last() {
+                if prev.right == OpenOrClosed::Closed(x) { left = OpenOrClosed::Open(x); }
+            }
+
+            out.push(Interval::new_unchecked(left, self.bound(x + width)));
+            x += width + (self.next() % 3) as i64;
+        }
+
+        out
+    }
+}
+
+fn oracle(xs: &[intervals::Closed<i64>]) -> (Option<usize>, Option<usize>) {
+    // Stable sorts keep ties in order of appearance.
+    let mut indices: Vec<usize> = (0..xs.len()).collect();
+
+    indices.sort_by_key(|&i| xs[i].right.0 - xs[i].left.0);
+    let narrowest = indices.first().cloned();
+
+    indices.sort_by_key(|&i| std::cmp::Reverse(xs[i].right.0 - xs[i].left.0));
+    let widest = indices.first().cloned();
+
+    (narrowest, widest)
+}
+
+#[test]
+fn test_against_oracle() {
+    let mut rng = Lcg(42);
+
+    for n in 0..200 {
+        let xs: Vec<_> = (0..(n % 25)).map(|_| rng.interval()).collect();
+        let (n_idx, w_idx) = oracle(&xs);
+
+        // Compare both the intervals and their positions, to check tie-breaking.
+        let nth = |k: Option<usize>| k.map(|k| xs[k]);
+        let find = |x: Option<intervals::Closed<i64>>| x.map(|x| xs.iter().position(|y| *y == x).unwrap());
+
+        assert_eq!(narrowest(xs.clone()), nth(n_idx));
+        assert_eq!(widest(xs.clone()), nth(w_idx));
+        assert_eq!(find(narrowest(xs.clone())), n_idx);
+        assert_eq!(find(widest(xs.clone())), w_idx);
+    }
+}
+
+#[test]
+fn test_ties_keep_first() {
+    let xs = vec![
+        Interval::lcro_unchecked(0.0, 1.0),
+        Interval::lcro_unchecked(5.0, 6.0),
+        Interval::lcro_unchecked(2.0, 2.5),
+        Interval::lcro_unchecked(3.0, 3.5),
+    ];
+
+    assert_eq!(narrowest(xs.clone()), Some(Interval::lcro_unchecked(2.0, 2.5)));
+    assert_eq!(widest(xs), Some(Interval::lcro_unchecked(0.0, 1.0)));
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!(narrowest(Vec::<intervals::Closed<f64>>::new()), None);
+    assert_eq!(widest(Vec::<intervals::Closed<f64>>::new()), None);
+}
+
+#[test]
+fn test_unbounded() {
+    let xs = vec![Interval::left_closed(0.0), Interval::left_closed(1.0)];
+
+    assert_eq!(narrowest(xs.clone()), None);
+    assert_eq!(widest(xs), Some(Interval::left_closed(0.0)));
+
+    let xs = vec![
+        Interval::closed_unchecked(0.0, 1.0),
+        Interval::closed_unchecked(0.0, f64::INFINITY),
+        Interval::closed_unchecked(-f64::INFINITY, 0.0),
+    ];
+
+    assert_eq!(narrowest(xs.clone()), Some(xs[0]));
+    assert_eq!(widest(xs.clone()), Some(xs[1]));
+}
+
+#[test]
+fn test_overflowing_width() {
+    let xs = vec![
+        Interval::closed_unchecked(0i8, 10),
+        Interval::closed_unchecked(i8::MIN, i8::MAX),
+        Interval::closed_unchecked(-100, 100),
+        Interval::closed_unchecked(2, 3),
+    ];
+
+    assert_eq!(narrowest(xs.clone()), Some(xs[3]));
+    assert_eq!(widest(xs.clone()), Some(xs[1]));
+
+    let xs = vec![Interval::closed_unchecked(0u64, 1), Interval::closed_unchecked(0, u64::MAX)];
+
+    assert_eq!(widest(xs), Some(Interval::closed_unchecked(0, u64::MAX)));
+}
+
+#[test]
+fn test_nan_skipped() {
+    let xs = vec![
+        Interval::closed_unchecked(f64::NAN, 1.0),
+        Interval::closed_unchecked(0.0, 2.0),
+        Interval::closed_unchecked(0.0, 3.0),
+    ];
+
+    assert_eq!(narrowest(xs.clone()), Some(xs[1]));
+    assert_eq!(widest(xs.clone()), Some(xs[2]));
+}
+
+#[test]
+fn test_find_containing_against_brute_force() {
+    let mut rng = Lcg(7);
+
+    for _ in 0..100 {
+        let sorted = rng.sorted();
+
+        assert!(is_sorted_disjoint(&sorted));
+
+        for x in -2..70 {
+            let expected = sorted.iter().position(|i| i.contains(x));
+
+            assert_eq!(find_containing(&sorted, &x), expected, "{} in {:?}", x, sorted);
+        }
+    }
+}
+
+#[test]
+fn test_find_overlapping_against_brute_force() {
+    let mut rng = Lcg(11);
+
+    for _ in 0..100 {
+        let sorted = rng.sorted();
+
+        for _ in 0..50 {
+            let a = (rng.next() % 70) as i64 - 2;
+            let b = a + (rng.next() % 10) as i64;
+            let query = Interval::new_unchecked(rng.bound(a), rng.bound(b));
+
+            if Interval::new(query.left, query.right).is_err() { continue; }
+
+            let expected: Vec<usize> = (0..sorted.len())
+                .filter(|&k| sorted[k].intersect(query).is_some())
+                .collect();
+            let found = find_overlapping(&sorted, &query);
+
+            assert_eq!(found.clone().collect::<Vec<_>>(), expected, "{:?} in {:?}", query, sorted);
+        }
+    }
+}
+
+#[test]
+fn test_find_unbounded_query() {
+    let sorted = [Interval::lcro_unchecked(0, 10), Interval::lcro_unchecked(10, 20)];
+
+    assert_eq!(find_overlapping(&sorted, &Interval::left_open(10)), 1..2);
+    assert_eq!(find_overlapping(&sorted, &Interval::right_open(10)), 0..1);
+    assert_eq!(find_overlapping(&sorted, &Interval::<_, _>::unbounded()), 0..2);
+    assert_eq!(find_overlapping(&sorted, &Interval::left_closed(20)), 2..2);
+    assert_eq!(find_containing::<_, _>(&[] as &[intervals::LCRO<i32>], &0), None);
+}
+
+#[test]
+fn test_find_nan() {
+    let sorted = [Interval::closed_unchecked(0.0, 1.0)];
+
+    assert_eq!(find_containing(&sorted, &f64::NAN), None);
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn test_find_unsorted() {
+    let unsorted = [Interval::closed_unchecked(5, 6), Interval::closed_unchecked(0, 1)];
+
+    find_containing(&unsorted, &0);
+}
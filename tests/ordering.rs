@@ -0,0 +1,103 @@
+extern crate intervals;
+
+use intervals::{Interval, bounds::{NoBound, OpenOrClosed}};
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
+// Deterministic Fisher-Yates shuffle.
+fn shuffled<T: Clone>(xs: &[T]) -> Vec<T> {
+    let mut out = xs.to_vec();
+    let mut state = 11u64;
+
+    for i in (1..out.len()).rev() {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        out.swap(i, (state >> 33) as usize % (i + 1));
+    }
+
+    out
+}
+
+#[test]
+fn sort_closed() {
+    let sorted: Vec<intervals::Closed<i64>> = (0..6)
+        .flat_map(|a| (a..6).map(move |b| Interval::closed_unchecked(a, b)))
+        .collect();
+
+    let mut xs = shuffled(&sorted);
+
+    assert_ne!(xs, sorted);
+
+    xs.sort();
+
+    assert_eq!(xs, sorted);
+}
+
+#[test]
+fn sort_floats() {
+    let sorted = vec![
+        Interval::closed_unchecked(-1.5, 0.0),
+        Interval::closed_unchecked(-1.5, 2.0),
+        Interval::closed_unchecked(0.0, 0.0),
+        Interval::closed_unchecked(0.0, 0.25),
+        Interval::closed_unchecked(0.5, 1.0),
+        Interval::closed_unchecked(3.0, 4.0),
+    ];
+
+    let mut xs = shuffled(&sorted);
+
+    xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    assert_eq!(xs, sorted);
+
+    let nan = Interval::closed_unchecked(f64::NAN, 1.0);
+
+    assert_eq!(nan.partial_cmp(&sorted[0]), None);
+    assert_eq!(sorted[0].partial_cmp(&nan), None);
+}
+
+#[test]
+fn openness() {
+    let sorted = vec![
+        Interval::new_unchecked(OpenOrClosed::Closed(0), OpenOrClosed::Open(1)),
+        Interval::new_unchecked(OpenOrClosed::Closed(0), OpenOrClosed::Closed(1)),
+        Interval::new_unchecked(OpenOrClosed::Open(0), OpenOrClosed::Open(1)),
+        Interval::new_unchecked(OpenOrClosed::Open(0), OpenOrClosed::Closed(1)),
+        Interval::new_unchecked(OpenOrClosed::Closed(1), OpenOrClosed::Closed(1)),
+    ];
+
+    let mut xs = shuffled(&sorted);
+
+    xs.sort();
+
+    assert_eq!(xs, sorted);
+
+    for (i, x) in sorted.iter().enumerate() {
+        for (j, y) in sorted.iter().enumerate() {
+            assert_eq!(x.cmp(y), i.cmp(&j), "{} vs {}", x, y);
+            assert_eq!(x.cmp(y) == Ordering::Equal, x == y);
+        }
+    }
+}
+
+#[test]
+fn unbounded() {
+    let a = Interval::new_unchecked(NoBound::new(), OpenOrClosed::Closed(0));
+    let b = Interval::new_unchecked(NoBound::new(), OpenOrClosed::Closed(5));
+
+    assert!(a < b);
+    assert!(Interval::left_closed(0) < Interval::left_closed(1));
+    assert!(Interval::right_open(0) < Interval::right_open(1));
+    assert_eq!(Interval::<NoBound<i32>, NoBound<i32>>::unbounded().cmp(&Interval::unbounded()), Ordering::Equal);
+}
+
+#[test]
+fn btree_set() {
+    let set: BTreeSet<intervals::Closed<u32>> = [(3, 4), (0, 9), (0, 2), (3, 4)]
+        .iter()
+        .map(|&(a, b)| Interval::closed_unchecked(a, b))
+        .collect();
+
+    assert_eq!(set.len(), 3);
+    assert_eq!(set.iter().next(), Some(&Interval::closed_unchecked(0, 2)));
+    assert_eq!(set.iter().last(), Some(&Interval::closed_unchecked(3, 4)));
+}
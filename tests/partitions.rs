@@ -0,0 +1,194 @@
+extern crate intervals;
+
+use intervals::{bounds::ProperBound, partitions::{Declarative, Edges, OutOfRange, Partition, SubInterval, Uniform}};
+use std::{
+    borrow::Cow,
+    rc::Rc,
+    sync::{Arc, atomic::{AtomicUsize, Ordering as AtomicOrdering}},
+};
+
+/// Partition relying on the provided methods of [Partition].
+struct Wrapped(Uniform<f64>);
+
+impl Partition for Wrapped {
+    type Value = f64;
+
+    fn len(&self) -> usize { self.0.len() }
+
+    fn index(&self, value: &f64) -> Option<usize> { self.0.index(value) }
+
+    fn subinterval(&self, k: usize) -> Option<SubInterval<f64>> { self.0.subinterval(k) }
+}
+
+#[test]
+fn default_try_index() {
+    let p = Wrapped(Uniform::new(4, 0.0, 2.0));
+
+    assert_eq!(p.try_index(&-0.1), Err(OutOfRange::Below));
+    assert_eq!(p.try_index(&0.0), Ok(0));
+    assert_eq!(p.try_index(&1.2), Ok(2));
+    assert_eq!(p.try_index(&2.0), Ok(3));
+    assert_eq!(p.try_index(&2.1), Err(OutOfRange::Above));
+    assert_eq!(p.try_index(&f64::NAN), Err(OutOfRange::Incomparable));
+}
+
+fn bin_all(p: impl Partition<Value = f64>, data: &[f64]) -> Vec<Option<usize>> {
+    data.iter().map(|x| p.index(x)).collect()
+}
+
+const DATA: [f64; 6] = [-1.0, 0.0, 0.3, 1.0, 1.9, 2.0];
+
+#[test]
+fn blanket_impls() {
+    let uniform = Uniform::new(4, 0.0, 2.0);
+    let expected = bin_all(uniform, &DATA);
+
+    assert_eq!(expected, vec![None, Some(0), Some(0), Some(2), Some(3), Some(3)]);
+
+    let (by_ref, by_mut): (&Uniform<f64>, &mut Uniform<f64>) = (&uniform, &mut uniform.clone());
+
+    assert_eq!(bin_all(by_ref, &DATA), expected);
+    assert_eq!(bin_all(by_mut, &DATA), expected);
+    assert_eq!(bin_all(Rc::new(uniform), &DATA), expected);
+
+    let declarative = Box::new(Declarative::new_unchecked([0.0, 0.5, 1.0, 1.5, 2.0]));
+
+    assert_eq!(bin_all(declarative, &DATA), expected);
+}
+
+#[test]
+fn blanket_impls_dyn() {
+    let partitions: Vec<Arc<dyn Partition<Value = f64>>> = vec![
+        Arc::new(Uniform::new(4, 0.0, 2.0)),
+        Arc::new(Declarative::new_unchecked([0.0, 0.5, 1.0, 1.5, 2.0])),
+        Arc::new(Wrapped(Uniform::new(4, 0.0, 2.0))),
+    ];
+
+    for p in partitions.iter() {
+        assert_eq!(p.len(), 4);
+        assert!(!p.is_empty());
+        assert_eq!(p.try_index(&2.1), Err(OutOfRange::Above));
+        assert_eq!(p.digitise(&1.2).unwrap().index, 2);
+        assert_eq!(bin_all(p.clone(), &DATA), bin_all(Uniform::new(4, 0.0, 2.0), &DATA));
+    }
+
+    let boxed: Box<dyn Partition<Value = f64>> = Box::new(Uniform::new(2, 0.0, 1.0));
+
+    assert_eq!(bin_all(&*boxed, &[0.7]), vec![Some(1)]);
+    assert_eq!(bin_all(boxed, &[0.2]), vec![Some(0)]);
+}
+
+#[test]
+fn digitise_ref_matches_digitise() {
+    let edges = Edges::new(vec![0.0, 0.5, 2.0, 2.0, 3.0]).unwrap();
+    let declarative = Declarative::new_unchecked([0.0, 0.5, 2.0, 2.0, 3.0]);
+    let uniform = Uniform::new(4, 0.0, 3.0);
+
+    for k in 0..=30 {
+        let x = k as f64 / 10.0 - 0.1;
+
+        assert_eq!(edges.digitise_ref(&x).map(|s| s.into_owned()), edges.digitise(&x));
+        assert_eq!(declarative.digitise_ref(&x).map(|s| s.into_owned()), declarative.digitise(&x));
+        assert_eq!(uniform.digitise_ref(&x).map(|s| s.into_owned()), uniform.digitise(&x));
+        assert_eq!(Box::new(&edges).digitise_ref(&x), edges.digitise_ref(&x));
+    }
+}
+
+static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, PartialEq, PartialOrd)]
+struct Counted(i64);
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        CLONES.fetch_add(1, AtomicOrdering::SeqCst);
+
+        Counted(self.0)
+    }
+}
+
+#[test]
+fn digitise_ref_borrows() {
+    let edges = Edges::new((0..10).map(Counted).collect()).unwrap();
+    let declarative = Declarative::new_unchecked([Counted(0), Counted(5), Counted(10)]);
+
+    let before = CLONES.load(AtomicOrdering::SeqCst);
+
+    let sub = edges.digitise_ref(&Counted(3)).unwrap();
+    assert_eq!(sub.index, 3);
+    assert!(matches!(sub.interval.left.proper_value(), Cow::Borrowed(_)));
+
+    let sub = declarative.digitise_ref(&Counted(7)).unwrap();
+    assert_eq!(sub.index, 1);
+
+    assert_eq!(CLONES.load(AtomicOrdering::SeqCst), before);
+
+    // The owned form clones both bounds.
+    edges.digitise(&Counted(3)).unwrap();
+
+    assert_eq!(CLONES.load(AtomicOrdering::SeqCst), before + 2);
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    extern crate serde_test;
+
+    use self::serde_test::{Token, assert_de_tokens, assert_de_tokens_error};
+    use intervals::partitions::{Edges, Histogram};
+
+    fn tokens(edges: &[i32]) -> Vec<Token> {
+        let mut tokens = vec![
+            Token::Struct { name: "Edges", len: 1 },
+            Token::Str("edges"),
+            Token::Seq { len: Some(edges.len()) },
+        ];
+
+        tokens.extend(edges.iter().map(|&e| Token::I32(e)));
+        tokens.extend_from_slice(&[Token::SeqEnd, Token::StructEnd]);
+
+        tokens
+    }
+
+    #[test]
+    fn edges_valid() {
+        assert_de_tokens(&Edges::new(vec![0, 5, 10]).unwrap(), &tokens(&[0, 5, 10]));
+    }
+
+    #[test]
+    fn edges_rejected() {
+        assert_de_tokens_error::<Edges<i32>>(&tokens(&[]), "fewer than two edges");
+        assert_de_tokens_error::<Edges<i32>>(&tokens(&[1]), "fewer than two edges");
+        assert_de_tokens_error::<Edges<i32>>(&tokens(&[0, 5, 3]), "the edges are not non-decreasing");
+    }
+
+    fn histogram_tokens(edges: &[i32], counts: &[u64]) -> Vec<Token> {
+        let mut tokens = vec![
+            Token::Struct { name: "Histogram", len: 2 },
+            Token::Str("partition"),
+        ];
+
+        tokens.extend(self::tokens(edges));
+        tokens.extend_from_slice(&[Token::Str("counts"), Token::Seq { len: Some(counts.len()) }]);
+        tokens.extend(counts.iter().map(|&n| Token::U64(n)));
+        tokens.extend_from_slice(&[Token::SeqEnd, Token::StructEnd]);
+
+        tokens
+    }
+
+    #[test]
+    fn histogram_valid() {
+        let mut histogram = Histogram::new(Edges::new(vec![0, 5, 10]).unwrap());
+
+        histogram.extend(vec![1, 6, 7]);
+
+        assert_de_tokens(&histogram, &histogram_tokens(&[0, 5, 10], &[1, 2]));
+    }
+
+    #[test]
+    fn histogram_rejected() {
+        let message = "the number of counts differs from the number of bins";
+
+        assert_de_tokens_error::<Histogram<Edges<i32>>>(&histogram_tokens(&[0, 5, 10], &[1]), message);
+        assert_de_tokens_error::<Histogram<Edges<i32>>>(&histogram_tokens(&[0, 5, 10], &[1, 2, 3]), message);
+    }
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "rand")]
+extern crate intervals;
+extern crate rand;
+
+use intervals::{Interval, bounds::OpenOrClosed};
+use rand::{SeedableRng, rngs::SmallRng};
+
+const N: usize = 20_000;
+
+fn rng() -> SmallRng { SmallRng::seed_from_u64(2024) }
+
+#[test]
+fn floats_inside() {
+    let mut rng = rng();
+
+    for x in [
+        Interval::new_unchecked(OpenOrClosed::Closed(-1.0), OpenOrClosed::Closed(3.0)),
+        Interval::new_unchecked(OpenOrClosed::Closed(-1.0), OpenOrClosed::Open(3.0)),
+        Interval::new_unchecked(OpenOrClosed::Open(-1.0), OpenOrClosed::Closed(3.0)),
+        Interval::new_unchecked(OpenOrClosed::Open(-1.0), OpenOrClosed::Open(3.0)),
+    ].iter() {
+        let samples: Vec<f64> = (0..N).map(|_| x.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / N as f64;
+
+        assert!(samples.iter().all(|&v| x.contains(v)), "{}", x);
+        assert!((mean - 1.0).abs() < 0.05, "{} has mean {}", x, mean);
+    }
+}
+
+#[test]
+fn integers_inside() {
+    let mut rng = rng();
+
+    let x = Interval::closed_unchecked(1u8, 6);
+    let mut counts = [0usize; 7];
+
+    for _ in 0..N {
+        counts[x.sample(&mut rng) as usize] += 1;
+    }
+
+    assert_eq!(counts[0], 0);
+    assert!(counts[1..].iter().all(|&c| (c as f64 - N as f64 / 6.0).abs() < N as f64 / 60.0), "{:?}", counts);
+
+    // Open endpoints are never drawn.
+    let y = Interval::open_unchecked(-3i64, 3);
+    let samples: Vec<i64> = (0..N).map(|_| y.sample(&mut rng)).collect();
+
+    assert!(samples.iter().all(|v| (-2..=2).contains(v)));
+    assert!(samples.contains(&-2) && samples.contains(&2));
+    assert!((samples.iter().sum::<i64>() as f64 / N as f64).abs() < 0.1);
+
+    assert!((0..100).all(|_| Interval::lorc_unchecked(i32::MAX - 1, i32::MAX).sample(&mut rng) == i32::MAX));
+    assert!((0..100).all(|_| Interval::lcro_unchecked(u64::MIN, 1).sample(&mut rng) == 0));
+}
+
+#[test]
+fn narrow_floats() {
+    let mut rng = rng();
+    let (a, b) = (1.0f64, 1.0 + 2.0 * f64::EPSILON);
+
+    assert!((0..100).all(|_| Interval::open_unchecked(a, b).sample(&mut rng) == 1.0 + f64::EPSILON));
+    assert!((0..100).all(|_| Interval::degenerate(0.5f32).sample(&mut rng) == 0.5));
+}
+
+#[test]
+#[should_panic]
+fn empty_integers() { Interval::open_unchecked(0, 1).sample(&mut rng()); }
+
+#[test]
+#[should_panic]
+fn empty_floats() { Interval::open_unchecked(1.0, 1.0 + f64::EPSILON).sample(&mut rng()); }
+
+#[test]
+#[should_panic]
+fn infinite_floats() { Interval::closed_unchecked(0.0, f64::INFINITY).sample(&mut rng()); }
+
+#[test]
+fn gen_range_endpoints() {
+    use rand::Rng;
+
+    let mut rng = rng();
+
+    macro_rules! draws {
+        ($x:expr) => {{ let x = $x; (0..N).map(|_| rng.gen_range(x)).collect::<Vec<i32>>() }};
+    }
+
+    let closed = draws!(Interval::closed_unchecked(0, 3));
+    let open = draws!(Interval::open_unchecked(0, 3));
+    let lcro = draws!(Interval::lcro_unchecked(0, 3));
+    let lorc = draws!(Interval::lorc_unchecked(0, 3));
+
+    assert!(closed.contains(&0) && closed.contains(&3));
+    assert!(!open.contains(&0) && !open.contains(&3) && open.contains(&1) && open.contains(&2));
+    assert!(lcro.contains(&0) && !lcro.contains(&3));
+    assert!(!lorc.contains(&0) && lorc.contains(&3));
+
+    // Floats drawn from a coarse grid of representable values.
+    let (a, b) = (1.0f64, 1.0 + 3.0 * f64::EPSILON);
+    let closed: Vec<f64> = (0..N).map(|_| rng.gen_range(Interval::closed_unchecked(a, b))).collect();
+    let open: Vec<f64> = (0..N).map(|_| rng.gen_range(Interval::open_unchecked(a, b))).collect();
+
+    assert!(closed.contains(&a) && closed.contains(&b));
+    assert!(!open.contains(&a) && !open.contains(&b));
+    assert!(open.iter().all(|v| a < *v && *v < b));
+
+    let mixed = Interval::new_unchecked(OpenOrClosed::Open(-1.0), OpenOrClosed::Closed(1.0));
+
+    assert!((0..N).all(|_| mixed.contains(rng.gen_range(mixed))));
+}
+
+#[test]
+fn sample_range_is_empty() {
+    use rand::distributions::uniform::SampleRange;
+
+    assert!(!Interval::closed_unchecked(0, 0).is_empty());
+    assert!(!Interval::open_unchecked(0, 2).is_empty());
+    assert!(Interval::open_unchecked(0, 1).is_empty());
+    assert!(Interval::lcro_unchecked(0u8, 0).is_empty());
+    assert!(!Interval::open_unchecked(i64::MIN, i64::MAX).is_empty());
+    assert!(Interval::open_unchecked(1.0, 1.0 + f64::EPSILON).is_empty());
+    assert!(!Interval::open_unchecked(1.0, 1.0 + 2.0 * f64::EPSILON).is_empty());
+    assert!(Interval::closed_unchecked(f64::NAN, 1.0).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn gen_range_empty() {
+    use rand::Rng;
+
+    rng().gen_range(Interval::open_unchecked(0u32, 1));
+}
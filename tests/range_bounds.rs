@@ -0,0 +1,198 @@
+extern crate intervals;
+
+use intervals::{Interval, bounds::{self, FromStdBoundsError, NoBound, OpenOrClosed, ValidationError}};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::ops::{Bound, RangeBounds};
+
+fn map() -> BTreeMap<usize, usize> { (0..10).map(|k| (k, k * k)).collect() }
+
+fn keys<X: RangeBounds<usize>>(x: X) -> Vec<usize> { map().range(x).map(|(k, _)| *k).collect() }
+
+fn drained<X: RangeBounds<usize>>(x: X) -> (Vec<usize>, Vec<usize>) {
+    let mut v: Vec<usize> = (0..10).collect();
+    let drained = v.drain(x).collect();
+
+    (drained, v)
+}
+
+#[test]
+fn std_bounds() {
+    let x = Interval::lcro_unchecked(1, 2);
+
+    assert_eq!(x.start_bound(), Bound::Included(&1));
+    assert_eq!(x.end_bound(), Bound::Excluded(&2));
+
+    let y = Interval::left_open(1.5);
+
+    assert_eq!(y.start_bound(), Bound::Excluded(&1.5));
+    assert_eq!(y.end_bound(), Bound::Unbounded);
+
+    let z = Interval::new_unchecked(OpenOrClosed::Open(0), OpenOrClosed::Closed(3));
+
+    assert_eq!(z.start_bound(), Bound::Excluded(&0));
+    assert_eq!(z.end_bound(), Bound::Included(&3));
+    assert!(RangeBounds::contains(&z, &3) && !RangeBounds::contains(&z, &0));
+}
+
+#[test]
+fn btree_map_range() {
+    assert_eq!(keys(Interval::open_unchecked(2, 5)), vec![3, 4]);
+    assert_eq!(keys(Interval::closed_unchecked(2, 5)), vec![2, 3, 4, 5]);
+    assert_eq!(keys(Interval::lcro_unchecked(2, 5)), vec![2, 3, 4]);
+    assert_eq!(keys(Interval::lorc_unchecked(2, 5)), vec![3, 4, 5]);
+
+    assert_eq!(keys(Interval::left_open(7)), vec![8, 9]);
+    assert_eq!(keys(Interval::left_closed(7)), vec![7, 8, 9]);
+    assert_eq!(keys(Interval::right_open(2)), vec![0, 1]);
+    assert_eq!(keys(Interval::right_closed(2)), vec![0, 1, 2]);
+
+    assert_eq!(keys(Interval::degenerate(4)), vec![4]);
+    assert_eq!(keys(intervals::Unbounded::<usize>::unbounded()), (0..10).collect::<Vec<_>>());
+
+    let mixed = Interval::new_unchecked(OpenOrClosed::Closed(8), bounds::NoBound::new());
+
+    assert_eq!(keys(mixed), vec![8, 9]);
+    assert_eq!(map().range(Interval::closed_unchecked(2, 3)).map(|(_, v)| *v).sum::<usize>(), 13);
+}
+
+#[test]
+fn vec_drain() {
+    assert_eq!(drained(Interval::open_unchecked(2, 5)), (vec![3, 4], vec![0, 1, 2, 5, 6, 7, 8, 9]));
+    assert_eq!(drained(Interval::closed_unchecked(2, 5)), (vec![2, 3, 4, 5], vec![0, 1, 6, 7, 8, 9]));
+    assert_eq!(drained(Interval::lcro_unchecked(2, 5)), (vec![2, 3, 4], vec![0, 1, 5, 6, 7, 8, 9]));
+    assert_eq!(drained(Interval::lorc_unchecked(2, 5)), (vec![3, 4, 5], vec![0, 1, 2, 6, 7, 8, 9]));
+
+    assert_eq!(drained(Interval::left_open(7)), (vec![8, 9], (0..8).collect()));
+    assert_eq!(drained(Interval::left_closed(7)), (vec![7, 8, 9], (0..7).collect()));
+    assert_eq!(drained(Interval::right_open(2)), (vec![0, 1], (2..10).collect()));
+    assert_eq!(drained(Interval::right_closed(2)), (vec![0, 1, 2], (3..10).collect()));
+
+    assert_eq!(drained(intervals::Unbounded::<usize>::unbounded()), ((0..10).collect(), vec![]));
+}
+
+
+type Erased = Interval<OpenOrClosed<i32>, OpenOrClosed<i32>>;
+
+#[test]
+fn try_from_bounds_erased() {
+    let x = Erased::try_from((Bound::Excluded(1), Bound::Included(4))).unwrap();
+
+    assert_eq!(x, Interval::lorc_unchecked(1, 4));
+    assert_eq!(Erased::try_from((Bound::Included(2), Bound::Included(2))).unwrap(), Interval::degenerate(2));
+
+    match Erased::try_from((Bound::Included(5), Bound::Excluded(1))) {
+        Err(FromStdBoundsError::Invalid(ValidationError::DecreasingBounds(l, r))) => {
+            assert_eq!((l, r), (OpenOrClosed::Closed(5), OpenOrClosed::Open(1)));
+        },
+        x => panic!("unexpected {:?}", x),
+    }
+
+    match Erased::try_from((Bound::Excluded(2), Bound::Included(2))) {
+        Err(FromStdBoundsError::Invalid(ValidationError::DecreasingBounds(..))) => {},
+        x => panic!("unexpected {:?}", x),
+    }
+
+    match Erased::try_from((Bound::Unbounded, Bound::Excluded(5))) {
+        Err(FromStdBoundsError::Mismatch(Bound::Unbounded, Bound::Excluded(5))) => {},
+        x => panic!("unexpected {:?}", x),
+    }
+}
+
+#[test]
+fn try_from_bounds_aliases() {
+    type LeftErased = Interval<OpenOrClosed<i32>, NoBound<i32>>;
+    type RightErased = Interval<NoBound<i32>, OpenOrClosed<i32>>;
+
+    assert_eq!(intervals::Closed::try_from((Bound::Included(0), Bound::Included(3))).unwrap(), 0..=3);
+    assert_eq!(intervals::LCRO::try_from((Bound::Included(0), Bound::Excluded(3))).unwrap(), 0..3);
+    assert_eq!(intervals::LeftClosed::try_from((Bound::Included(0), Bound::Unbounded)).unwrap(), 0..);
+    assert_eq!(intervals::RightOpen::try_from((Bound::Unbounded, Bound::Excluded(3))).unwrap(), ..3);
+    assert_eq!(intervals::Unbounded::<i32>::try_from((Bound::Unbounded, Bound::Unbounded)).unwrap(), ..);
+
+    assert_eq!(LeftErased::try_from((Bound::Excluded(1), Bound::Unbounded)).unwrap(), Interval::left_open(1));
+    assert_eq!(RightErased::try_from((Bound::Unbounded, Bound::Included(1))).unwrap(), Interval::right_closed(1));
+
+    match intervals::Closed::try_from((Bound::Included(0), Bound::Excluded(3))) {
+        Err(FromStdBoundsError::Mismatch(Bound::Included(0), Bound::Excluded(3))) => {},
+        x => panic!("unexpected {:?}", x),
+    }
+
+    match intervals::Unbounded::<i32>::try_from((Bound::Unbounded, Bound::Included(3))) {
+        Err(FromStdBoundsError::Mismatch(Bound::Unbounded, Bound::Included(3))) => {},
+        x => panic!("unexpected {:?}", x),
+    }
+
+    assert!(LeftErased::try_from((Bound::Unbounded, Bound::Unbounded)).is_err());
+    assert!(RightErased::try_from((Bound::Included(0), Bound::Included(1))).is_err());
+}
+
+#[test]
+fn try_from_bounds_round_trip() {
+    let pairs = [
+        (Bound::Included(2), Bound::Included(5)),
+        (Bound::Included(2), Bound::Excluded(5)),
+        (Bound::Excluded(2), Bound::Included(5)),
+        (Bound::Excluded(2), Bound::Excluded(5)),
+    ];
+
+    for &(start, end) in pairs.iter() {
+        let x = Erased::try_from((start, end)).unwrap();
+
+        assert_eq!((x.start_bound().cloned(), x.end_bound().cloned()), (start, end));
+        assert_eq!(keys_i32(x), (0..10).filter(|k| (start, end).contains(k)).collect::<Vec<_>>());
+    }
+}
+
+fn keys_i32<X: RangeBounds<i32>>(x: X) -> Vec<i32> { (0..10).filter(|k| x.contains(k)).collect() }
+
+#[test]
+fn to_std_bounds() {
+    let x = Interval::new_unchecked(OpenOrClosed::Closed(String::from("a")), OpenOrClosed::Open(String::from("c")));
+
+    assert_eq!(x.to_std_bounds(), (Bound::Included(&String::from("a")), Bound::Excluded(&String::from("c"))));
+    assert_eq!(x.to_std_bounds(), (x.start_bound(), x.end_bound()));
+    assert_eq!(x.into_std_bounds(), (Bound::Included(String::from("a")), Bound::Excluded(String::from("c"))));
+
+    assert_eq!(Interval::right_open(2).into_std_bounds(), (Bound::Unbounded, Bound::Excluded(2)));
+    assert_eq!(intervals::Unbounded::<i32>::unbounded().into_std_bounds(), (Bound::Unbounded, Bound::Unbounded));
+}
+
+fn round_trip<L, R>(x: Interval<L, R>)
+where
+    L: bounds::FromStdBound<Value = f64> + Clone + PartialEq + std::fmt::Debug,
+    R: bounds::FromStdBound<Value = f64> + Clone + PartialEq + std::fmt::Debug,
+    bounds::Validator: bounds::ValidateBounds<L, R>,
+    Interval<L, R>: intervals::Contains<L, R> + std::fmt::Display,
+{
+    let y = Interval::<L, R>::try_from(x.clone().into_std_bounds()).unwrap();
+
+    assert_eq!(x, y);
+
+    for v in (-16..=16).map(|k| k as f64 / 8.0) {
+        assert_eq!(x.contains(v), y.contains(v), "{} at {}", x, v);
+    }
+}
+
+#[test]
+fn std_bounds_round_trip() {
+    let grid: Vec<f64> = (-16..=16).map(|k| k as f64 / 8.0).collect();
+
+    for &a in grid.iter().step_by(3) {
+        for &b in grid.iter().filter(|&&b| b > a) {
+            round_trip(Interval::open_unchecked(a, b));
+            round_trip(Interval::closed_unchecked(a, b));
+            round_trip(Interval::lcro_unchecked(a, b));
+            round_trip(Interval::lorc_unchecked(a, b));
+            round_trip(Interval::new_unchecked(OpenOrClosed::Open(a), OpenOrClosed::Closed(b)));
+        }
+
+        round_trip(Interval::left_open(a));
+        round_trip(Interval::left_closed(a));
+        round_trip(Interval::right_open(a));
+        round_trip(Interval::right_closed(a));
+        round_trip(Interval::degenerate(a));
+    }
+
+    round_trip(intervals::Unbounded::<f64>::unbounded());
+}
@@ -0,0 +1,155 @@
+extern crate intervals;
+
+use intervals::{Interval, bounds::ValidationError};
+use std::convert::TryFrom;
+
+#[test]
+fn range_eq() {
+    assert_eq!(Interval::lcro_unchecked(0, 10), 0..10);
+    assert_eq!(Interval::closed_unchecked(0, 10), 0..=10);
+    assert_eq!(Interval::left_closed(0), 0..);
+    assert_eq!(Interval::right_open(10), ..10);
+    assert_eq!(Interval::right_closed(10), ..=10);
+    assert_eq!(intervals::Unbounded::<f64>::unbounded(), ..);
+
+    assert_eq!(0..10, Interval::lcro_unchecked(0, 10));
+    assert_eq!(0..=10, Interval::closed_unchecked(0, 10));
+    assert_eq!(.., intervals::Unbounded::<i32>::unbounded());
+}
+
+#[test]
+fn range_ne() {
+    assert_ne!(Interval::lcro_unchecked(0, 10), 0..9);
+    assert_ne!(Interval::lcro_unchecked(0, 10), 1..10);
+    assert_ne!(Interval::closed_unchecked(0, 10), 0..=9);
+    assert_ne!(Interval::left_closed(0), 1..);
+    assert_ne!(Interval::right_closed(10), ..=9);
+
+    assert_ne!(Interval::lcro_unchecked(0.0, 1.0), 0.0..f64::NAN);
+}
+
+#[test]
+fn try_from_range() {
+    let x = intervals::LCRO::<i32>::try_from(0..10).unwrap();
+
+    assert_eq!(x, Interval::lcro_unchecked(0, 10));
+    assert_eq!(Interval::try_from(-0.5..0.5).unwrap(), Interval::lcro_unchecked(-0.5, 0.5));
+
+    let indices = Interval::try_from(2..5usize).unwrap();
+    let slice = ['a', 'b', 'c', 'd', 'e', 'f'];
+
+    assert_eq!((0..slice.len()).filter(|&k| indices.contains(k)).map(|k| slice[k]).collect::<String>(), "cde");
+}
+
+#[test]
+fn try_from_range_empty() {
+    let (a, b) = (5, 3);
+
+    match Interval::try_from(a..b) {
+        Err(ValidationError::DecreasingBounds(l, r)) => assert_eq!((l.0, r.0), (5, 3)),
+        r => panic!("{:?}", r),
+    }
+
+    match Interval::try_from(5..5) {
+        Err(ValidationError::DecreasingBounds(..)) => {},
+        r => panic!("{:?}", r),
+    }
+
+    match Interval::try_from(f64::NAN..0.0) {
+        Err(ValidationError::IncomparableBounds(..)) => {},
+        r => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn try_from_range_contains_parity() {
+    for &(a, b) in [(0, 10), (3, 4), (-2, 2)].iter() {
+        let x = Interval::try_from(a..b).unwrap();
+
+        for v in -5..15 {
+            assert_eq!(x.contains(v), (a..b).contains(&v), "{:?} vs {}", x, v);
+        }
+    }
+}
+
+#[test]
+fn try_from_range_inclusive() {
+    assert_eq!(Interval::try_from(0..=10).unwrap(), Interval::closed_unchecked(0, 10));
+    assert_eq!(Interval::try_from(3..=3).unwrap(), Interval::degenerate(3));
+    assert_eq!(Interval::try_from(-0.5..=0.5).unwrap(), Interval::closed_unchecked(-0.5, 0.5));
+
+    let (a, b) = (10, 0);
+
+    match Interval::try_from(a..=b) {
+        Err(ValidationError::DecreasingBounds(l, r)) => assert_eq!((l.0, r.0), (10, 0)),
+        r => panic!("{:?}", r),
+    }
+
+    match Interval::try_from(0.0..=f64::NAN) {
+        Err(ValidationError::IncomparableBounds(..)) => {},
+        r => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn try_from_range_inclusive_exhausted() {
+    let mut range = 2..=4;
+
+    assert_eq!(range.by_ref().count(), 3);
+    assert!(range.is_empty());
+
+    match Interval::try_from(range) {
+        Err(ValidationError::DecreasingBounds(..)) => {},
+        r => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn try_from_range_inclusive_contains_parity() {
+    for &(a, b) in [(0, 10), (3, 4), (5, 5), (-2, 2)].iter() {
+        let x = Interval::try_from(a..=b).unwrap();
+
+        for v in -5..15 {
+            assert_eq!(x.contains(v), (a..=b).contains(&v), "{:?} vs {}", x, v);
+        }
+    }
+
+    for &(a, b) in [(0.0, 1.0), (-0.25, 0.25), (1.5, 1.5)].iter() {
+        let x = Interval::try_from(a..=b).unwrap();
+
+        for v in (-8..=16).map(|k| k as f64 / 8.0) {
+            assert_eq!(x.contains(v), (a..=b).contains(&v), "{:?} vs {}", x, v);
+        }
+    }
+}
+
+#[test]
+fn from_half_bounded_ranges() {
+    let lc: intervals::LeftClosed<i32> = (3..).into();
+    let ro: intervals::RightOpen<f64> = (..1.5).into();
+    let rc: intervals::RightClosed<u8> = (..=7).into();
+    let un: intervals::Unbounded<i64> = (..).into();
+
+    assert_eq!(lc, 3..);
+    assert_eq!(ro, ..1.5);
+    assert_eq!(rc, ..=7);
+    assert_eq!(un, ..);
+
+    assert!(lc.contains(3) && !lc.contains(2));
+    assert!(ro.contains(1.4) && !ro.contains(1.5));
+    assert!(rc.contains(7) && !rc.contains(8));
+    assert!(un.contains(i64::MIN));
+}
+
+#[test]
+fn from_ranges_generic() {
+    fn width<X: std::convert::TryInto<intervals::LCRO<u32>>>(x: X) -> Option<u32> {
+        x.try_into().ok()?.checked_width()
+    }
+    fn lower<X: Into<intervals::LeftClosed<u32>>>(x: X) -> u32 { x.into().left.0 }
+
+    assert_eq!(width(2..7), Some(5));
+    assert_eq!(width(7..7), None);
+    assert_eq!(width(Interval::lcro_unchecked(0, 1)), Some(1));
+    assert_eq!(lower(4..), 4);
+}
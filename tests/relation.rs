@@ -0,0 +1,144 @@
+extern crate intervals;
+
+#[macro_use]
+mod common;
+
+use intervals::{bounds::Bound, Interval, Position, Relation};
+
+// Checks every shape in the first list against every shape in the second.
+macro_rules! cross {
+    ($($x:expr),+; $ys:tt) => {{ $( cross!(@one $x; $ys); )+ }};
+    (@one $x:expr; [$($y:expr),+]) => {{ $( check(&$x, &$y); )+ }};
+}
+
+const ENDPOINTS: [f64; 5] = [-1.0, 0.0, 0.5, 1.0, 2.0];
+
+const STEP: f64 = 0.125;
+
+// Least and greatest points of a grid fine enough to separate open and closed
+// bounds at the endpoints, and far enough to reach past them.
+fn extent<L, R>(x: &Interval<L, R>) -> (f64, f64)
+where
+    L: Bound<Value = f64>,
+    R: Bound<Value = f64>,
+{
+    let grid = (-16..=16).map(|k| k as f64 * STEP).chain(vec![-1e9, 1e9]);
+    let inside: Vec<f64> = grid.filter(|v| x.classify(v) == Position::Inside).collect();
+
+    (inside.iter().cloned().fold(f64::INFINITY, f64::min), inside.iter().cloned().fold(f64::NEG_INFINITY, f64::max))
+}
+
+// Derives the relation from the grid points held by each interval; two
+// intervals meet if no grid point lies between them.
+fn expected(x: (f64, f64), y: (f64, f64)) -> Relation {
+    use std::cmp::Ordering::*;
+
+    if x.1 < y.0 {
+        return if y.0 - x.1 == STEP { Relation::Meets } else { Relation::Before };
+    }
+
+    if y.1 < x.0 {
+        return if x.0 - y.1 == STEP { Relation::MetBy } else { Relation::After };
+    }
+
+    match (x.0.partial_cmp(&y.0).unwrap(), x.1.partial_cmp(&y.1).unwrap()) {
+        (Less, Less) => Relation::Overlaps,
+        (Less, Equal) => Relation::FinishedBy,
+        (Less, Greater) => Relation::Contains,
+        (Equal, Less) => Relation::Starts,
+        (Equal, Equal) => Relation::Equals,
+        (Equal, Greater) => Relation::StartedBy,
+        (Greater, Less) => Relation::During,
+        (Greater, Equal) => Relation::Finishes,
+        (Greater, Greater) => Relation::OverlappedBy,
+    }
+}
+
+fn check<L, R, LL, RR>(x: &Interval<L, R>, y: &Interval<LL, RR>)
+where
+    L: Bound<Value = f64> + std::fmt::Debug,
+    R: Bound<Value = f64> + std::fmt::Debug,
+    LL: Bound<Value = f64> + std::fmt::Debug,
+    RR: Bound<Value = f64> + std::fmt::Debug,
+{
+    let relation = x.relate(y);
+
+    assert_eq!(relation, expected(extent(x), extent(y)), "{:?} vs {:?}", x, y);
+    assert_eq!(y.relate(x), relation.inverse(), "{:?} vs {:?}", y, x);
+
+    let apart = [Relation::Before, Relation::Meets, Relation::MetBy, Relation::After];
+
+    assert_eq!(x.intersects(y), !apart.contains(&relation), "{:?} vs {:?}", x, y);
+}
+
+#[test]
+fn table() {
+    let y = i!(Closed[2, 4]);
+
+    assert_eq!(i!(Closed[0, 1]).relate(&y), Relation::Before);
+    assert_eq!(i!(LCRO[0, 2]).relate(&y), Relation::Meets);
+    assert_eq!(i!(Closed[0, 3]).relate(&y), Relation::Overlaps);
+    assert_eq!(i!(Closed[2, 3]).relate(&y), Relation::Starts);
+    assert_eq!(i!(Closed[3, 3]).relate(&y), Relation::During);
+    assert_eq!(i!(Closed[3, 4]).relate(&y), Relation::Finishes);
+    assert_eq!(i!(Closed[2, 4]).relate(&y), Relation::Equals);
+    assert_eq!(i!(Closed[1, 4]).relate(&y), Relation::FinishedBy);
+    assert_eq!(i!(Closed[1, 5]).relate(&y), Relation::Contains);
+    assert_eq!(i!(Closed[2, 5]).relate(&y), Relation::StartedBy);
+    assert_eq!(i!(Closed[3, 5]).relate(&y), Relation::OverlappedBy);
+    assert_eq!(i!(LORC[4, 5]).relate(&y), Relation::MetBy);
+    assert_eq!(i!(Closed[5, 6]).relate(&y), Relation::After);
+}
+
+#[test]
+fn openness() {
+    assert_eq!(i!(LCRO[0, 1]).relate(&i!(Closed[1, 2])), Relation::Meets);
+    assert_eq!(i!(Closed[0, 1]).relate(&i!(LORC[1, 2])), Relation::Meets);
+    assert_eq!(i!(Open[0, 1]).relate(&i!(Open[1, 2])), Relation::Before);
+    assert_eq!(i!(Closed[0, 1]).relate(&i!(Closed[1, 2])), Relation::Overlaps);
+
+    assert_eq!(i!(Open[0, 2]).relate(&i!(Closed[0, 2])), Relation::During);
+    assert_eq!(i!(LCRO[0, 2]).relate(&i!(Closed[0, 2])), Relation::Starts);
+    assert_eq!(i!(Degenerate[0]).relate(&i!(Closed[0, 2])), Relation::Starts);
+    assert_eq!(i!(Degenerate[0]).relate(&i!(LORC[0, 2])), Relation::Meets);
+}
+
+#[test]
+fn unbounded() {
+    assert_eq!(i!(LC[0]).relate(&i!(Closed[1, 2])), Relation::Contains);
+    assert_eq!(i!(LC[0]).relate(&i!(LC[1])), Relation::FinishedBy);
+    assert_eq!(i!(LC[0]).relate(&i!(LO[0])), Relation::FinishedBy);
+    assert_eq!(i!(RO[0]).relate(&i!(LC[0])), Relation::Meets);
+    assert_eq!(i!(RO[0]).relate(&i!(LO[0])), Relation::Before);
+
+    assert_eq!(Interval::unbounded().relate(&i!(LC[0])), Relation::FinishedBy);
+    assert_eq!(Interval::unbounded().relate(&intervals::Unbounded::<i32>::unbounded()), Relation::Equals);
+}
+
+#[test]
+fn incomparable() {
+    assert_eq!(i!(Closed[0.0, 1.0]).relate(&i!(Closed[f64::NAN, 0.5])), Relation::Incomparable);
+    assert_eq!(Relation::Incomparable.inverse(), Relation::Incomparable);
+}
+
+#[test]
+fn matrix() {
+    for &a in ENDPOINTS.iter() {
+        for &b in ENDPOINTS.iter().filter(|&&b| b > a) {
+            for &c in ENDPOINTS.iter() {
+                for &d in ENDPOINTS.iter().filter(|&&d| d > c) {
+                    cross!(
+                        i!(Open[a, b]), i!(Closed[a, b]), i!(LCRO[a, b]), i!(LORC[a, b]),
+                        i!(LO[a]), i!(LC[a]), i!(RO[b]), i!(RC[b]),
+                        i!(Degenerate[a]), Interval::unbounded();
+                        [
+                            i!(Open[c, d]), i!(Closed[c, d]), i!(LCRO[c, d]), i!(LORC[c, d]),
+                            i!(LO[c]), i!(LC[c]), i!(RO[d]), i!(RC[d]),
+                            i!(Degenerate[c]), Interval::unbounded()
+                        ]
+                    );
+                }
+            }
+        }
+    }
+}
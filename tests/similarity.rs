@@ -0,0 +1,71 @@
+extern crate intervals;
+
+#[macro_use]
+mod common;
+
+use intervals::Interval;
+
+#[test]
+fn nested() {
+    let (x, y) = (i!(Closed[0.0, 10.0]), i!(Closed[2.0, 4.0]));
+
+    assert_eq!(x.jaccard(&y), 0.2);
+    assert_eq!(y.jaccard(&x), 0.2);
+    assert_eq!(x.overlap_coefficient(&y), 1.0);
+    assert_eq!(y.overlap_coefficient(&x), 1.0);
+}
+
+#[test]
+fn partially_overlapping() {
+    let (x, y) = (i!(LCRO[0, 6]), i!(LORC[4, 8]));
+
+    assert_eq!(x.jaccard(&y), 0.25);
+    assert_eq!(y.jaccard(&x), 0.25);
+    assert_eq!(x.overlap_coefficient(&y), 0.5);
+    assert_eq!(y.overlap_coefficient(&x), 0.5);
+
+    assert_eq!(i!(Closed[0u32, 3]).jaccard(&i!(Closed[1, 4])), 0.5);
+}
+
+#[test]
+fn disjoint() {
+    assert_eq!(i!(Closed[0.0, 1.0]).jaccard(&i!(Closed[2.0, 3.0])), 0.0);
+    assert_eq!(i!(Closed[0.0, 1.0]).overlap_coefficient(&i!(Closed[2.0, 3.0])), 0.0);
+
+    // Touching at an excluded point, or a point of no width.
+    assert_eq!(i!(LCRO[0, 1]).jaccard(&i!(Closed[1, 2])), 0.0);
+    assert_eq!(i!(Degenerate[0]).overlap_coefficient(&i!(Degenerate[1])), 0.0);
+    assert_eq!(i!(RO[0.0]).jaccard(&i!(LC[0.0])), 0.0);
+}
+
+#[test]
+fn identical() {
+    for x in [i!(Closed[0.0, 1.0]), i!(Closed[-5.0, 5.0])].iter() {
+        assert_eq!(x.jaccard(x), 1.0);
+        assert_eq!(x.overlap_coefficient(x), 1.0);
+    }
+
+    // Openness makes no difference to the widths.
+    assert_eq!(i!(Open[0, 1]).jaccard(&i!(Closed[0, 1])), 1.0);
+}
+
+#[test]
+fn degenerate_denominators() {
+    // Meeting at a point that both hold.
+    assert_eq!(i!(Closed[0.0, 1.0]).jaccard(&i!(Closed[1.0, 2.0])), 0.0);
+    assert!(i!(Closed[0.0, 1.0]).overlap_coefficient(&i!(Degenerate[1.0])).is_nan());
+
+    assert!(i!(Degenerate[1.0]).jaccard(&i!(Degenerate[1.0])).is_nan());
+    assert!(i!(Degenerate[1.0]).overlap_coefficient(&i!(Degenerate[1.0])).is_nan());
+}
+
+#[test]
+fn unbounded() {
+    assert_eq!(i!(LC[0.0]).jaccard(&i!(Closed[1.0, 2.0])), 0.0);
+    assert_eq!(i!(LC[0.0]).overlap_coefficient(&i!(Closed[1.0, 2.0])), 1.0);
+    assert_eq!(i!(RC[1.0]).overlap_coefficient(&i!(Closed[0.0, 2.0])), 0.5);
+
+    assert!(i!(LC[0.0]).jaccard(&i!(LC[1.0])).is_nan());
+    assert!(i!(LC[0.0]).overlap_coefficient(&i!(LO[1.0])).is_nan());
+    assert!(intervals::Unbounded::<f64>::unbounded().jaccard(&Interval::unbounded()).is_nan());
+}
@@ -0,0 +1,253 @@
+extern crate intervals;
+
+use intervals::{Interval, SplitError, bounds::{Bound, OpenOrClosed}};
+
+#[test]
+fn split_evenly_tiles() {
+    let x = Interval::closed_unchecked(-3.0, 7.0);
+
+    for n in 1..50 {
+        let pieces = x.split_evenly(n).unwrap();
+
+        assert_eq!(pieces.len(), n);
+        assert_eq!(pieces[0].left, x.left);
+        assert_eq!(pieces[n - 1].right, OpenOrClosed::Closed(7.0));
+
+        for w in pieces.windows(2) {
+            assert_eq!(w[0].right, OpenOrClosed::Open(w[1].left.0));
+        }
+    }
+}
+
+#[test]
+fn split_evenly_exact() {
+    let pieces = Interval::closed_unchecked(0u32, 12).split_evenly(4).unwrap();
+
+    assert_eq!(pieces[0], Interval::lcro_unchecked(0, 3));
+    assert_eq!(pieces[1], Interval::lcro_unchecked(3, 6));
+    assert_eq!(pieces[2], Interval::lcro_unchecked(6, 9));
+    assert_eq!(pieces[3], Interval::closed_unchecked(9, 12));
+
+    for p in pieces.iter() {
+        assert_eq!(p.right.value().unwrap() - p.left.0, 3);
+    }
+}
+
+#[test]
+fn split_evenly_no_drift() {
+    let pieces = Interval::closed_unchecked(0.0, 1.0).split_evenly(10).unwrap();
+
+    for (k, p) in pieces.iter().enumerate() {
+        assert_eq!(p.left.0, k as f64 / 10.0);
+    }
+}
+
+#[test]
+fn split_evenly_u8() {
+    let pieces = Interval::closed_unchecked(0u8, 200).split_evenly(3).unwrap();
+
+    assert_eq!(pieces[0], Interval::lcro_unchecked(0, 66));
+    assert_eq!(pieces[1], Interval::lcro_unchecked(66, 133));
+    assert_eq!(pieces[2], Interval::closed_unchecked(133, 200));
+}
+
+#[test]
+fn split_evenly_full_range() {
+    let pieces = Interval::closed_unchecked(i32::MIN, i32::MAX).split_evenly(2).unwrap();
+
+    assert_eq!(pieces[0], Interval::lcro_unchecked(i32::MIN, -1));
+    assert_eq!(pieces[1], Interval::closed_unchecked(-1, i32::MAX));
+
+    let pieces = Interval::closed_unchecked(u128::MIN, u128::MAX).split_evenly(7).unwrap();
+
+    assert_eq!(pieces.len(), 7);
+    assert_eq!(pieces[6].right, OpenOrClosed::Closed(u128::MAX));
+
+    for p in pieces.iter() {
+        assert!(Interval::new(p.left, p.right).is_ok());
+    }
+}
+
+#[test]
+fn split_evenly_more_pieces_than_width() {
+    assert!(Interval::closed_unchecked(0u32, 2).split_evenly(4).is_err());
+    assert!(Interval::closed_unchecked(0u32, 2).split_evenly(3).is_err());
+    assert!(Interval::closed_unchecked(-1i8, -1).split_evenly(2).is_err());
+    assert_eq!(Interval::closed_unchecked(-1i8, -1).split_evenly(1).unwrap(), [Interval::degenerate(-1)]);
+
+    let pieces = Interval::closed_unchecked(0u32, 2).split_evenly(2).unwrap();
+
+    assert_eq!(pieces[0], Interval::lcro_unchecked(0, 1));
+    assert_eq!(pieces[1], Interval::closed_unchecked(1, 2));
+}
+
+#[test]
+fn split_evenly_degenerate_float() {
+    assert!(Interval::degenerate(1.0).split_evenly(2).is_err());
+    assert_eq!(Interval::degenerate(1.0).split_evenly(1).unwrap(), [Interval::degenerate(1.0)]);
+}
+
+#[test]
+fn split_evenly_below_resolution() {
+    let (a, b) = (1.0f64, 1.0f64 + f64::EPSILON);
+
+    assert!(Interval::closed_unchecked(a, b).split_evenly(2).is_err());
+    assert!(Interval::closed_unchecked(1.0f32, 1.0 + 4.0 * f32::EPSILON).split_evenly(8).is_err());
+
+    for p in Interval::closed_unchecked(a, a + 4.0 * f64::EPSILON).split_evenly(4).unwrap() {
+        assert!(Interval::new(p.left, p.right).is_ok());
+    }
+}
+
+#[test]
+fn split_evenly_zero() {
+    assert!(Interval::closed_unchecked(0.0, 1.0).split_evenly(0).is_err());
+}
+
+#[test]
+fn split_at_many_empty() {
+    let x = Interval::lcro_unchecked(0.0, 1.0);
+    let pieces = x.split_at_many(&[]).unwrap();
+
+    assert_eq!(pieces.len(), 1);
+    assert_eq!(pieces[0], x);
+}
+
+#[test]
+fn split_at_many_outer_openness() {
+    let pieces = Interval::open_unchecked(0.0, 1.0).split_at_many(&[0.25, 0.5]).unwrap();
+
+    assert_eq!(pieces.len(), 3);
+    assert_eq!(pieces[0], Interval::open_unchecked(0.0, 0.25));
+    assert_eq!(pieces[1], Interval::lcro_unchecked(0.25, 0.5));
+    assert_eq!(pieces[2], Interval::lcro_unchecked(0.5, 1.0));
+}
+
+#[test]
+fn split_at_many_endpoints() {
+    let x = Interval::closed_unchecked(0, 10);
+
+    assert_eq!(x.split_at_many(&[0]), Err(SplitError::OutOfRange(0)));
+    assert_eq!(x.split_at_many(&[5, 10]), Err(SplitError::OutOfRange(1)));
+    assert_eq!(x.split_at_many(&[5, 11]), Err(SplitError::OutOfRange(1)));
+    assert_eq!(Interval::open_unchecked(0, 10).split_at_many(&[-1]), Err(SplitError::OutOfRange(0)));
+}
+
+#[test]
+fn split_at_many_unordered() {
+    let x = Interval::closed_unchecked(0.0, 10.0);
+
+    assert_eq!(x.split_at_many(&[2.0, 5.0, 5.0]), Err(SplitError::Unordered(2)));
+    assert_eq!(x.split_at_many(&[5.0, 2.0]), Err(SplitError::Unordered(1)));
+    assert_eq!(x.split_at_many(&[f64::NAN]), Err(SplitError::OutOfRange(0)));
+}
+
+#[test]
+fn split_at_bounded() {
+    assert_eq!(
+        Interval::closed_unchecked(0, 10).split_at(4),
+        Ok((Interval::lcro_unchecked(0, 4), Interval::closed_unchecked(4, 10)))
+    );
+    assert_eq!(
+        Interval::open_unchecked(0, 10).split_at(4),
+        Ok((Interval::open_unchecked(0, 4), Interval::lcro_unchecked(4, 10)))
+    );
+    assert_eq!(
+        Interval::lorc_unchecked(0.0, 1.0).split_at(0.5),
+        Ok((Interval::open_unchecked(0.0, 0.5), Interval::closed_unchecked(0.5, 1.0)))
+    );
+}
+
+#[test]
+fn split_at_unbounded() {
+    assert_eq!(
+        Interval::right_closed(10).split_at(4),
+        Ok((Interval::right_open(4), Interval::closed_unchecked(4, 10)))
+    );
+
+    let (lower, upper) = intervals::Unbounded::unbounded().split_at(0.0).unwrap();
+
+    assert_eq!(lower, Interval::right_open(0.0));
+    assert_eq!(upper, Interval::left_closed(0.0));
+}
+
+#[test]
+fn split_at_out_of_range() {
+    for &x in [-1, 0, 10, 11].iter() {
+        assert_eq!(Interval::closed_unchecked(0, 10).split_at(x), Err(SplitError::OutOfRange(0)));
+        assert_eq!(Interval::open_unchecked(0, 10).split_at(x), Err(SplitError::OutOfRange(0)));
+    }
+
+    assert_eq!(Interval::left_closed(0).split_at(0), Err(SplitError::OutOfRange(0)));
+    assert_eq!(Interval::right_open(0).split_at(0), Err(SplitError::OutOfRange(0)));
+    assert!(Interval::closed_unchecked(0.0, 1.0).split_at(f64::NAN).is_err());
+}
+
+#[test]
+fn split_at_agrees_with_many() {
+    let x = Interval::lorc_unchecked(0, 10);
+
+    for p in 1..10 {
+        let (lower, upper) = x.split_at(p).unwrap();
+        let pieces = x.split_at_many(&[p]).unwrap();
+
+        assert_eq!(pieces[0].left, lower.left);
+        assert_eq!(pieces[0].right, lower.right);
+        assert_eq!(pieces[1].left, upper.left);
+        assert_eq!(pieces[1].right, upper.right);
+    }
+}
+
+#[test]
+fn bisect_floats() {
+    let x = Interval::lorc_unchecked(-1.0, 3.0);
+    let (lower, upper) = x.bisect().unwrap();
+
+    assert_eq!(lower, Interval::open_unchecked(-1.0, 1.0));
+    assert_eq!(upper, Interval::closed_unchecked(1.0, 3.0));
+
+    // Repeated bisection of the lower half converges on the left bound.
+    let mut y = Interval::closed_unchecked(0.0, 1.0).bisect().unwrap().0;
+
+    for _ in 0..10 {
+        y = y.bisect().unwrap().0;
+    }
+
+    assert_eq!(y, Interval::lcro_unchecked(0.0, 1.0 / 2048.0));
+}
+
+#[test]
+fn bisect_integers() {
+    assert_eq!(
+        Interval::closed_unchecked(0, 10).bisect(),
+        Ok((Interval::lcro_unchecked(0, 5), Interval::closed_unchecked(5, 10)))
+    );
+    assert_eq!(
+        Interval::closed_unchecked(0, 3).bisect(),
+        Ok((Interval::lcro_unchecked(0, 1), Interval::closed_unchecked(1, 3)))
+    );
+    assert_eq!(
+        Interval::open_unchecked(-3, 0).bisect(),
+        Ok((Interval::open_unchecked(-3, -2), Interval::lcro_unchecked(-2, 0)))
+    );
+    assert_eq!(
+        Interval::closed_unchecked(i8::MIN, i8::MIN + 2).bisect(),
+        Ok((Interval::lcro_unchecked(i8::MIN, i8::MIN + 1), Interval::closed_unchecked(i8::MIN + 1, i8::MIN + 2)))
+    );
+    assert_eq!(
+        Interval::closed_unchecked(i8::MIN, i8::MAX).bisect(),
+        Ok((Interval::lcro_unchecked(i8::MIN, -1), Interval::closed_unchecked(-1, i8::MAX)))
+    );
+
+    // The midpoint of a unit interval truncates to its left bound.
+    assert_eq!(Interval::closed_unchecked(0, 1).bisect(), Err(SplitError::OutOfRange(0)));
+}
+
+#[test]
+fn bisect_degenerate_and_unbounded() {
+    assert_eq!(Interval::degenerate(2).bisect(), Err(SplitError::OutOfRange(0)));
+    assert_eq!(Interval::degenerate(2.0).bisect(), Err(SplitError::OutOfRange(0)));
+
+    assert!(Interval::left_closed(0.0).bisect().is_err());
+    assert!(Interval::right_open(0).bisect().is_err());
+}
@@ -0,0 +1,76 @@
+extern crate intervals;
+
+use intervals::{Interval, bounds::{self, Bound, OpenOrClosed}};
+
+#[test]
+fn interior_bounded() {
+    assert_eq!(Interval::closed_unchecked(0, 1).interior(), Interval::open_unchecked(0, 1));
+    assert_eq!(Interval::open_unchecked(0, 1).interior(), Interval::open_unchecked(0, 1));
+    assert_eq!(Interval::lcro_unchecked(0, 1).interior(), Interval::open_unchecked(0, 1));
+    assert_eq!(Interval::lorc_unchecked(0, 1).interior(), Interval::open_unchecked(0, 1));
+    assert_eq!(Interval::degenerate(0).interior(), Interval::open_unchecked(0, 0));
+}
+
+#[test]
+fn interior_unbounded() {
+    assert_eq!(Interval::left_closed(0).interior(), Interval::left_open(0));
+    assert_eq!(Interval::left_open(0).interior(), Interval::left_open(0));
+    assert_eq!(Interval::right_closed(0).interior(), Interval::right_open(0));
+    assert_eq!(Interval::right_open(0).interior(), Interval::right_open(0));
+    assert_eq!(intervals::Unbounded::<f64>::unbounded().interior(), Interval::unbounded());
+}
+
+#[test]
+fn interior_mixed() {
+    let x = Interval::new_unchecked(OpenOrClosed::Closed(0.0), OpenOrClosed::Open(1.0));
+
+    assert_eq!(x.interior(), Interval::open_unchecked(0.0, 1.0));
+
+    let y = Interval::new_unchecked(bounds::NoBound::new(), OpenOrClosed::Closed(1.0));
+
+    assert_eq!(y.interior(), Interval::right_open(1.0));
+}
+
+#[test]
+fn interior_round_trip() {
+    let x = Interval::lorc_unchecked(0.5, 2.5).interior();
+
+    assert_eq!(x.closure(), Interval::closed_unchecked(0.5, 2.5));
+    assert_eq!(x.left.value(), Some(&0.5));
+    assert_eq!(x.right.value(), Some(&2.5));
+
+    assert_eq!(Interval::left_open(1).interior().closure(), Interval::left_closed(1));
+    assert_eq!(Interval::right_closed(1).interior().closure(), Interval::right_closed(1));
+}
+
+#[test]
+fn closure_bounded() {
+    assert_eq!(Interval::closed_unchecked(0, 1).closure(), Interval::closed_unchecked(0, 1));
+    assert_eq!(Interval::open_unchecked(0, 1).closure(), Interval::closed_unchecked(0, 1));
+    assert_eq!(Interval::lcro_unchecked(0, 1).closure(), Interval::closed_unchecked(0, 1));
+    assert_eq!(Interval::lorc_unchecked(0, 1).closure(), Interval::closed_unchecked(0, 1));
+    assert_eq!(Interval::degenerate(0).closure(), Interval::degenerate(0));
+    assert_eq!(Interval::open_unchecked(0, 0).closure(), Interval::degenerate(0));
+}
+
+#[test]
+fn closure_unbounded() {
+    assert_eq!(Interval::left_closed(0).closure(), Interval::left_closed(0));
+    assert_eq!(Interval::left_open(0).closure(), Interval::left_closed(0));
+    assert_eq!(Interval::right_closed(0).closure(), Interval::right_closed(0));
+    assert_eq!(Interval::right_open(0).closure(), Interval::right_closed(0));
+    assert_eq!(intervals::Unbounded::<f64>::unbounded().closure(), Interval::unbounded());
+}
+
+#[test]
+fn closure_mixed() {
+    for &(l, r) in [(true, true), (true, false), (false, true), (false, false)].iter() {
+        let bound = |open, v| if open { OpenOrClosed::Open(v) } else { OpenOrClosed::Closed(v) };
+
+        let x = Interval::new_unchecked(bound(l, 0.0), bound(r, 1.0));
+        let y = Interval::new_unchecked(bounds::NoBound::new(), bound(r, 1.0));
+
+        assert_eq!(x.closure(), Interval::closed_unchecked(0.0, 1.0));
+        assert_eq!(y.closure(), Interval::right_closed(1.0));
+    }
+}
@@ -0,0 +1,74 @@
+extern crate intervals;
+
+use intervals::{Interval, bounds::OpenOrClosed};
+
+const TINY: f64 = 5e-324;
+
+#[test]
+fn test_degenerate() {
+    assert_eq!(Interval::degenerate(1.0f64).count_ulps(), Some(1));
+    assert_eq!(Interval::degenerate(-0.0f64).count_ulps(), Some(1));
+    assert_eq!(Interval::closed_unchecked(-0.0f64, 0.0).count_ulps(), Some(1));
+
+    assert_eq!(Interval::open_unchecked(1.0f64, 1.0).count_ulps(), Some(0));
+    assert_eq!(Interval::lcro_unchecked(1.0f64, 1.0).count_ulps(), Some(0));
+    assert_eq!(Interval::lorc_unchecked(1.0f32, 1.0).count_ulps(), Some(0));
+}
+
+#[test]
+fn test_adjacent() {
+    let next = f64::from_bits(1.0f64.to_bits() + 1);
+
+    assert_eq!(Interval::closed_unchecked(1.0, next).count_ulps(), Some(2));
+    assert_eq!(Interval::lcro_unchecked(1.0, next).count_ulps(), Some(1));
+    assert_eq!(Interval::lorc_unchecked(1.0, next).count_ulps(), Some(1));
+    assert_eq!(Interval::open_unchecked(1.0, next).count_ulps(), Some(0));
+}
+
+#[test]
+fn test_straddling_zero() {
+    assert_eq!(Interval::closed_unchecked(-TINY, TINY).count_ulps(), Some(3));
+    assert_eq!(Interval::open_unchecked(-TINY, TINY).count_ulps(), Some(1));
+    assert_eq!(Interval::closed_unchecked(-2.0 * TINY, TINY).count_ulps(), Some(4));
+
+    let x = Interval::closed_unchecked(-1.0f32, 1.0f32);
+
+    assert_eq!(x.count_ulps(), Some(2 * 1.0f32.to_bits() as u64 + 1));
+}
+
+#[test]
+fn test_subnormals() {
+    let n_subnormals = (1u64 << 52) - 1;
+
+    assert_eq!(Interval::closed_unchecked(0.0, f64::MIN_POSITIVE).count_ulps(), Some(n_subnormals + 2));
+    assert_eq!(Interval::open_unchecked(0.0, f64::MIN_POSITIVE).count_ulps(), Some(n_subnormals));
+    assert_eq!(Interval::closed_unchecked(TINY, 4.0 * TINY).count_ulps(), Some(4));
+}
+
+#[test]
+fn test_extremes() {
+    let all = Interval::closed_unchecked(f64::NEG_INFINITY, f64::INFINITY);
+
+    assert_eq!(all.count_ulps(), Some(2 * f64::INFINITY.to_bits() + 1));
+    assert_eq!(Interval::closed_unchecked(f64::MAX, f64::INFINITY).count_ulps(), Some(2));
+}
+
+#[test]
+fn test_none() {
+    assert_eq!(Interval::left_closed(0.0f64).count_ulps(), None);
+    assert_eq!(Interval::right_open(0.0f32).count_ulps(), None);
+    assert_eq!(Interval::closed_unchecked(f64::NAN, 1.0).count_ulps(), None);
+    assert_eq!(Interval::open_unchecked(0.0, f64::NAN).count_ulps(), None);
+}
+
+#[test]
+fn test_mixed_bounds() {
+    let x = Interval::new_unchecked(OpenOrClosed::Open(0.0f64), OpenOrClosed::Closed(3.0 * TINY));
+
+    assert_eq!(x.count_ulps(), Some(3));
+}
+
+#[test]
+fn test_decreasing() {
+    assert_eq!(Interval::closed_unchecked(1.0f64, 0.0).count_ulps(), Some(0));
+}
@@ -0,0 +1,101 @@
+extern crate intervals;
+
+#[macro_use]
+mod common;
+
+use intervals::{bounds::{Bound, Unroll}, Interval, Position};
+
+// Checks every shape in the first list against every shape in the second.
+macro_rules! cross {
+    ($($x:expr),+; $ys:tt) => {{ $( cross!(@one $x; $ys); )+ }};
+    (@one $x:expr; [$($y:expr),+]) => {{ $( check($x, $y); check($y, $x); )+ }};
+}
+
+const ENDPOINTS: [f64; 4] = [-1.0, 0.0, 1.0, 2.0];
+
+// Compares against the union of a grid of points fine enough to expose a
+// missing endpoint, and far enough to reach past the bounds.
+fn check<L, R, LL, RR>(x: Interval<L, R>, y: Interval<LL, RR>)
+where
+    L: Bound<Value = f64> + Unroll<LL> + Copy + std::fmt::Debug,
+    R: Bound<Value = f64> + Unroll<RR> + Copy + std::fmt::Debug,
+    LL: Bound<Value = f64> + Copy + std::fmt::Debug,
+    RR: Bound<Value = f64> + Copy + std::fmt::Debug,
+{
+    let grid: Vec<f64> = vec![-1e9].into_iter()
+        .chain((-16..=16).map(|k| k as f64 / 8.0))
+        .chain(vec![1e9])
+        .collect();
+
+    let inside: Vec<bool> = grid
+        .iter()
+        .map(|v| x.classify(v) == Position::Inside || y.classify(v) == Position::Inside)
+        .collect();
+
+    let first = inside.iter().position(|&b| b).unwrap();
+    let last = inside.iter().rposition(|&b| b).unwrap();
+    let convex = inside[first..=last].iter().all(|&b| b);
+
+    match x.union(y) {
+        Some(z) => {
+            assert!(convex, "{:?} with {:?}", x, y);
+
+            for (v, &expected) in grid.iter().zip(inside.iter()) {
+                assert_eq!(z.classify(v) == Position::Inside, expected, "{:?} with {:?} at {}", x, y, v);
+            }
+        },
+        None => assert!(!convex, "{:?} with {:?}", x, y),
+    }
+}
+
+fn show<T: std::fmt::Display>(z: Option<T>) -> Option<String> { z.map(|z| z.to_string()) }
+
+#[test]
+fn adjacent() {
+    assert_eq!(show(i!(LCRO[0, 1]).union(i!(Closed[1, 2]))), Some("[0, 2]".to_string()));
+    assert_eq!(show(i!(Closed[0, 1]).union(i!(LORC[1, 2]))), Some("[0, 2]".to_string()));
+    assert_eq!(show(i!(Closed[0, 1]).union(i!(Closed[1, 2]))), Some("[0, 2]".to_string()));
+    assert_eq!(show(i!(RC[0]).union(i!(LO[0]))), Some("(-\u{221E}, \u{221E})".to_string()));
+
+    assert_eq!(show(i!(Open[0, 1]).union(i!(Open[1, 2]))), None);
+    assert_eq!(show(i!(LCRO[0, 1]).union(i!(LORC[1, 2]))), None);
+    assert_eq!(show(i!(RO[0]).union(i!(LO[0]))), None);
+}
+
+#[test]
+fn gaps() {
+    assert_eq!(show(i!(Closed[0, 1]).union(i!(Closed[2, 3]))), None);
+    assert_eq!(show(i!(Closed[2, 3]).union(i!(Closed[0, 1]))), None);
+    assert_eq!(show(i!(RC[0]).union(i!(LC[1]))), None);
+    assert_eq!(show(i!(Closed[0.0, 1.0]).union(i!(Closed[f64::NAN, 2.0]))), None);
+}
+
+#[test]
+fn overlapping() {
+    assert_eq!(show(i!(Open[0, 2]).union(i!(Open[1, 3]))), Some("(0, 3)".to_string()));
+    assert_eq!(show(i!(Open[0, 3]).union(i!(Closed[1, 2]))), Some("(0, 3)".to_string()));
+    assert_eq!(show(i!(LC[0]).union(i!(RO[1]))), Some("(-\u{221E}, \u{221E})".to_string()));
+    assert_eq!(show(i!(Degenerate[1]).union(i!(LCRO[0, 1]))), Some("[0, 1]".to_string()));
+}
+
+#[test]
+fn matrix() {
+    for &a in ENDPOINTS.iter() {
+        for &b in ENDPOINTS.iter().filter(|&&b| b > a) {
+            for &c in ENDPOINTS.iter() {
+                for &d in ENDPOINTS.iter().filter(|&&d| d > c) {
+                    cross!(
+                        i!(Open[a, b]), i!(Closed[a, b]), i!(LCRO[a, b]), i!(LORC[a, b]),
+                        i!(LO[a]), i!(LC[a]), i!(RO[b]), i!(RC[b]),
+                        i!(Degenerate[a]), Interval::unbounded();
+                        [
+                            i!(Open[c, d]), i!(Closed[c, d]), i!(LCRO[c, d]), i!(LORC[c, d]),
+                            i!(LO[c]), i!(LC[c]), i!(RO[d]), i!(RC[d]),
+                            i!(Degenerate[c]), Interval::unbounded()
+                        ]
+                    );
+                }
+            }
+        }
+    }
+}
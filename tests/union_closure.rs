@@ -1,22 +1,9 @@
 extern crate intervals;
 
-use intervals::Interval;
+#[macro_use]
+mod common;
 
-macro_rules! i {
-    (Open[$left:expr, $right:expr]) => { Interval::open_unchecked($left, $right) };
-    (Closed[$left:expr, $right:expr]) => { Interval::closed_unchecked($left, $right) };
-
-    (LCRO[$left:expr, $right:expr]) => { Interval::lcro_unchecked($left, $right) };
-    (LORC[$left:expr, $right:expr]) => { Interval::lorc_unchecked($left, $right) };
-
-    (LO[$left:expr]) => { Interval::left_open($left) };
-    (LC[$left:expr]) => { Interval::left_closed($left) };
-
-    (RO[$right:expr]) => { Interval::right_open($right) };
-    (RC[$right:expr]) => { Interval::right_closed($right) };
-
-    (Degenerate[$x:expr]) => { Interval::degenerate($x) };
-}
+use intervals::{Interval, bounds::Bound};
 
 macro_rules! test_ucs {
     ($x:expr; [$(($y:expr, |$z:ident| $test:expr)),+]) => {{
@@ -24,10 +11,22 @@ macro_rules! test_ucs {
         $({
             let y = $y;
             let $z = x.union_closure(y);
+            let span = x.span(y);
+
+            assert_eq!(
+                Interval::new_unchecked(span.left.with_limit_point(), span.right.with_limit_point()),
+                $z
+            );
 
             { $test }
 
             let $z = y.union_closure(x);
+            let span = y.span(x);
+
+            assert_eq!(
+                Interval::new_unchecked(span.left.with_limit_point(), span.right.with_limit_point()),
+                $z
+            );
 
             { $test }
         })+
@@ -443,3 +442,16 @@ fn rc() {
         ]
     );
 }
+
+#[test]
+fn span() {
+    assert_eq!(i!(Open[0.0, 1.0]).span(i!(Open[2.0, 3.0])), i!(Open[0.0, 3.0]));
+    assert_eq!(i!(LCRO[0.0, 1.0]).span(i!(LORC[2.0, 3.0])), i!(Closed[0.0, 3.0]));
+    assert_eq!(i!(LORC[0.0, 1.0]).span(i!(LCRO[2.0, 3.0])), i!(Open[0.0, 3.0]));
+
+    assert_eq!(i!(Open[0.0, 1.0]).span(i!(Closed[0.0, 1.0])), i!(Closed[0.0, 1.0]));
+    assert_eq!(i!(Open[0.0, 1.0]).span(i!(Closed[0.5, 1.0])), i!(LORC[0.0, 1.0]));
+
+    assert_eq!(i!(Open[0.0, 1.0]).span(i!(LO[0.5])), i!(LO[0.0]));
+    assert_eq!(i!(Open[0.0, 1.0]).span(i!(RC[0.5])), i!(RO[1.0]));
+}
@@ -0,0 +1,121 @@
+extern crate intervals;
+
+use intervals::{Interval, partitions::{Edges, Partition, Uniform}};
+
+#[test]
+fn checked_width_i8() {
+    assert_eq!(Interval::closed_unchecked(0i8, i8::MAX).checked_width(), Some(i8::MAX));
+    assert_eq!(Interval::closed_unchecked(-1i8, i8::MAX).checked_width(), None);
+
+    assert_eq!(Interval::closed_unchecked(i8::MIN + 1, 0i8).checked_width(), Some(i8::MAX));
+    assert_eq!(Interval::closed_unchecked(i8::MIN, 0i8).checked_width(), None);
+
+    assert_eq!(Interval::closed_unchecked(i8::MIN, i8::MAX).checked_width(), None);
+    assert_eq!(Interval::open_unchecked(i8::MIN, -1i8).checked_width(), Some(i8::MAX));
+}
+
+#[test]
+fn checked_width_u8() {
+    assert_eq!(Interval::closed_unchecked(0u8, u8::MAX).checked_width(), Some(u8::MAX));
+    assert_eq!(Interval::lcro_unchecked(5u8, 5u8).checked_width(), Some(0));
+
+    // Mis-constructed intervals underflow rather than wrap.
+    assert_eq!(Interval::closed_unchecked(1u8, 0u8).checked_width(), None);
+    assert_eq!(Interval::closed_unchecked(u8::MAX, 0u8).checked_width(), None);
+}
+
+#[test]
+fn checked_width_i64() {
+    assert_eq!(Interval::closed_unchecked(0i64, i64::MAX).checked_width(), Some(i64::MAX));
+    assert_eq!(Interval::closed_unchecked(-1i64, i64::MAX).checked_width(), None);
+
+    assert_eq!(Interval::lorc_unchecked(i64::MIN + 1, 0i64).checked_width(), Some(i64::MAX));
+    assert_eq!(Interval::lorc_unchecked(i64::MIN, 0i64).checked_width(), None);
+}
+
+#[test]
+fn checked_width_unbounded() {
+    assert_eq!(Interval::left_open(0i64).checked_width(), None);
+    assert_eq!(Interval::right_closed(0i64).checked_width(), None);
+    assert_eq!(Interval::<_, _>::unbounded().checked_width(), None::<i64>);
+}
+
+#[test]
+fn subinterval_checked_width() {
+    let partition = Uniform::new(2, 0i8, 100i8);
+    let sub = partition.subinterval(1).unwrap();
+
+    assert_eq!(sub.checked_width(), Some(50));
+    assert_eq!(sub.checked_width(), Some(sub.width()));
+    assert_eq!(Uniform::new(2, 0.0, 1.0).subinterval(0).unwrap().checked_width(), Some(0.5));
+}
+
+#[test]
+fn subinterval_checked_width_overflow() {
+    assert_eq!(Edges::new(vec![i8::MIN, i8::MAX]).unwrap().subinterval(0).unwrap().checked_width(), None);
+    assert_eq!(Edges::new(vec![-1i8, i8::MAX]).unwrap().subinterval(0).unwrap().checked_width(), None);
+    assert_eq!(Edges::new(vec![0i8, i8::MAX]).unwrap().subinterval(0).unwrap().checked_width(), Some(i8::MAX));
+
+    assert_eq!(Edges::new(vec![0u8, u8::MAX]).unwrap().subinterval(0).unwrap().checked_width(), Some(u8::MAX));
+    assert_eq!(Edges::new(vec![i64::MIN, 0]).unwrap().subinterval(0).unwrap().checked_width(), None);
+}
+
+#[test]
+fn measure_bounded() {
+    assert_eq!(Interval::closed_unchecked(0.0, 2.5).measure(), 2.5);
+    assert_eq!(Interval::open_unchecked(-1, 1).measure(), 2.0);
+    assert_eq!(Interval::lcro_unchecked(0u8, u8::MAX).measure(), 255.0);
+    assert_eq!(Interval::lorc_unchecked(i64::MIN, i64::MAX).measure(), 2.0f64.powi(64));
+
+    assert_eq!(Interval::degenerate(3).measure(), 0.0);
+    assert_eq!(Interval::open_unchecked(3, 3).measure(), 0.0);
+    assert_eq!(Interval::closed_unchecked(3, 2).measure(), 0.0);
+
+    assert!(Interval::closed_unchecked(f64::NAN, 1.0).measure().is_nan());
+}
+
+#[test]
+fn measure_unbounded() {
+    let x: intervals::LeftOpen<i32> = Interval::left_open(0);
+    let y: intervals::LeftClosed<f64> = Interval::left_closed(0.0);
+    let z: intervals::RightOpen<u8> = Interval::right_open(0);
+    let w: intervals::RightClosed<i64> = Interval::right_closed(0);
+
+    assert_eq!(x.measure(), f64::INFINITY);
+    assert_eq!(y.measure(), f64::INFINITY);
+    assert_eq!(z.measure(), f64::INFINITY);
+    assert_eq!(w.measure(), f64::INFINITY);
+    assert_eq!(intervals::Unbounded::<f64>::unbounded().measure(), f64::INFINITY);
+}
+
+#[test]
+fn midpoint() {
+    assert_eq!(Interval::closed_unchecked(-1.0, 2.0).midpoint(), Some(0.5));
+    assert_eq!(Interval::open_unchecked(1, 4).midpoint(), Some(2));
+    assert_eq!(Interval::open_unchecked(-4, -1).midpoint(), Some(-3));
+    assert_eq!(Interval::degenerate(7).midpoint(), Some(7));
+
+    assert_eq!(Interval::closed_unchecked(u8::MAX - 1, u8::MAX).midpoint(), Some(u8::MAX - 1));
+    assert_eq!(Interval::closed_unchecked(i32::MAX - 10, i32::MAX).midpoint(), Some(i32::MAX - 5));
+    assert_eq!(Interval::closed_unchecked(i32::MIN, i32::MIN + 10).midpoint(), Some(i32::MIN + 5));
+
+    assert_eq!(Interval::closed_unchecked(-100i8, 100).midpoint(), Some(0));
+    assert_eq!(Interval::closed_unchecked(i8::MIN, i8::MAX).midpoint(), Some(-1));
+    assert_eq!(Interval::closed_unchecked(i32::MIN, i32::MAX).midpoint(), Some(-1));
+    assert_eq!(Interval::closed_unchecked(0, u64::MAX).midpoint(), Some(u64::MAX / 2));
+
+    assert_eq!(Interval::left_closed(0).midpoint(), None);
+    assert_eq!(Interval::right_open(0.0).midpoint(), None);
+    assert_eq!(intervals::Unbounded::<f64>::unbounded().midpoint(), None);
+}
+
+#[test]
+fn midpoint_agrees_with_subinterval() {
+    let p = Uniform::new(4, 0.0, 1.0);
+
+    for k in 0..4 {
+        let sub = p.subinterval(k).unwrap();
+
+        assert_eq!(sub.interval.midpoint(), Some(sub.midpoint()));
+    }
+}